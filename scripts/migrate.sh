@@ -1,5 +0,0 @@
-#!/bin/sh
-
-sqlx migrate add user --source ./sql
-
-sqlx migrate add extensions --source ./sql
\ No newline at end of file
@@ -0,0 +1,57 @@
+-- Brings a database migrated with the 2022 history in sql/legacy/postgres to
+-- the schema of sql/postgres/20261019120000_schema.sql. Applied by the
+-- application, never by sqlx, and only while the legacy versions are recorded.
+-- Clubs without an owner or duplicated names stop the upgrade: fix them first.
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+
+ALTER TABLE public.club_members DROP CONSTRAINT IF EXISTS club_members_club_id_fkey;
+ALTER TABLE public.club_members DROP CONSTRAINT IF EXISTS club_members_user_id_fkey;
+ALTER TABLE public.club DROP CONSTRAINT IF EXISTS club_owner_fkey;
+ALTER TABLE public.club DROP CONSTRAINT IF EXISTS club_id_key;
+ALTER TABLE public.user DROP CONSTRAINT IF EXISTS user_id_key;
+
+UPDATE public.user SET is_premium = FALSE WHERE is_premium IS NULL;
+ALTER TABLE public.user
+    ALTER COLUMN id SET DEFAULT gen_random_uuid(),
+    ALTER COLUMN is_premium SET DEFAULT FALSE,
+    ALTER COLUMN is_premium SET NOT NULL,
+    ADD CONSTRAINT user_name_key UNIQUE (name);
+
+ALTER TABLE public.club
+    ALTER COLUMN owner SET NOT NULL,
+    ADD CONSTRAINT club_name_key UNIQUE (name),
+    ADD CONSTRAINT club_owner_fkey FOREIGN KEY (owner) REFERENCES public.user (id) ON DELETE RESTRICT;
+
+-- The legacy schema allowed the owner in club_members, where it was counted twice.
+DELETE FROM public.club_members m USING public.club c WHERE c.id = m.club_id AND c.owner = m.user_id;
+ALTER TABLE public.club_members
+    ADD CONSTRAINT club_members_club_id_fkey FOREIGN KEY (club_id) REFERENCES public.club (id) ON DELETE CASCADE,
+    ADD CONSTRAINT club_members_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.user (id) ON DELETE CASCADE;
+-- The owner is counted separately from the members, so it must never appear in club_members.
+CREATE FUNCTION public.club_members_owner_check() RETURNS trigger AS $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM public.club WHERE id = NEW.club_id AND owner = NEW.user_id) THEN
+        RAISE EXCEPTION 'The owner of club % cannot be a member', NEW.club_id
+            USING ERRCODE = 'check_violation', CONSTRAINT = 'club_members_owner_check';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER club_members_owner_check
+    BEFORE INSERT OR UPDATE ON public.club_members
+    FOR EACH ROW EXECUTE FUNCTION public.club_members_owner_check();
+
+CREATE FUNCTION public.club_owner_check() RETURNS trigger AS $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM public.club_members WHERE club_id = NEW.id AND user_id = NEW.owner) THEN
+        RAISE EXCEPTION 'The owner of club % cannot be a member', NEW.id
+            USING ERRCODE = 'check_violation', CONSTRAINT = 'club_owner_check';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER club_owner_check
+    BEFORE UPDATE OF owner ON public.club
+    FOR EACH ROW EXECUTE FUNCTION public.club_owner_check();
@@ -0,0 +1,18 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS user (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    is_premium BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE IF NOT EXISTS club (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    owner TEXT NOT NULL REFERENCES user (id)
+);
+
+CREATE TABLE IF NOT EXISTS club_members (
+    club_id TEXT NOT NULL REFERENCES club (id),
+    user_id TEXT NOT NULL REFERENCES user (id),
+    PRIMARY KEY (club_id, user_id)
+);
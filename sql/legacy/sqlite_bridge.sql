@@ -0,0 +1,52 @@
+-- Brings a database created by sql/legacy/sqlite/20261019090000_init.sql to the
+-- schema of sql/sqlite/20261019120000_schema.sql. Applied by the application,
+-- never by sqlx, and only while the legacy version is recorded. SQLite cannot
+-- alter constraints, so the tables are rebuilt and renamed into place.
+CREATE TABLE user_baseline (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    is_premium BOOLEAN NOT NULL DEFAULT FALSE,
+    CONSTRAINT user_name_key UNIQUE (name)
+);
+
+CREATE TABLE club_baseline (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    owner TEXT NOT NULL REFERENCES user_baseline (id) ON DELETE RESTRICT,
+    CONSTRAINT club_name_key UNIQUE (name)
+);
+
+CREATE TABLE club_members_baseline (
+    club_id TEXT NOT NULL REFERENCES club_baseline (id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES user_baseline (id) ON DELETE CASCADE,
+    PRIMARY KEY (club_id, user_id)
+);
+
+INSERT INTO user_baseline (id, name, is_premium) SELECT id, name, is_premium FROM user;
+INSERT INTO club_baseline (id, name, owner) SELECT id, name, owner FROM club;
+-- The legacy schema allowed the owner in club_members, where it was counted twice.
+INSERT INTO club_members_baseline (club_id, user_id)
+    SELECT m.club_id, m.user_id FROM club_members m JOIN club c ON c.id = m.club_id WHERE c.owner <> m.user_id;
+
+DROP TABLE club_members;
+DROP TABLE club;
+DROP TABLE user;
+
+ALTER TABLE user_baseline RENAME TO user;
+ALTER TABLE club_baseline RENAME TO club;
+ALTER TABLE club_members_baseline RENAME TO club_members;
+
+-- The owner is counted separately from the members, so it must never appear in club_members.
+CREATE TRIGGER club_members_owner_check
+    BEFORE INSERT ON club_members
+    WHEN EXISTS (SELECT 1 FROM club WHERE id = NEW.club_id AND owner = NEW.user_id)
+BEGIN
+    SELECT RAISE(ABORT, 'club_members_owner_check');
+END;
+
+CREATE TRIGGER club_owner_check
+    BEFORE UPDATE OF owner ON club
+    WHEN EXISTS (SELECT 1 FROM club_members WHERE club_id = NEW.id AND user_id = NEW.owner)
+BEGIN
+    SELECT RAISE(ABORT, 'club_owner_check');
+END;
@@ -0,0 +1,53 @@
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+
+CREATE TABLE public.user (
+    id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+    name TEXT NOT NULL,
+    is_premium BOOLEAN NOT NULL DEFAULT FALSE,
+    CONSTRAINT user_name_key UNIQUE (name)
+);
+
+CREATE TABLE public.club (
+    id UUID NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    owner UUID NOT NULL,
+    CONSTRAINT club_name_key UNIQUE (name),
+    CONSTRAINT club_owner_fkey FOREIGN KEY (owner) REFERENCES public.user (id) ON DELETE RESTRICT
+);
+
+CREATE TABLE public.club_members (
+    club_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    PRIMARY KEY (club_id, user_id),
+    CONSTRAINT club_members_club_id_fkey FOREIGN KEY (club_id) REFERENCES public.club (id) ON DELETE CASCADE,
+    CONSTRAINT club_members_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.user (id) ON DELETE CASCADE
+);
+
+-- The owner is counted separately from the members, so it must never appear in club_members.
+CREATE FUNCTION public.club_members_owner_check() RETURNS trigger AS $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM public.club WHERE id = NEW.club_id AND owner = NEW.user_id) THEN
+        RAISE EXCEPTION 'The owner of club % cannot be a member', NEW.club_id
+            USING ERRCODE = 'check_violation', CONSTRAINT = 'club_members_owner_check';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER club_members_owner_check
+    BEFORE INSERT OR UPDATE ON public.club_members
+    FOR EACH ROW EXECUTE FUNCTION public.club_members_owner_check();
+
+CREATE FUNCTION public.club_owner_check() RETURNS trigger AS $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM public.club_members WHERE club_id = NEW.id AND user_id = NEW.owner) THEN
+        RAISE EXCEPTION 'The owner of club % cannot be a member', NEW.id
+            USING ERRCODE = 'check_violation', CONSTRAINT = 'club_owner_check';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER club_owner_check
+    BEFORE UPDATE OF owner ON public.club
+    FOR EACH ROW EXECUTE FUNCTION public.club_owner_check();
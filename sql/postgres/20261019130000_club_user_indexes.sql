@@ -0,0 +1,3 @@
+-- club_members is keyed by (club_id, user_id), which does not help lookups by user.
+CREATE INDEX club_members_user_id_idx ON public.club_members (user_id);
+CREATE INDEX club_owner_idx ON public.club (owner);
@@ -0,0 +1,7 @@
+-- Existing users get the time of the migration as their creation time, and
+-- those who are already premium have been premium since then.
+ALTER TABLE public.user
+    ADD COLUMN created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    ADD COLUMN premium_since TIMESTAMPTZ,
+    ADD COLUMN premium_expires_at TIMESTAMPTZ;
+UPDATE public.user SET premium_since = created_at WHERE is_premium;
@@ -0,0 +1,18 @@
+-- Domain events are written here in the same transaction as the change that
+-- produced them, then published by the outbox relay. `position` keeps the
+-- order in which they were written.
+CREATE TABLE public.outbox (
+    position BIGSERIAL PRIMARY KEY,
+    id UUID NOT NULL,
+    aggregate_id TEXT NOT NULL,
+    event_type TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    published_at TIMESTAMPTZ,
+    CONSTRAINT outbox_id_key UNIQUE (id)
+);
+
+CREATE INDEX outbox_unpublished_idx ON public.outbox (position) WHERE published_at IS NULL;
@@ -0,0 +1,31 @@
+CREATE TABLE public.webhook (
+    id UUID NOT NULL PRIMARY KEY,
+    url TEXT NOT NULL,
+    -- An empty list subscribes to every event.
+    events TEXT[] NOT NULL,
+    secret TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+-- One row per event and webhook: the delivery log, and the queue the
+-- delivery worker reads from while `status` is pending.
+CREATE TABLE public.webhook_delivery (
+    id UUID NOT NULL PRIMARY KEY,
+    webhook_id UUID NOT NULL,
+    event_id UUID NOT NULL,
+    event_type TEXT NOT NULL,
+    body TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    response_status INTEGER,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    delivered_at TIMESTAMPTZ,
+    CONSTRAINT webhook_delivery_event_key UNIQUE (webhook_id, event_id),
+    CONSTRAINT webhook_delivery_status_check CHECK (status IN ('pending', 'delivered', 'failed')),
+    CONSTRAINT webhook_delivery_webhook_id_fkey FOREIGN KEY (webhook_id) REFERENCES public.webhook (id) ON DELETE CASCADE
+);
+
+CREATE INDEX webhook_delivery_pending_idx ON public.webhook_delivery (next_attempt_at) WHERE status = 'pending';
+CREATE INDEX webhook_delivery_created_at_idx ON public.webhook_delivery (webhook_id, created_at);
@@ -0,0 +1,23 @@
+-- The event-sourced club store. Every change to a club is appended to its
+-- stream, numbered from 1 by `version`, and the club is rebuilt by replaying
+-- the stream from its latest snapshot.
+CREATE TABLE public.club_event (
+    club_id UUID NOT NULL,
+    version BIGINT NOT NULL,
+    id UUID NOT NULL,
+    event_type TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (club_id, version),
+    CONSTRAINT club_event_id_key UNIQUE (id)
+);
+
+-- The state of a club after `version` events. Only the latest is kept.
+CREATE TABLE public.club_snapshot (
+    club_id UUID NOT NULL PRIMARY KEY,
+    version BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    owner UUID NOT NULL,
+    members UUID[] NOT NULL,
+    taken_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,16 @@
+-- Who changed which user or club, and how. The snapshots hold the target as
+-- JSON before and after the change; one of them is missing when the command
+-- created or deleted the target.
+CREATE TABLE public.audit_log (
+    id UUID NOT NULL PRIMARY KEY,
+    actor TEXT NOT NULL,
+    action TEXT NOT NULL,
+    target_type TEXT NOT NULL,
+    target_id TEXT NOT NULL,
+    before_snapshot JSONB,
+    after_snapshot JSONB,
+    occurred_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX audit_log_target_idx ON public.audit_log (target_id, occurred_at DESC);
+CREATE INDEX audit_log_occurred_at_idx ON public.audit_log (occurred_at DESC);
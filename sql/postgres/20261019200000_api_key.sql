@@ -0,0 +1,13 @@
+-- Keys other services call the web server with. Only the SHA-256 of a key is
+-- stored; `prefix` keeps its first characters to tell keys apart.
+CREATE TABLE public.api_key (
+    id UUID NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    prefix TEXT NOT NULL,
+    key_hash TEXT NOT NULL,
+    scopes TEXT[] NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    CONSTRAINT api_key_key_hash_key UNIQUE (key_hash)
+);
@@ -0,0 +1,5 @@
+-- Deleted users are kept, hidden from every lookup, until they are purged
+-- after the retention window. They may be restored until then.
+ALTER TABLE public.user ADD COLUMN deleted_at TIMESTAMPTZ;
+
+CREATE INDEX user_deleted_at_idx ON public.user (deleted_at) WHERE deleted_at IS NOT NULL;
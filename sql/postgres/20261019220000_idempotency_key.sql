@@ -0,0 +1,16 @@
+-- Responses to requests made with an Idempotency-Key header, sent again when
+-- the request is retried. A key belongs to the caller in `scope`.
+CREATE TABLE public.idempotency_key (
+    scope TEXT NOT NULL,
+    key TEXT NOT NULL,
+    fingerprint TEXT NOT NULL,
+    response_status INTEGER,
+    response_headers JSONB,
+    response_body BYTEA,
+    created_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    locked_until TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (scope, key)
+);
+
+CREATE INDEX idempotency_key_expires_at_idx ON public.idempotency_key (expires_at);
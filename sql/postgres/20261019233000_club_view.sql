@@ -0,0 +1,14 @@
+-- The current state of every event-sourced club, written with its events.
+-- Club lists are read from here instead of replaying every stream, and the
+-- unique name keeps two streams from claiming the same club name.
+CREATE TABLE public.club_view (
+    club_id UUID NOT NULL PRIMARY KEY,
+    version BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    owner UUID NOT NULL,
+    members UUID[] NOT NULL,
+    CONSTRAINT club_view_name_key UNIQUE (name)
+);
+
+CREATE INDEX club_view_owner_idx ON public.club_view (owner);
+CREATE INDEX club_view_members_idx ON public.club_view USING GIN (members);
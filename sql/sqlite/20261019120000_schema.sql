@@ -0,0 +1,34 @@
+CREATE TABLE user (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    is_premium BOOLEAN NOT NULL DEFAULT FALSE,
+    CONSTRAINT user_name_key UNIQUE (name)
+);
+
+CREATE TABLE club (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    owner TEXT NOT NULL REFERENCES user (id) ON DELETE RESTRICT,
+    CONSTRAINT club_name_key UNIQUE (name)
+);
+
+CREATE TABLE club_members (
+    club_id TEXT NOT NULL REFERENCES club (id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES user (id) ON DELETE CASCADE,
+    PRIMARY KEY (club_id, user_id)
+);
+
+-- The owner is counted separately from the members, so it must never appear in club_members.
+CREATE TRIGGER club_members_owner_check
+    BEFORE INSERT ON club_members
+    WHEN EXISTS (SELECT 1 FROM club WHERE id = NEW.club_id AND owner = NEW.user_id)
+BEGIN
+    SELECT RAISE(ABORT, 'club_members_owner_check');
+END;
+
+CREATE TRIGGER club_owner_check
+    BEFORE UPDATE OF owner ON club
+    WHEN EXISTS (SELECT 1 FROM club_members WHERE club_id = NEW.id AND user_id = NEW.owner)
+BEGIN
+    SELECT RAISE(ABORT, 'club_owner_check');
+END;
@@ -0,0 +1,8 @@
+-- SQLite cannot add a column with a non-constant default, so existing users
+-- get the time of the migration as their creation time, and those who are
+-- already premium have been premium since then.
+ALTER TABLE user ADD COLUMN created_at TEXT NOT NULL DEFAULT '1970-01-01 00:00:00';
+UPDATE user SET created_at = strftime('%Y-%m-%d %H:%M:%S', 'now');
+ALTER TABLE user ADD COLUMN premium_since TEXT;
+ALTER TABLE user ADD COLUMN premium_expires_at TEXT;
+UPDATE user SET premium_since = created_at WHERE is_premium;
@@ -0,0 +1,29 @@
+CREATE TABLE webhook (
+    id TEXT NOT NULL PRIMARY KEY,
+    url TEXT NOT NULL,
+    -- A JSON array of event names. An empty array subscribes to every event.
+    events TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+-- One row per event and webhook: the delivery log, and the queue the
+-- delivery worker reads from while `status` is pending.
+CREATE TABLE webhook_delivery (
+    id TEXT NOT NULL PRIMARY KEY,
+    webhook_id TEXT NOT NULL REFERENCES webhook (id) ON DELETE CASCADE,
+    event_id TEXT NOT NULL,
+    event_type TEXT NOT NULL,
+    body TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'delivered', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    response_status INTEGER,
+    last_error TEXT,
+    next_attempt_at TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    delivered_at TEXT,
+    CONSTRAINT webhook_delivery_event_key UNIQUE (webhook_id, event_id)
+);
+
+CREATE INDEX webhook_delivery_pending_idx ON webhook_delivery (next_attempt_at) WHERE status = 'pending';
+CREATE INDEX webhook_delivery_created_at_idx ON webhook_delivery (webhook_id, created_at);
@@ -0,0 +1,24 @@
+-- The event-sourced club store. Every change to a club is appended to its
+-- stream, numbered from 1 by `version`, and the club is rebuilt by replaying
+-- the stream from its latest snapshot.
+CREATE TABLE club_event (
+    club_id TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    id TEXT NOT NULL,
+    event_type TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    occurred_at TEXT NOT NULL,
+    PRIMARY KEY (club_id, version),
+    CONSTRAINT club_event_id_key UNIQUE (id)
+);
+
+-- The state of a club after `version` events. Only the latest is kept.
+-- `members` is a JSON array of user ids.
+CREATE TABLE club_snapshot (
+    club_id TEXT NOT NULL PRIMARY KEY,
+    version INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    members TEXT NOT NULL,
+    taken_at TEXT NOT NULL
+);
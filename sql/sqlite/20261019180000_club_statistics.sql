@@ -0,0 +1,29 @@
+-- The club statistics projection, kept up to date from the domain events by
+-- the outbox relay. The counts leave out the owner. There is no foreign key
+-- to club because event-sourced clubs are not stored there.
+CREATE TABLE club_statistics (
+    club_id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    member_count INTEGER NOT NULL,
+    premium_member_count INTEGER NOT NULL,
+    last_joined_at TEXT,
+    updated_at TEXT NOT NULL
+);
+
+-- Existing clubs of the state-based store are counted right away. Clubs of
+-- the event-sourced store appear after `club stats rebuild`.
+INSERT INTO club_statistics
+SELECT
+    c.id,
+    c.name,
+    c.owner,
+    count(m.user_id),
+    count(m.user_id) FILTER (WHERE u.is_premium),
+    (SELECT max(o.occurred_at) FROM outbox o
+        WHERE o.event_type = 'MemberJoined' AND o.aggregate_id = c.id),
+    strftime('%Y-%m-%d %H:%M:%S', 'now')
+FROM club c
+LEFT JOIN club_members m ON m.club_id = c.id
+LEFT JOIN user u ON u.id = m.user_id
+GROUP BY c.id;
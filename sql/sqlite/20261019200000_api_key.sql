@@ -0,0 +1,13 @@
+-- Keys other services call the web server with. Only the SHA-256 of a key is
+-- stored; `prefix` keeps its first characters to tell keys apart.
+CREATE TABLE api_key (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    prefix TEXT NOT NULL,
+    key_hash TEXT NOT NULL UNIQUE,
+    -- A JSON array of scope names.
+    scopes TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    last_used_at TEXT,
+    revoked_at TEXT
+);
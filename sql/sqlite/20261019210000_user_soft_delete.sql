@@ -0,0 +1,5 @@
+-- Deleted users are kept, hidden from every lookup, until they are purged
+-- after the retention window. They may be restored until then.
+ALTER TABLE user ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX user_deleted_at_idx ON user (deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,14 @@
+-- The current state of every event-sourced club, written with its events.
+-- Club lists are read from here instead of replaying every stream, and the
+-- unique name keeps two streams from claiming the same club name.
+-- `members` is a JSON array of user ids.
+CREATE TABLE club_view (
+    club_id TEXT NOT NULL PRIMARY KEY,
+    version INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    members TEXT NOT NULL,
+    CONSTRAINT club_view_name_key UNIQUE (name)
+);
+
+CREATE INDEX club_view_owner_idx ON club_view (owner);
@@ -0,0 +1,17 @@
+-- Responses to requests made with an Idempotency-Key header, sent again when
+-- the request is retried. A key belongs to the caller in `scope`.
+CREATE TABLE idempotency_key (
+    scope TEXT NOT NULL,
+    key TEXT NOT NULL,
+    fingerprint TEXT NOT NULL,
+    response_status INTEGER,
+    -- A JSON array of [name, value] pairs.
+    response_headers TEXT,
+    response_body BLOB,
+    created_at TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    locked_until TEXT NOT NULL,
+    PRIMARY KEY (scope, key)
+);
+
+CREATE INDEX idempotency_key_expires_at_idx ON idempotency_key (expires_at);
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::model::{
+    api_key::{entity::ApiKey, repository::ApiKeyRepositoryTrait},
+    audit::entity::Actor,
+    error::DomainError,
+};
+
+/// How stale the last use of a key may get before it is written again, so a
+/// busy key does not cost a write on every request.
+const TOUCH_INTERVAL_SECS: i64 = 60;
+
+pub struct ApiKeyAuthenticateService {
+    api_key_repository: Arc<Mutex<dyn ApiKeyRepositoryTrait + Send + Sync>>,
+}
+
+impl ApiKeyAuthenticateService {
+    pub fn new(api_key_repository: Arc<Mutex<dyn ApiKeyRepositoryTrait + Send + Sync>>) -> Self {
+        Self { api_key_repository }
+    }
+
+    /// The actor a request made with `key` acts as. Unknown and revoked keys
+    /// are rejected alike.
+    pub async fn handle(&self, key: &str) -> Result<Actor> {
+        let repo = self.api_key_repository.lock().await;
+        let api_key = repo
+            .find_by_hash(&ApiKey::hash(key))
+            .await?
+            .filter(|k| !k.is_revoked())
+            .ok_or_else(|| DomainError::Unauthorized("The API key is not valid.".to_string()))?;
+
+        let now = Utc::now();
+        let stale = api_key
+            .get_last_used_at()
+            .is_none_or(|t| now - *t >= Duration::seconds(TOUCH_INTERVAL_SECS));
+        if stale {
+            repo.touch(api_key.get_id(), now).await?;
+        }
+
+        Ok(Actor::ApiKey {
+            id: api_key.get_id().clone(),
+            scopes: api_key.get_scopes().to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::application::api_key::{
+        ApiKeyCreateCommand, ApiKeyCreateService, ApiKeyRevokeService,
+    };
+    use crate::domain::model::api_key::{entity::ApiKeyScope, factory::ApiKeyFactory};
+    use crate::infrastructure::database::api_key::InMemoryApiKeyDatabase;
+    use crate::interface::repository::api_key::ApiKeyRepository;
+
+    #[tokio::test]
+    async fn a_revoked_key_no_longer_authenticates() -> Result<()> {
+        let api_key_repository = Arc::new(Mutex::new(
+            ApiKeyRepository::new(Box::new(InMemoryApiKeyDatabase::new())).await?,
+        ));
+        let create_repository = Arc::clone(&api_key_repository);
+        let create_service =
+            ApiKeyCreateService::new(create_repository, Arc::new(ApiKeyFactory::new()));
+        let revoke_repository = Arc::clone(&api_key_repository);
+        let revoke_service = ApiKeyRevokeService::new(revoke_repository);
+        let authenticate_service = ApiKeyAuthenticateService::new(api_key_repository);
+
+        let created = create_service
+            .handle(ApiKeyCreateCommand::new(
+                "billing",
+                &["premium:write".to_string()],
+            ))
+            .await?;
+        let key = created.key.expect("the key is returned on creation");
+
+        match authenticate_service.handle(&key).await? {
+            Actor::ApiKey { id, scopes } => {
+                assert_eq!(id.to_string(), created.id);
+                assert_eq!(scopes, vec![ApiKeyScope::PremiumWrite]);
+            }
+            actor => panic!("unexpected actor {}", actor),
+        }
+
+        revoke_service.handle(&created.id).await?;
+        let error = authenticate_service.handle(&key).await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<DomainError>(),
+            Some(DomainError::Unauthorized(_))
+        ));
+        Ok(())
+    }
+}
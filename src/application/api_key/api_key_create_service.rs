@@ -0,0 +1,60 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use super::ApiKeyData;
+use crate::domain::model::api_key::{
+    entity::{ApiKeyName, ApiKeyScope},
+    factory::ApiKeyFactoryTrait,
+    repository::ApiKeyRepositoryTrait,
+};
+
+pub struct ApiKeyCreateService {
+    api_key_repository: Arc<Mutex<dyn ApiKeyRepositoryTrait + Send + Sync>>,
+    api_key_factory: Arc<dyn ApiKeyFactoryTrait + Send + Sync>,
+}
+
+pub struct ApiKeyCreateCommand {
+    name: String,
+    scopes: Vec<String>,
+}
+
+impl ApiKeyCreateCommand {
+    pub fn new(name: &str, scopes: &[String]) -> Self {
+        Self {
+            name: name.to_string(),
+            scopes: scopes.to_vec(),
+        }
+    }
+}
+
+impl ApiKeyCreateService {
+    pub fn new(
+        api_key_repository: Arc<Mutex<dyn ApiKeyRepositoryTrait + Send + Sync>>,
+        api_key_factory: Arc<dyn ApiKeyFactoryTrait + Send + Sync>,
+    ) -> Self {
+        Self {
+            api_key_repository,
+            api_key_factory,
+        }
+    }
+
+    /// Creates the key. The result is the only place the plain text key is
+    /// returned.
+    pub async fn handle(&self, command: ApiKeyCreateCommand) -> Result<ApiKeyData> {
+        let name = ApiKeyName::new(&command.name)?;
+        let scopes = command
+            .scopes
+            .iter()
+            .map(|s| ApiKeyScope::parse(s))
+            .collect::<Result<Vec<_>>>()?;
+
+        let (api_key, key) = self.api_key_factory.create(name, &scopes)?;
+        self.api_key_repository.lock().await.save(&api_key).await?;
+
+        Ok(ApiKeyData {
+            key: Some(key),
+            ..ApiKeyData::new(&api_key)
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::model::api_key::{entity::ApiKey, repository::ApiKeyRepositoryTrait};
+
+pub struct ApiKeyListService {
+    api_key_repository: Arc<Mutex<dyn ApiKeyRepositoryTrait + Send + Sync>>,
+}
+
+#[derive(Debug)]
+pub struct ApiKeyData {
+    pub id: String,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    /// The plain text key, only known right after the key is created.
+    pub key: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKeyData {
+    pub fn new(api_key: &ApiKey) -> Self {
+        Self {
+            id: api_key.get_id().to_string(),
+            name: api_key.get_name().to_string(),
+            prefix: api_key.get_prefix().to_string(),
+            scopes: api_key
+                .get_scopes()
+                .iter()
+                .map(|s| s.as_str().to_string())
+                .collect(),
+            key: None,
+            created_at: *api_key.get_created_at(),
+            last_used_at: api_key.get_last_used_at().copied(),
+            revoked_at: api_key.get_revoked_at().copied(),
+        }
+    }
+}
+
+impl ApiKeyListService {
+    pub fn new(api_key_repository: Arc<Mutex<dyn ApiKeyRepositoryTrait + Send + Sync>>) -> Self {
+        Self { api_key_repository }
+    }
+
+    pub async fn handle(&self) -> Result<Vec<ApiKeyData>> {
+        let repo = self.api_key_repository.lock().await;
+        Ok(repo.find_all().await?.iter().map(ApiKeyData::new).collect())
+    }
+}
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use super::ApiKeyData;
+use crate::domain::model::{
+    api_key::{entity::ApiKeyId, repository::ApiKeyRepositoryTrait},
+    error::DomainError,
+};
+
+pub struct ApiKeyRevokeService {
+    api_key_repository: Arc<Mutex<dyn ApiKeyRepositoryTrait + Send + Sync>>,
+}
+
+impl ApiKeyRevokeService {
+    pub fn new(api_key_repository: Arc<Mutex<dyn ApiKeyRepositoryTrait + Send + Sync>>) -> Self {
+        Self { api_key_repository }
+    }
+
+    /// Revokes the key for good; requests made with it are rejected from then
+    /// on.
+    pub async fn handle(&self, id: &str) -> Result<ApiKeyData> {
+        let id = ApiKeyId::new(id)?;
+        let repo = self.api_key_repository.lock().await;
+        let mut api_key = repo
+            .find_by_id(&id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Could not find the API key.".to_string()))?;
+
+        api_key.revoke()?;
+        repo.save(&api_key).await?;
+
+        Ok(ApiKeyData::new(&api_key))
+    }
+}
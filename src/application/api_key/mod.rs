@@ -0,0 +1,9 @@
+mod api_key_authenticate_service;
+mod api_key_create_service;
+mod api_key_list_service;
+mod api_key_revoke_service;
+
+pub use api_key_authenticate_service::ApiKeyAuthenticateService;
+pub use api_key_create_service::{ApiKeyCreateCommand, ApiKeyCreateService};
+pub use api_key_list_service::{ApiKeyData, ApiKeyListService};
+pub use api_key_revoke_service::ApiKeyRevokeService;
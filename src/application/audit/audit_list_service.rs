@@ -0,0 +1,75 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::{entity::Actor, repository::AuditRepositoryTrait},
+    error::DomainError,
+};
+
+const DEFAULT_LIMIT: usize = 50;
+const MAX_LIMIT: usize = 500;
+
+pub struct AuditListService {
+    audit_repository: Arc<dyn AuditRepositoryTrait + Send + Sync>,
+}
+
+#[derive(Debug)]
+pub struct AuditEntryData {
+    pub id: String,
+    pub actor: String,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AuditListService {
+    pub fn new(audit_repository: Arc<dyn AuditRepositoryTrait + Send + Sync>) -> Self {
+        Self { audit_repository }
+    }
+
+    /// The latest entries, newest first, only those about `target` when it
+    /// is given.
+    pub async fn handle(
+        &self,
+        actor: &Actor,
+        target: Option<&str>,
+        limit: Option<usize>,
+    ) -> Result<Vec<AuditEntryData>> {
+        actor.authorize(ApiKeyScope::AuditRead, &[])?;
+        let limit = match limit {
+            None => DEFAULT_LIMIT,
+            Some(limit) if (1..=MAX_LIMIT).contains(&limit) => limit,
+            Some(_) => {
+                return Err(DomainError::invalid_field(
+                    "limit",
+                    "range",
+                    &format!("limit must be between 1 and {}", MAX_LIMIT),
+                )
+                .into())
+            }
+        };
+
+        Ok(self
+            .audit_repository
+            .find(target, limit)
+            .await?
+            .into_iter()
+            .map(|e| AuditEntryData {
+                id: e.id,
+                actor: e.actor.to_string(),
+                action: e.action,
+                target_type: e.target_type,
+                target_id: e.target_id,
+                before: e.before,
+                after: e.after,
+                occurred_at: e.occurred_at,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::Utc;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::domain::model::{
+    audit::entity::{Actor, AuditEntry},
+    club::entity::Club,
+    user::entity::User,
+};
+
+/// An aggregate whose changes are audited.
+pub trait Auditable {
+    const TARGET_TYPE: &'static str;
+
+    fn audit_id(&self) -> String;
+    fn audit_snapshot(&self) -> Value;
+}
+
+impl Auditable for User {
+    const TARGET_TYPE: &'static str = "user";
+
+    fn audit_id(&self) -> String {
+        self.get_id().to_string()
+    }
+
+    fn audit_snapshot(&self) -> Value {
+        json!({
+            "id": self.get_id().to_string(),
+            "name": self.get_name().to_string(),
+            "is_premium": self.get_is_premium().to_inner(),
+            "premium_since": self.get_premium_since().map(|t| t.to_rfc3339()),
+            "premium_expires_at": self.get_premium_expires_at().map(|t| t.to_rfc3339()),
+        })
+    }
+}
+
+impl Auditable for Club {
+    const TARGET_TYPE: &'static str = "club";
+
+    fn audit_id(&self) -> String {
+        self.get_id().to_string()
+    }
+
+    fn audit_snapshot(&self) -> Value {
+        json!({
+            "id": self.get_id().to_string(),
+            "name": self.get_name().to_string(),
+            "owner": self.get_owner_id().to_string(),
+            "members": self
+                .get_members()
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// The audit entry of `actor` changing the target from `before` to `after`,
+/// to be saved along with the change. One of them must be given.
+pub fn audit_entry<T: Auditable>(
+    actor: &Actor,
+    action: &str,
+    before: Option<&T>,
+    after: Option<&T>,
+) -> AuditEntry {
+    let target_id = after
+        .or(before)
+        .map(Auditable::audit_id)
+        .expect("an audit entry needs a target");
+    AuditEntry {
+        id: Uuid::new_v4().to_string(),
+        actor: actor.clone(),
+        action: action.to_string(),
+        target_type: T::TARGET_TYPE.to_string(),
+        target_id,
+        before: before.map(Auditable::audit_snapshot),
+        after: after.map(Auditable::audit_snapshot),
+        occurred_at: Utc::now(),
+    }
+}
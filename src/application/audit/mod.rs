@@ -0,0 +1,4 @@
+mod audit_list_service;
+mod auditable;
+
+pub use self::{audit_list_service::*, auditable::*};
@@ -1,38 +1,45 @@
-use anyhow::{anyhow, Result};
-use std::sync::{Arc, Mutex};
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
+use crate::application::{audit::audit_entry, club::ClubInfo, user::UserData};
 use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
     club::{
         entity::ClubName, factory::ClubFactoryTrait, repository::ClubRepositoryTrait,
         service::ClubService,
     },
+    error::DomainError,
     user::{entity::UserId, repository::UserRepositoryTrait},
 };
 
 pub struct ClubCreateService {
-    club_repository: Arc<Mutex<dyn ClubRepositoryTrait>>,
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
     club_factory: Arc<dyn ClubFactoryTrait>,
     club_service: Arc<ClubService>,
     user_repository: Arc<dyn UserRepositoryTrait>,
 }
 
 pub struct ClubCreateCommand {
+    actor: Actor,
     user_id: String,
     name: String,
 }
 
 impl ClubCreateCommand {
-    pub fn new(user_id: &str, name: &str) -> Self {
+    pub fn new(actor: &Actor, user_id: &str, name: &str) -> Self {
         Self {
+            actor: actor.clone(),
             user_id: user_id.to_string(),
             name: name.to_string(),
         }
     }
 }
 
-impl<'a> ClubCreateService {
+impl ClubCreateService {
     pub fn new(
-        club_repository: Arc<Mutex<dyn ClubRepositoryTrait>>,
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
         club_factory: Arc<dyn ClubFactoryTrait>,
         club_service: Arc<ClubService>,
         user_repository: Arc<dyn UserRepositoryTrait>,
@@ -45,26 +52,37 @@ impl<'a> ClubCreateService {
         }
     }
 
-    pub async fn handle(&self, command: ClubCreateCommand) -> Result<()> {
+    /// Creates a club and returns it as `ClubGetInfoService` would.
+    pub async fn handle(&self, command: ClubCreateCommand) -> Result<ClubInfo> {
         let user_id = UserId::new(&command.user_id)?;
+        command
+            .actor
+            .authorize(ApiKeyScope::ClubsWrite, &[&user_id])?;
         let user_repo = Arc::clone(&self.user_repository);
         let owner = user_repo
             .find_by_id(&user_id)
             .await?
-            .ok_or_else(|| anyhow!("User not found"))?;
+            .ok_or_else(|| DomainError::NotFound("User not found".to_string()))?;
 
         let name = ClubName::new(&command.name)?;
         let club_factory = Arc::clone(&self.club_factory);
-        let club = club_factory.create(name, owner)?;
+        let mut club = club_factory.create(name, owner.clone())?;
 
         let club_service = Arc::clone(&self.club_service);
         if club_service.exists(&club).await {
-            return Err(anyhow!("Club already exists"));
+            return Err(DomainError::Conflict("Club already exists".to_string()).into());
         }
 
         let club_repo = Arc::clone(&self.club_repository);
-        let club_repo = club_repo.lock().unwrap();
+        let club_repo = club_repo.lock().await;
 
-        club_repo.save(&club).await
+        let entry = audit_entry(&command.actor, "club.create", None, Some(&club));
+        club_repo.save_audited(&mut club, &entry).await?;
+        Ok(ClubInfo {
+            id: club.get_id().to_string(),
+            name: club.get_name().to_string(),
+            owner: Some(UserData::new(&owner)),
+            members: Vec::new(),
+        })
     }
 }
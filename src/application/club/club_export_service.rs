@@ -0,0 +1,69 @@
+use anyhow::Result;
+use std::{collections::HashSet, sync::Arc};
+use tokio::sync::Mutex;
+
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope, audit::entity::Actor, club::repository::ClubRepositoryTrait,
+    user::repository::UserRepositoryTrait,
+};
+
+/// How many members are looked up at once, to stay below the databases'
+/// limits on query parameters.
+const EXPORT_LOOKUP_SIZE: usize = 500;
+
+pub struct ClubExportService {
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+    user_repository: Arc<dyn UserRepositoryTrait>,
+}
+
+#[derive(Debug)]
+pub struct ClubExportData {
+    pub id: String,
+    pub name: String,
+    pub owner: String,
+    pub members: Vec<String>,
+}
+
+impl ClubExportService {
+    pub fn new(
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+        user_repository: Arc<dyn UserRepositoryTrait>,
+    ) -> Self {
+        Self {
+            club_repository,
+            user_repository,
+        }
+    }
+
+    /// Every club with the members who are not deleted, as the user export
+    /// leaves deleted users out.
+    pub async fn handle(&self, actor: &Actor) -> Result<Vec<ClubExportData>> {
+        actor.authorize(ApiKeyScope::ExportRead, &[])?;
+        let repo = self.club_repository.lock().await;
+        let clubs = repo.find_all().await?;
+        let members = clubs
+            .iter()
+            .flat_map(|c| c.get_members().iter().cloned())
+            .collect::<Vec<_>>();
+        let mut active = HashSet::new();
+        for chunk in members.chunks(EXPORT_LOOKUP_SIZE) {
+            let users = self.user_repository.batch_find(chunk.to_vec()).await?;
+            active.extend(users.iter().map(|u| u.get_id().to_string()));
+        }
+
+        Ok(clubs
+            .iter()
+            .map(|c| ClubExportData {
+                id: c.get_id().to_string(),
+                name: c.get_name().to_string(),
+                owner: c.get_owner_id().to_string(),
+                members: c
+                    .get_members()
+                    .iter()
+                    .map(|m| m.to_string())
+                    .filter(|m| active.contains(m))
+                    .collect(),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,56 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::application::user::UserData;
+use crate::domain::model::{
+    club::{entity::ClubId, repository::ClubRepositoryTrait},
+    user::{entity::UserId, repository::UserRepositoryTrait},
+};
+
+pub struct ClubGetInfoService {
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+    user_repository: Arc<dyn UserRepositoryTrait>,
+}
+
+#[derive(Debug)]
+pub struct ClubInfo {
+    pub id: String,
+    pub name: String,
+    pub owner: Option<UserData>,
+    pub members: Vec<UserData>,
+}
+
+impl ClubGetInfoService {
+    pub fn new(
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+        user_repository: Arc<dyn UserRepositoryTrait>,
+    ) -> Self {
+        Self {
+            club_repository,
+            user_repository,
+        }
+    }
+
+    pub async fn handle(&self, club_id: &str) -> Result<Option<ClubInfo>> {
+        let club_id = ClubId::new(club_id)?;
+        let club_repo = self.club_repository.lock().await;
+        let club = match club_repo.find_by_id(&club_id).await? {
+            Some(club) => club,
+            None => return Ok(None),
+        };
+
+        // Resolve the owner together with the members in a single lookup.
+        let mut ids = vec![club.get_owner_id().to_owned()];
+        ids.extend(club.get_members().iter().cloned());
+        let users = self.user_repository.batch_find(ids).await?;
+        let resolve = |id: &UserId| users.iter().find(|u| u.get_id() == id).map(UserData::new);
+
+        Ok(Some(ClubInfo {
+            id: club.get_id().to_string(),
+            name: club.get_name().to_string(),
+            owner: resolve(club.get_owner_id()),
+            members: club.get_members().iter().filter_map(resolve).collect(),
+        }))
+    }
+}
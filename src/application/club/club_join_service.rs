@@ -1,25 +1,32 @@
-use anyhow::{anyhow, Result};
-use std::sync::{Arc, Mutex};
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
+use crate::application::audit::audit_entry;
 use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
     club::{
-        entity::{ClubId, ClubMembers},
+        entity::ClubId,
         factory::ClubFactoryTrait,
-        repository::ClubRepositoryTrait,
+        repository::{ClubRepositoryTrait, ClubStatisticsRepositoryTrait},
         service::ClubService,
         specifications::ClubMembersFullSpec,
     },
+    error::DomainError,
     user::{entity::UserId, repository::UserRepositoryTrait},
 };
 
 pub struct ClubJoinCommand {
+    actor: Actor,
     user_id: String,
     club_id: String,
 }
 
 impl ClubJoinCommand {
-    pub fn new(user_id: &str, club_id: &str) -> Self {
+    pub fn new(actor: &Actor, user_id: &str, club_id: &str) -> Self {
         Self {
+            actor: actor.clone(),
             user_id: user_id.to_string(),
             club_id: club_id.to_string(),
         }
@@ -27,24 +34,27 @@ impl ClubJoinCommand {
 }
 
 pub struct ClubJoinService {
-    club_repository: Arc<Mutex<dyn ClubRepositoryTrait>>,
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
     club_factory: Arc<dyn ClubFactoryTrait>,
     club_service: Arc<ClubService>,
     user_repository: Arc<dyn UserRepositoryTrait>,
+    club_statistics_repository: Arc<dyn ClubStatisticsRepositoryTrait + Send + Sync>,
 }
 
-impl<'a> ClubJoinService {
+impl ClubJoinService {
     pub fn new(
-        club_repository: Arc<Mutex<dyn ClubRepositoryTrait>>,
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
         club_factory: Arc<dyn ClubFactoryTrait>,
         club_service: Arc<ClubService>,
         user_repository: Arc<dyn UserRepositoryTrait>,
+        club_statistics_repository: Arc<dyn ClubStatisticsRepositoryTrait + Send + Sync>,
     ) -> Self {
         Self {
             club_repository,
             club_factory,
             club_service,
             user_repository,
+            club_statistics_repository,
         }
     }
 
@@ -54,30 +64,45 @@ impl<'a> ClubJoinService {
         let user = user_repo
             .find_by_id(&member_id)
             .await?
-            .ok_or_else(|| anyhow!("Could not find the user"))?;
+            .ok_or_else(|| DomainError::NotFound("Could not find the user".to_string()))?;
 
         let club_id = ClubId::new(&command.club_id)?;
         let club_repo = Arc::clone(&self.club_repository);
-        let club_repo = club_repo.lock().unwrap();
+        let club_repo = club_repo.lock().await;
         let mut club = club_repo
             .find_by_id(&club_id)
             .await?
-            .ok_or_else(|| anyhow!("Could not find the club"))?;
+            .ok_or_else(|| DomainError::NotFound("Could not find the club".to_string()))?;
+        command
+            .actor
+            .authorize(ApiKeyScope::ClubsWrite, &[&member_id, club.get_owner_id()])?;
 
-        let user_repo = Arc::clone(&self.user_repository);
-        let owner = user_repo
-            .find_by_id(club.get_owner_id())
-            .await?
-            .ok_or_else(|| anyhow!("Could not find the owner of this club."))?;
-        let members = user_repo.batch_find(club.get_members().clone()).await?;
-        let club_members = ClubMembers::new(club_id, owner, members);
+        // The projection may lag behind the club by a few events, so the member
+        // count comes from the club itself and only the premium count, which
+        // would need every member loaded, from the projection. Deleted members
+        // keep their seats until they are purged, since restoring them brings
+        // them back into the club.
+        let premium_member_count =
+            match self.club_statistics_repository.find_by_id(&club_id).await? {
+                Some(statistics) => statistics.premium_member_count,
+                None => self
+                    .user_repository
+                    .batch_find(club.get_members().clone())
+                    .await?
+                    .iter()
+                    .filter(|m| m.get_is_premium().to_inner())
+                    .count(),
+            };
         let club_full_spec = ClubMembersFullSpec::new();
-        if club_full_spec.is_satisfied_by(club_members) {
-            return Err(anyhow!("Club is already full."));
+        if club_full_spec.is_satisfied_by_counts(club.get_members().len(), premium_member_count) {
+            return Err(DomainError::Conflict("Club is already full.".to_string()).into());
         }
 
+        let before = club.clone();
         club.join(user)?;
 
-        club_repo.save(&club).await
+        let entry = audit_entry(&command.actor, "club.join", Some(&before), Some(&club));
+        club_repo.save_audited(&mut club, &entry).await?;
+        Ok(())
     }
 }
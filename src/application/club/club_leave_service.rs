@@ -0,0 +1,59 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::application::audit::audit_entry;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    club::{entity::ClubId, repository::ClubRepositoryTrait},
+    error::DomainError,
+    user::entity::UserId,
+};
+
+pub struct ClubLeaveCommand {
+    actor: Actor,
+    user_id: String,
+    club_id: String,
+}
+
+impl ClubLeaveCommand {
+    pub fn new(actor: &Actor, user_id: &str, club_id: &str) -> Self {
+        Self {
+            actor: actor.clone(),
+            user_id: user_id.to_string(),
+            club_id: club_id.to_string(),
+        }
+    }
+}
+
+pub struct ClubLeaveService {
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+}
+
+impl ClubLeaveService {
+    pub fn new(club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>) -> Self {
+        Self { club_repository }
+    }
+
+    pub async fn handle(&self, command: ClubLeaveCommand) -> Result<()> {
+        let member_id = UserId::new(&command.user_id)?;
+        let club_id = ClubId::new(&command.club_id)?;
+
+        let club_repo = self.club_repository.lock().await;
+        let mut club = club_repo
+            .find_by_id(&club_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Could not find the club".to_string()))?;
+        command
+            .actor
+            .authorize(ApiKeyScope::ClubsWrite, &[&member_id, club.get_owner_id()])?;
+
+        let before = club.clone();
+        club.leave(&member_id)?;
+
+        let entry = audit_entry(&command.actor, "club.leave", Some(&before), Some(&club));
+        club_repo.save_audited(&mut club, &entry).await?;
+        Ok(())
+    }
+}
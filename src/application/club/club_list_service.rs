@@ -0,0 +1,157 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, sync::Arc};
+use tokio::sync::Mutex;
+
+use crate::application::shared::{decode_cursor, encode_cursor};
+use crate::domain::model::{
+    club::{
+        entity::{Club, ClubId, ClubName},
+        repository::{ClubListCursor, ClubListQuery, ClubRepositoryTrait},
+    },
+    error::DomainError,
+    user::repository::UserRepositoryTrait,
+};
+
+const DEFAULT_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 100;
+
+pub struct ClubListService {
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+    user_repository: Arc<dyn UserRepositoryTrait>,
+}
+
+pub struct ClubListCommand {
+    cursor: Option<String>,
+    limit: Option<usize>,
+    name: Option<String>,
+}
+
+impl ClubListCommand {
+    pub fn new(cursor: Option<&str>, limit: Option<usize>, name: Option<&str>) -> Self {
+        Self {
+            cursor: cursor.map(|x| x.to_string()),
+            limit,
+            name: name.map(|x| x.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ClubSummary {
+    pub id: String,
+    pub name: String,
+    pub owner: String,
+    pub member_count: usize,
+}
+
+impl ClubSummary {
+    /// Summarizes `club`, counting only the members in `active`, the ids of
+    /// the users who are not deleted.
+    pub fn new(club: &Club, active: &HashSet<String>) -> Self {
+        Self {
+            id: club.get_id().to_string(),
+            name: club.get_name().to_string(),
+            owner: club.get_owner_id().to_string(),
+            member_count: club
+                .get_members()
+                .iter()
+                .filter(|m| active.contains(&m.to_string()))
+                .count(),
+        }
+    }
+
+    /// Summarizes `clubs`, looking up which of their members are not deleted
+    /// in a single lookup.
+    pub async fn summarize(
+        clubs: &[Club],
+        user_repository: &dyn UserRepositoryTrait,
+    ) -> Result<Vec<Self>> {
+        let members = clubs
+            .iter()
+            .flat_map(|c| c.get_members().iter().cloned())
+            .collect();
+        let active = user_repository
+            .batch_find(members)
+            .await?
+            .iter()
+            .map(|u| u.get_id().to_string())
+            .collect();
+        Ok(clubs.iter().map(|c| Self::new(c, &active)).collect())
+    }
+}
+
+#[derive(Debug)]
+pub struct ClubListResult {
+    pub clubs: Vec<ClubSummary>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Cursor {
+    id: String,
+    name: String,
+}
+
+impl ClubListService {
+    pub fn new(
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+        user_repository: Arc<dyn UserRepositoryTrait>,
+    ) -> Self {
+        Self {
+            club_repository,
+            user_repository,
+        }
+    }
+
+    pub async fn handle(&self, command: ClubListCommand) -> Result<ClubListResult> {
+        let limit = match command.limit {
+            None => DEFAULT_LIMIT,
+            Some(limit) if (1..=MAX_LIMIT).contains(&limit) => limit,
+            Some(_) => {
+                return Err(DomainError::invalid_field(
+                    "limit",
+                    "range",
+                    &format!("limit must be between 1 and {}", MAX_LIMIT),
+                )
+                .into())
+            }
+        };
+        let after = match command.cursor {
+            Some(cursor) => {
+                let cursor = decode_cursor::<Cursor>(&cursor)?;
+                Some(ClubListCursor {
+                    id: ClubId::new(&cursor.id)?,
+                    name: ClubName::new(&cursor.name)?,
+                })
+            }
+            None => None,
+        };
+
+        // Fetch one extra club to find out whether there is a next page.
+        let query = ClubListQuery {
+            name_contains: command.name.filter(|n| !n.is_empty()),
+            after,
+            limit: limit + 1,
+        };
+        let repo = self.club_repository.lock().await;
+        let mut clubs = repo.find_page(&query).await?;
+
+        let next_cursor = if clubs.len() > limit {
+            clubs.truncate(limit);
+            clubs.last().map(|c| {
+                encode_cursor(&Cursor {
+                    id: c.get_id().to_string(),
+                    name: c.get_name().to_string(),
+                })
+            })
+        } else {
+            None
+        };
+
+        Ok(ClubListResult {
+            clubs: ClubSummary::summarize(&clubs, self.user_repository.as_ref()).await?,
+            next_cursor,
+        })
+    }
+}
@@ -1,46 +1,206 @@
-use crate::domain::model::club::{
-    entity::Club, repository::ClubRepositoryTrait, specifications::ClubRecommendationSpec,
+use crate::domain::model::{
+    club::{
+        entity::ClubStatistics, repository::ClubStatisticsRepositoryTrait,
+        specifications::ClubRecommendationSpec,
+    },
+    error::DomainError,
 };
 
 use anyhow::Result;
-use std::sync::{Arc, Mutex};
+use std::{cmp::Reverse, sync::Arc};
 
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 100;
+
+/// Recommends clubs from the club statistics projection, so that no club
+/// has to be loaded.
 pub struct ClubRecommendationService {
-    club_repository: Arc<Mutex<dyn ClubRepositoryTrait>>,
+    club_statistics_repository: Arc<dyn ClubStatisticsRepositoryTrait + Send + Sync>,
+}
+
+pub struct ClubRecommendationCommand {
+    limit: Option<usize>,
+    sort: Option<String>,
+}
+
+impl ClubRecommendationCommand {
+    pub fn new(limit: Option<usize>, sort: Option<&str>) -> Self {
+        Self {
+            limit,
+            sort: sort.map(|x| x.to_string()),
+        }
+    }
 }
 
 pub struct ClubRecommendation {
     pub club_id: String,
     pub club_name: String,
     pub owner: String,
+    pub member_count: usize,
 }
 
 impl ClubRecommendation {
-    fn new(club: &Club) -> Self {
+    fn new(statistics: &ClubStatistics) -> Self {
         Self {
-            club_id: club.get_id().to_string(),
-            club_name: club.get_name().to_string(),
-            owner: club.get_owner_id().to_string(),
+            club_id: statistics.club_id.to_string(),
+            club_name: statistics.name.to_string(),
+            owner: statistics.owner_id.to_string(),
+            member_count: statistics.count_members(),
         }
     }
 }
 
 impl ClubRecommendationService {
-    pub fn new(club_repository: Arc<Mutex<dyn ClubRepositoryTrait>>) -> Self {
-        Self { club_repository }
+    pub fn new(
+        club_statistics_repository: Arc<dyn ClubStatisticsRepositoryTrait + Send + Sync>,
+    ) -> Self {
+        Self {
+            club_statistics_repository,
+        }
     }
 
-    pub async fn handle(&self) -> Result<Vec<ClubRecommendation>> {
+    pub async fn handle(
+        &self,
+        command: ClubRecommendationCommand,
+    ) -> Result<Vec<ClubRecommendation>> {
+        let limit = match command.limit {
+            None => DEFAULT_LIMIT,
+            Some(limit) if (1..=MAX_LIMIT).contains(&limit) => limit,
+            Some(_) => {
+                return Err(DomainError::invalid_field(
+                    "limit",
+                    "range",
+                    &format!("limit must be between 1 and {}", MAX_LIMIT),
+                )
+                .into())
+            }
+        };
+        let sort = command.sort.unwrap_or_else(|| "-members".to_string());
+
         let spec = ClubRecommendationSpec::new();
 
-        let repo = Arc::clone(&self.club_repository);
-        let repo = repo.lock().unwrap();
-        let clubs = repo.find_all().await?;
-        Ok(clubs
-            .into_iter()
-            .filter(|c| spec.is_satisfied_by(c))
-            .map(|c| ClubRecommendation::new(&c))
-            .take(10)
-            .collect())
+        let mut clubs = self
+            .club_statistics_repository
+            .find_all()
+            .await?
+            .iter()
+            .filter(|c| spec.is_satisfied_by_statistics(c))
+            .map(ClubRecommendation::new)
+            .collect::<Vec<ClubRecommendation>>();
+
+        // Sort by name first so that ties in the member count stay stable.
+        clubs.sort_by(|a, b| a.club_name.cmp(&b.club_name));
+        match sort.as_str() {
+            "name" => {}
+            "-name" => clubs.reverse(),
+            "members" => clubs.sort_by_key(|c| c.member_count),
+            "-members" => clubs.sort_by_key(|c| Reverse(c.member_count)),
+            _ => {
+                return Err(DomainError::invalid_field(
+                    "sort",
+                    "one_of",
+                    &format!(
+                        "Unknown sort `{}`. Expected one of members, -members, name, -name",
+                        sort
+                    ),
+                )
+                .into())
+            }
+        }
+
+        Ok(clubs.into_iter().take(limit).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    use crate::application::club::ClubStatisticsProjector;
+    use crate::domain::model::{
+        club::{
+            entity::{Club, ClubId, ClubName},
+            repository::ClubRepositoryTrait,
+        },
+        user::{
+            entity::{User, UserId, UserIsPremium, UserName},
+            repository::UserRepositoryTrait,
+        },
+    };
+    use crate::infrastructure::database::{
+        club::InMemoryClubDatabase, club_statistics::InMemoryClubStatisticsDatabase,
+        user::InMemoryUserDatabase,
+    };
+    use crate::interface::repository::{
+        club::ClubRepository, club_statistics::ClubStatisticsRepository, user::UserRepository,
+    };
+
+    use super::{ClubRecommendationCommand, ClubRecommendationService};
+
+    #[tokio::test]
+    async fn recommends_largest_clubs_first_up_to_limit() {
+        let club_repository = ClubRepository::new(Box::new(InMemoryClubDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let club_statistics_repository =
+            ClubStatisticsRepository::new(Box::new(InMemoryClubStatisticsDatabase::new()))
+                .await
+                .map(Arc::new)
+                .unwrap();
+        let user_repository = UserRepository::new(Box::new(InMemoryUserDatabase::new()))
+            .await
+            .map(Arc::new)
+            .unwrap();
+        let projector = ClubStatisticsProjector::new(
+            club_repository.clone(),
+            user_repository.clone(),
+            club_statistics_repository.clone(),
+        );
+        for (id, name, members) in [
+            ("recommend-1", "recommend small", 2),
+            ("recommend-2", "recommend large", 4),
+            ("recommend-3", "recommend medium", 3),
+            ("recommend-4", "recommend too small", 1),
+        ] {
+            let members = (0..members)
+                .map(|i| UserId::new(&format!("{}-member-{}", id, i)).unwrap())
+                .collect::<Vec<_>>();
+            for member in &members {
+                let mut user = User::new(
+                    member.clone(),
+                    UserName::new(&member.to_string()).unwrap(),
+                    UserIsPremium::new(false),
+                )
+                .unwrap();
+                user_repository.save(&mut user).await.unwrap();
+            }
+            let mut club = Club::new(
+                ClubId::new(id).unwrap(),
+                ClubName::new(name).unwrap(),
+                members,
+                UserId::new(&format!("{}-owner", id)).unwrap(),
+            )
+            .unwrap();
+            club_repository.lock().await.save(&mut club).await.unwrap();
+            projector.refresh(club.get_id(), None).await.unwrap();
+        }
+        let service = ClubRecommendationService::new(club_statistics_repository);
+
+        let recommended = service
+            .handle(ClubRecommendationCommand::new(Some(2), None))
+            .await
+            .unwrap();
+        let names = recommended
+            .iter()
+            .map(|c| c.club_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["recommend large", "recommend medium"]);
+
+        let unknown_sort = service
+            .handle(ClubRecommendationCommand::new(None, Some("owner")))
+            .await;
+        assert!(unknown_sort.is_err());
     }
 }
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use crate::domain::model::club::repository::ClubStatisticsRepositoryTrait;
+
+pub struct ClubStatisticsListService {
+    club_statistics_repository: Arc<dyn ClubStatisticsRepositoryTrait + Send + Sync>,
+}
+
+#[derive(Debug)]
+pub struct ClubStatisticsData {
+    pub club_id: String,
+    pub club_name: String,
+    pub member_count: usize,
+    pub premium_member_count: usize,
+    pub last_joined_at: Option<DateTime<Utc>>,
+}
+
+impl ClubStatisticsListService {
+    pub fn new(
+        club_statistics_repository: Arc<dyn ClubStatisticsRepositoryTrait + Send + Sync>,
+    ) -> Self {
+        Self {
+            club_statistics_repository,
+        }
+    }
+
+    pub async fn handle(&self) -> Result<Vec<ClubStatisticsData>> {
+        Ok(self
+            .club_statistics_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|s| ClubStatisticsData {
+                club_id: s.club_id.to_string(),
+                club_name: s.name.to_string(),
+                member_count: s.member_count,
+                premium_member_count: s.premium_member_count,
+                last_joined_at: s.last_joined_at,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,192 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::Mutex;
+
+use crate::domain::model::{
+    club::{
+        entity::{Club, ClubId, ClubStatistics},
+        repository::{ClubRepositoryTrait, ClubStatisticsRepositoryTrait},
+    },
+    user::{entity::UserId, repository::UserRepositoryTrait},
+};
+
+/// Keeps the club statistics projection in step with the clubs. Every update
+/// recounts the club from the repositories, so handling the same event twice
+/// or out of order leaves the same counts behind.
+pub struct ClubStatisticsProjector {
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+    user_repository: Arc<dyn UserRepositoryTrait + Send + Sync>,
+    club_statistics_repository: Arc<dyn ClubStatisticsRepositoryTrait + Send + Sync>,
+}
+
+impl ClubStatisticsProjector {
+    pub fn new(
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+        user_repository: Arc<dyn UserRepositoryTrait + Send + Sync>,
+        club_statistics_repository: Arc<dyn ClubStatisticsRepositoryTrait + Send + Sync>,
+    ) -> Self {
+        Self {
+            club_repository,
+            user_repository,
+            club_statistics_repository,
+        }
+    }
+
+    /// Recounts a club after it changed. `joined_at` is when a member joined,
+    /// if that was the change.
+    pub async fn refresh(&self, club_id: &ClubId, joined_at: Option<DateTime<Utc>>) -> Result<()> {
+        let club = self
+            .club_repository
+            .lock()
+            .await
+            .find_by_id(club_id)
+            .await?;
+        match club {
+            Some(club) => self.recount(&club, joined_at).await,
+            None => self.club_statistics_repository.delete(club_id).await,
+        }
+    }
+
+    /// Recounts the clubs `user_id` is a member of, after the user became or
+    /// stopped being premium, or was deleted or restored.
+    pub async fn refresh_member(&self, user_id: &UserId) -> Result<()> {
+        let clubs = self
+            .club_repository
+            .lock()
+            .await
+            .find_by_member(user_id)
+            .await?;
+        for club in &clubs {
+            self.recount(club, None).await?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the projection with a fresh count of every club and returns
+    /// the number of clubs counted. `last_joined_at` maps club ids to when a
+    /// member last joined them.
+    pub async fn rebuild(&self, last_joined_at: &HashMap<String, DateTime<Utc>>) -> Result<usize> {
+        let clubs = self.club_repository.lock().await.find_all().await?;
+        self.club_statistics_repository.delete_all().await?;
+        for club in &clubs {
+            let joined_at = last_joined_at.get(&club.get_id().to_string()).copied();
+            self.save(club, joined_at).await?;
+        }
+        Ok(clubs.len())
+    }
+
+    async fn recount(&self, club: &Club, joined_at: Option<DateTime<Utc>>) -> Result<()> {
+        let previous = self
+            .club_statistics_repository
+            .find_by_id(club.get_id())
+            .await?
+            .and_then(|s| s.last_joined_at);
+        self.save(club, previous.max(joined_at)).await
+    }
+
+    async fn save(&self, club: &Club, last_joined_at: Option<DateTime<Utc>>) -> Result<()> {
+        let members = self
+            .user_repository
+            .batch_find(club.get_members().clone())
+            .await?;
+        let statistics = ClubStatistics::tally(club, &members, last_joined_at);
+        self.club_statistics_repository.save(&statistics).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, Utc};
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    use crate::domain::model::{
+        club::{
+            entity::{Club, ClubId, ClubName},
+            repository::{ClubRepositoryTrait, ClubStatisticsRepositoryTrait},
+        },
+        user::{
+            entity::{User, UserId, UserIsPremium, UserName},
+            repository::UserRepositoryTrait,
+        },
+    };
+    use crate::infrastructure::database::{
+        club::InMemoryClubDatabase, club_statistics::InMemoryClubStatisticsDatabase,
+        user::InMemoryUserDatabase,
+    };
+    use crate::interface::repository::{
+        club::ClubRepository, club_statistics::ClubStatisticsRepository, user::UserRepository,
+    };
+
+    use super::ClubStatisticsProjector;
+
+    #[tokio::test]
+    async fn recounts_clubs_when_members_join_or_change_plan() {
+        let club_repository = Arc::new(Mutex::new(
+            ClubRepository::new(Box::new(InMemoryClubDatabase::new()))
+                .await
+                .unwrap(),
+        ));
+        let user_repository = Arc::new(
+            UserRepository::new(Box::new(InMemoryUserDatabase::new()))
+                .await
+                .unwrap(),
+        );
+        let club_statistics_repository = Arc::new(
+            ClubStatisticsRepository::new(Box::new(InMemoryClubStatisticsDatabase::new()))
+                .await
+                .unwrap(),
+        );
+        let projector = ClubStatisticsProjector::new(
+            club_repository.clone(),
+            user_repository.clone(),
+            club_statistics_repository.clone(),
+        );
+
+        let mut member = User::new(
+            UserId::new("statistics-member").unwrap(),
+            UserName::new("statistics member").unwrap(),
+            UserIsPremium::new(false),
+        )
+        .unwrap();
+        user_repository.save(&mut member).await.unwrap();
+        let club_id = ClubId::new("statistics-club").unwrap();
+        let mut club = Club::new(
+            club_id.clone(),
+            ClubName::new("statistics club").unwrap(),
+            vec![member.get_id().clone()],
+            UserId::new("statistics-owner").unwrap(),
+        )
+        .unwrap();
+        club_repository.lock().await.save(&mut club).await.unwrap();
+
+        let joined_at = Utc::now();
+        projector.refresh(&club_id, Some(joined_at)).await.unwrap();
+        let statistics = club_statistics_repository
+            .find_by_id(&club_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(statistics.member_count, 1);
+        assert_eq!(statistics.premium_member_count, 0);
+        assert_eq!(statistics.last_joined_at, Some(joined_at));
+
+        member.upgrade().unwrap();
+        user_repository.save(&mut member).await.unwrap();
+        projector.refresh_member(member.get_id()).await.unwrap();
+        // An older join delivered late does not move the last join back.
+        projector
+            .refresh(&club_id, Some(joined_at - Duration::hours(1)))
+            .await
+            .unwrap();
+        let statistics = club_statistics_repository
+            .find_by_id(&club_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(statistics.member_count, 1);
+        assert_eq!(statistics.premium_member_count, 1);
+        assert_eq!(statistics.last_joined_at, Some(joined_at));
+    }
+}
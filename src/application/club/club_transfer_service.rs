@@ -0,0 +1,59 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::application::audit::audit_entry;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    club::{entity::ClubId, repository::ClubRepositoryTrait},
+    error::DomainError,
+    user::entity::UserId,
+};
+
+pub struct ClubTransferCommand {
+    actor: Actor,
+    club_id: String,
+    new_owner_id: String,
+}
+
+impl ClubTransferCommand {
+    pub fn new(actor: &Actor, club_id: &str, new_owner_id: &str) -> Self {
+        Self {
+            actor: actor.clone(),
+            club_id: club_id.to_string(),
+            new_owner_id: new_owner_id.to_string(),
+        }
+    }
+}
+
+pub struct ClubTransferService {
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+}
+
+impl ClubTransferService {
+    pub fn new(club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>) -> Self {
+        Self { club_repository }
+    }
+
+    pub async fn handle(&self, command: ClubTransferCommand) -> Result<()> {
+        let club_id = ClubId::new(&command.club_id)?;
+        let new_owner_id = UserId::new(&command.new_owner_id)?;
+
+        let club_repo = self.club_repository.lock().await;
+        let mut club = club_repo
+            .find_by_id(&club_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Could not find the club".to_string()))?;
+        command
+            .actor
+            .authorize(ApiKeyScope::ClubsWrite, &[club.get_owner_id()])?;
+
+        let before = club.clone();
+        club.transfer_ownership(&new_owner_id)?;
+
+        let entry = audit_entry(&command.actor, "club.transfer", Some(&before), Some(&club));
+        club_repo.save_audited(&mut club, &entry).await?;
+        Ok(())
+    }
+}
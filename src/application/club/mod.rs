@@ -1,5 +1,16 @@
 mod club_create_service;
+mod club_export_service;
+mod club_get_info_service;
 mod club_join_service;
+mod club_leave_service;
+mod club_list_service;
 mod club_recommendation_service;
+mod club_statistics_list_service;
+mod club_statistics_projector;
+mod club_transfer_service;
 
-pub use self::{club_create_service::*, club_join_service::*, club_recommendation_service::*};
+pub use self::{
+    club_create_service::*, club_export_service::*, club_get_info_service::*, club_join_service::*,
+    club_leave_service::*, club_list_service::*, club_recommendation_service::*,
+    club_statistics_list_service::*, club_statistics_projector::*, club_transfer_service::*,
+};
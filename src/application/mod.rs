@@ -1,2 +1,6 @@
+pub mod api_key;
+pub mod audit;
 pub mod club;
+pub mod shared;
 pub mod user;
+pub mod webhook;
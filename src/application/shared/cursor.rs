@@ -0,0 +1,18 @@
+use anyhow::Result;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::domain::model::error::DomainError;
+
+/// Cursors handed out by the listing services are opaque to clients: the
+/// position is serialized to JSON and encoded as URL-safe base64.
+pub fn encode_cursor<T: Serialize>(cursor: &T) -> String {
+    let json = serde_json::to_vec(cursor).expect("a cursor is always serializable");
+    base64::encode_config(json, base64::URL_SAFE_NO_PAD)
+}
+
+pub fn decode_cursor<T: DeserializeOwned>(cursor: &str) -> Result<T> {
+    base64::decode_config(cursor, base64::URL_SAFE_NO_PAD)
+        .ok()
+        .and_then(|json| serde_json::from_slice::<T>(&json).ok())
+        .ok_or_else(|| DomainError::invalid_field("cursor", "format", "Invalid cursor").into())
+}
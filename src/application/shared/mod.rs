@@ -0,0 +1,3 @@
+mod cursor;
+
+pub use self::cursor::*;
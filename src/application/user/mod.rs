@@ -1,13 +1,25 @@
+mod user_clubs_service;
 mod user_delete_service;
 mod user_downgrade_service;
+mod user_export_service;
 mod user_get_info_service;
+mod user_import_service;
+mod user_list_service;
+mod user_purge_service;
 mod user_register_service;
+mod user_restore_service;
 mod user_update_info_service;
 mod user_upgrade_service;
 
+pub use user_clubs_service::UserClubsService;
 pub use user_delete_service::{UserDeleteCommand, UserDeleteService};
 pub use user_downgrade_service::{UserDowngradeCommand, UserDowngradeService};
-pub use user_get_info_service::{UserData, UserGetInfoService};
-pub use user_register_service::UserRegisterService;
+pub use user_export_service::UserExportService;
+pub use user_get_info_service::{UserData, UserGetInfoService, UserProfile};
+pub use user_import_service::{UserImportCommand, UserImportRow, UserImportService};
+pub use user_list_service::{UserListCommand, UserListService};
+pub use user_purge_service::UserPurgeService;
+pub use user_register_service::{UserRegisterCommand, UserRegisterService};
+pub use user_restore_service::{UserRestoreCommand, UserRestoreService};
 pub use user_update_info_service::{UserUpdateCommand, UserUpdateInfoService};
 pub use user_upgrade_service::{UserUpgradeCommand, UserUpgradeService};
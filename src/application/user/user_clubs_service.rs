@@ -0,0 +1,128 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::application::club::ClubSummary;
+use crate::domain::model::{
+    club::repository::ClubRepositoryTrait,
+    user::{entity::UserId, repository::UserRepositoryTrait},
+};
+
+pub struct UserClubsService {
+    user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+}
+
+#[derive(Debug)]
+pub struct UserClubs {
+    pub owned: Vec<ClubSummary>,
+    pub member_of: Vec<ClubSummary>,
+}
+
+impl UserClubsService {
+    pub fn new(
+        user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            club_repository,
+        }
+    }
+
+    pub async fn handle(&self, user_id: &str) -> Result<Option<UserClubs>> {
+        let user_id = UserId::new(user_id)?;
+        let user_repo = self.user_repository.lock().await;
+        if user_repo.find_by_id(&user_id).await?.is_none() {
+            return Ok(None);
+        }
+
+        let club_repo = self.club_repository.lock().await;
+        let owned = club_repo.find_by_owner(&user_id).await?;
+        let member_of = club_repo.find_by_member(&user_id).await?;
+
+        Ok(Some(UserClubs {
+            owned: ClubSummary::summarize(&owned, &*user_repo).await?,
+            member_of: ClubSummary::summarize(&member_of, &*user_repo).await?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    use crate::domain::model::{
+        club::{
+            entity::{Club, ClubId, ClubName},
+            repository::ClubRepositoryTrait,
+        },
+        user::{
+            entity::{User, UserId, UserIsPremium, UserName},
+            repository::UserRepositoryTrait,
+        },
+    };
+    use crate::infrastructure::database::{club::InMemoryClubDatabase, user::InMemoryUserDatabase};
+    use crate::interface::repository::{club::ClubRepository, user::UserRepository};
+
+    use super::UserClubsService;
+
+    #[tokio::test]
+    async fn lists_owned_and_joined_clubs() {
+        let user_repository = UserRepository::new(Box::new(InMemoryUserDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let club_repository = ClubRepository::new(Box::new(InMemoryClubDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+
+        let user_id = UserId::new("clubs-user").unwrap();
+        let other_id = UserId::new("clubs-other").unwrap();
+        for (id, name) in [(&user_id, "clubs-user"), (&other_id, "clubs-other")] {
+            let mut user = User::new(
+                id.clone(),
+                UserName::new(name).unwrap(),
+                UserIsPremium::new(false),
+            )
+            .unwrap();
+            user_repository.lock().await.save(&mut user).await.unwrap();
+        }
+        for (id, name, members, owner) in [
+            ("clubs-1", "clubs owned", vec![other_id.clone()], &user_id),
+            ("clubs-2", "clubs joined", vec![user_id.clone()], &other_id),
+            ("clubs-3", "clubs unrelated", vec![], &other_id),
+        ] {
+            let mut club = Club::new(
+                ClubId::new(id).unwrap(),
+                ClubName::new(name).unwrap(),
+                members,
+                owner.clone(),
+            )
+            .unwrap();
+            club_repository.lock().await.save(&mut club).await.unwrap();
+        }
+        let user_clubs_service = UserClubsService::new(user_repository, club_repository);
+
+        let clubs = user_clubs_service
+            .handle("clubs-user")
+            .await
+            .unwrap()
+            .unwrap();
+
+        let owned = clubs
+            .owned
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>();
+        let member_of = clubs
+            .member_of
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(owned, vec!["clubs owned"]);
+        assert_eq!(member_of, vec!["clubs joined"]);
+    }
+}
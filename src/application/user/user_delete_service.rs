@@ -1,34 +1,134 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
-use crate::domain::model::user::{entity::UserId, repository::UserRepositoryTrait};
+use crate::application::audit::audit_entry;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    club::repository::ClubRepositoryTrait,
+    error::DomainError,
+    user::{entity::UserId, repository::UserRepositoryTrait},
+};
 
 use anyhow::Result;
 
 pub struct UserDeleteService {
     user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
 }
 
 pub struct UserDeleteCommand {
+    actor: Actor,
     id: String,
 }
 
 impl UserDeleteCommand {
-    pub fn new(id: &str) -> Self {
-        Self { id: id.to_string() }
+    pub fn new(actor: &Actor, id: &str) -> Self {
+        Self {
+            actor: actor.clone(),
+            id: id.to_string(),
+        }
     }
 }
 
 impl UserDeleteService {
-    pub fn new(user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            club_repository,
+        }
     }
 
+    /// Soft-deletes the user: they disappear at once but are only purged
+    /// after the retention window. A user who owns a club must hand it over
+    /// first.
     pub async fn handle(&self, command: UserDeleteCommand) -> Result<()> {
         let id = UserId::new(&command.id)?;
-        let repo = self.user_repository.lock().unwrap();
-        match repo.find_by_id(&id).await? {
-            Some(_) => repo.delete(&id).await,
-            None => Ok(()),
+        command.actor.authorize(ApiKeyScope::UsersWrite, &[&id])?;
+        let repo = self.user_repository.lock().await;
+        if let Some(user) = repo.find_by_id(&id).await? {
+            let club_repo = self.club_repository.lock().await;
+            if !club_repo.find_by_owner(&id).await?.is_empty() {
+                return Err(DomainError::Conflict("The user still owns a club".to_string()).into());
+            }
+
+            let mut deleted = user.clone();
+            deleted.delete()?;
+            let entry = audit_entry(&command.actor, "user.delete", Some(&user), None);
+            repo.save_audited(&mut deleted, &entry).await?;
         }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    use crate::domain::model::{
+        audit::entity::Actor,
+        error::DomainError,
+        user::{
+            entity::{User, UserId, UserIsPremium, UserName},
+            repository::UserRepositoryTrait,
+        },
+    };
+    use crate::infrastructure::database::{club::InMemoryClubDatabase, user::InMemoryUserDatabase};
+    use crate::interface::repository::{club::ClubRepository, user::UserRepository};
+
+    use super::{UserDeleteCommand, UserDeleteService};
+
+    #[tokio::test]
+    async fn only_the_user_may_delete_their_account() {
+        let user_repository = UserRepository::new(Box::new(InMemoryUserDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let club_repository = ClubRepository::new(Box::new(InMemoryClubDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let delete_repository = Arc::clone(&user_repository);
+        let service = UserDeleteService::new(delete_repository, club_repository);
+
+        let id = UserId::new("5d0e9b2c-8f39-4a57-9c4e-1f7a2d6b3e80").unwrap();
+        let mut user = User::new(
+            id.clone(),
+            UserName::new("delete-me").unwrap(),
+            UserIsPremium::new(false),
+        )
+        .unwrap();
+        user_repository.lock().await.save(&mut user).await.unwrap();
+
+        let other = Actor::User(UserId::new("9a7c3e14-2b6d-4f80-8e1a-5c9d0b4f7a23").unwrap());
+        for (actor, expected) in [(Actor::Anonymous, "Unauthorized"), (other, "Forbidden")] {
+            let error = service
+                .handle(UserDeleteCommand::new(&actor, &id.to_string()))
+                .await
+                .unwrap_err();
+            let kind = match error.downcast_ref::<DomainError>() {
+                Some(DomainError::Unauthorized(_)) => "Unauthorized",
+                Some(DomainError::Forbidden(_)) => "Forbidden",
+                _ => "other",
+            };
+            assert_eq!(kind, expected);
+        }
+        let found = user_repository.lock().await.find_by_id(&id).await.unwrap();
+        assert!(found.is_some());
+
+        service
+            .handle(UserDeleteCommand::new(
+                &Actor::User(id.clone()),
+                &id.to_string(),
+            ))
+            .await
+            .unwrap();
+        let repo = user_repository.lock().await;
+        assert!(repo.find_by_id(&id).await.unwrap().is_none());
+        assert!(repo.find_deleted_by_id(&id).await.unwrap().is_some());
     }
 }
@@ -1,19 +1,30 @@
-use crate::domain::model::user::{entity::UserId, repository::UserRepositoryTrait};
-
-use anyhow::{anyhow, Result};
-use std::sync::{Arc, Mutex};
+use crate::application::audit::audit_entry;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    error::DomainError,
+    user::{entity::UserId, repository::UserRepositoryTrait},
+};
+
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
 pub struct UserDowngradeService {
     user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
 }
 
 pub struct UserDowngradeCommand {
+    actor: Actor,
     id: String,
 }
 
 impl UserDowngradeCommand {
-    pub fn new(id: &str) -> Self {
-        Self { id: id.to_string() }
+    pub fn new(actor: &Actor, id: &str) -> Self {
+        Self {
+            actor: actor.clone(),
+            id: id.to_string(),
+        }
     }
 }
 
@@ -24,15 +35,20 @@ impl UserDowngradeService {
 
     pub async fn handle(&self, command: UserDowngradeCommand) -> Result<()> {
         let target_id = UserId::new(&command.id)?;
-        let repo = self.user_repository.lock().unwrap();
+        // Premium is the paid plan, so users cannot change their own.
+        command.actor.authorize(ApiKeyScope::PremiumWrite, &[])?;
+        let repo = self.user_repository.lock().await;
 
         let mut user = repo
             .find_by_id(&target_id)
             .await?
-            .ok_or_else(|| anyhow!("Could not find the target user."))?;
+            .ok_or_else(|| DomainError::NotFound("Could not find the target user.".to_string()))?;
 
+        let before = user.clone();
         user.downgrade()?;
 
-        repo.save(&user).await
+        let entry = audit_entry(&command.actor, "user.downgrade", Some(&before), Some(&user));
+        repo.save_audited(&mut user, &entry).await?;
+        Ok(())
     }
 }
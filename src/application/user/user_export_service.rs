@@ -0,0 +1,70 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    user::repository::{
+        SortOrder, UserListCursor, UserListQuery, UserRepositoryTrait, UserSortKey,
+    },
+};
+
+const PAGE_SIZE: usize = 100;
+
+pub struct UserExportService {
+    user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+}
+
+#[derive(Debug)]
+pub struct UserExportData {
+    pub id: String,
+    pub name: String,
+    pub is_premium: bool,
+    pub premium_since: Option<DateTime<Utc>>,
+    pub premium_expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserExportService {
+    pub fn new(user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>) -> Self {
+        Self { user_repository }
+    }
+
+    /// Returns every user ordered by name, reading the table page by page.
+    pub async fn handle(&self, actor: &Actor) -> Result<Vec<UserExportData>> {
+        actor.authorize(ApiKeyScope::ExportRead, &[])?;
+        let repo = self.user_repository.lock().await;
+        let mut users = Vec::new();
+        let mut after = None;
+        loop {
+            let query = UserListQuery {
+                is_premium: None,
+                name_prefix: None,
+                sort_key: UserSortKey::Name,
+                sort_order: SortOrder::Asc,
+                after,
+                limit: PAGE_SIZE,
+            };
+            let page = repo.find_page(&query).await?;
+            after = page.last().map(|u| UserListCursor {
+                id: u.get_id().clone(),
+                name: u.get_name().clone(),
+            });
+
+            let last_page = page.len() < PAGE_SIZE;
+            users.extend(page.iter().map(|u| UserExportData {
+                id: u.get_id().to_string(),
+                name: u.get_name().to_string(),
+                is_premium: u.get_is_premium().to_inner(),
+                premium_since: u.get_premium_since().cloned(),
+                premium_expires_at: u.get_premium_expires_at().cloned(),
+                created_at: *u.get_created_at(),
+            }));
+            if last_page {
+                return Ok(users);
+            }
+        }
+    }
+}
@@ -1,13 +1,45 @@
 use anyhow::Result;
-use std::sync::{Arc, Mutex};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
-use crate::domain::model::user::{
-    entity::{User, UserId},
-    repository::UserRepositoryTrait,
+use crate::domain::model::{
+    club::repository::ClubRepositoryTrait,
+    user::{
+        entity::{User, UserId},
+        repository::UserRepositoryTrait,
+    },
 };
 
 pub struct UserGetInfoService {
     user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+}
+
+/// Everything a client can see about a single user.
+#[derive(Debug)]
+pub struct UserProfile {
+    pub id: String,
+    pub name: String,
+    pub is_premium: bool,
+    pub premium_since: Option<DateTime<Utc>>,
+    pub premium_expires_at: Option<DateTime<Utc>>,
+    pub clubs_count: usize,
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserProfile {
+    pub fn new(user: &User, clubs_count: usize) -> Self {
+        Self {
+            id: user.get_id().to_string(),
+            name: user.get_name().to_string(),
+            is_premium: user.get_is_premium().to_inner(),
+            premium_since: user.get_premium_since().cloned(),
+            premium_expires_at: user.get_premium_expires_at().cloned(),
+            clubs_count,
+            created_at: *user.get_created_at(),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -35,16 +67,29 @@ impl UserData {
 }
 
 impl UserGetInfoService {
-    pub fn new(user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            club_repository,
+        }
     }
 
-    pub async fn handle(&self, user_id: &str) -> Result<Option<UserData>> {
+    pub async fn handle(&self, user_id: &str) -> Result<Option<UserProfile>> {
         let target_id = UserId::new(user_id)?;
 
-        let repo = self.user_repository.lock().unwrap();
-        repo.find_by_id(&target_id)
-            .await
-            .map(|x| x.map(|user| UserData::new(&user)))
+        let repo = self.user_repository.lock().await;
+        let user = match repo.find_by_id(&target_id).await? {
+            Some(user) => user,
+            None => return Ok(None),
+        };
+
+        let club_repo = self.club_repository.lock().await;
+        let clubs_count = club_repo.find_by_owner(&target_id).await?.len()
+            + club_repo.find_by_member(&target_id).await?.len();
+
+        Ok(Some(UserProfile::new(&user, clubs_count)))
     }
 }
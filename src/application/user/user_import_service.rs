@@ -0,0 +1,183 @@
+use anyhow::{bail, Result};
+use std::{collections::HashSet, sync::Arc};
+use tokio::sync::Mutex;
+
+use crate::application::audit::audit_entry;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    user::{
+        entity::UserName, factory::UserFactoryTrait, repository::UserRepositoryTrait,
+        service::UserService,
+    },
+};
+
+pub struct UserImportService {
+    user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+    user_factory: Arc<Mutex<dyn UserFactoryTrait>>,
+}
+
+/// One user to register. `line` is where the row came from in the input and
+/// is only used for reporting.
+pub struct UserImportRow {
+    pub line: usize,
+    pub name: String,
+    pub is_premium: bool,
+}
+
+pub struct UserImportCommand {
+    actor: Actor,
+    rows: Vec<UserImportRow>,
+    dry_run: bool,
+}
+
+impl UserImportCommand {
+    pub fn new(actor: &Actor, rows: Vec<UserImportRow>, dry_run: bool) -> Self {
+        Self {
+            actor: actor.clone(),
+            rows,
+            dry_run,
+        }
+    }
+}
+
+/// The outcome of a single row: the new user's id on success, the reason it
+/// was rejected otherwise. In a dry run the id is the one the user would get.
+#[derive(Debug)]
+pub struct UserImportRowResult {
+    pub line: usize,
+    pub name: String,
+    pub id: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct UserImportResult {
+    pub dry_run: bool,
+    pub imported: usize,
+    pub failed: usize,
+    pub rows: Vec<UserImportRowResult>,
+}
+
+impl UserImportService {
+    pub fn new(
+        user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+        user_factory: Arc<Mutex<dyn UserFactoryTrait>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            user_factory,
+        }
+    }
+
+    /// Registers every valid row. Invalid rows are reported and skipped, they
+    /// do not stop the rest of the import.
+    pub async fn handle(&self, command: UserImportCommand) -> Result<UserImportResult> {
+        command.actor.authorize(ApiKeyScope::UsersWrite, &[])?;
+        let repo = self.user_repository.lock().await;
+        let user_service = UserService::new(&*repo);
+
+        let mut seen = HashSet::new();
+        let mut rows = Vec::with_capacity(command.rows.len());
+        for row in command.rows {
+            let outcome = async {
+                let name = UserName::new(&row.name)?;
+                if !seen.insert(name.to_string()) {
+                    bail!("The name appears more than once in the import");
+                }
+
+                let mut user = self.user_factory.lock().await.create(name)?;
+                if row.is_premium {
+                    user.upgrade()?;
+                }
+                if user_service.exists(&user).await {
+                    bail!("User already exists");
+                }
+                if !command.dry_run {
+                    let entry = audit_entry(&command.actor, "user.import", None, Some(&user));
+                    repo.save_audited(&mut user, &entry).await?;
+                }
+
+                Ok(user.get_id().to_string())
+            }
+            .await;
+
+            let (id, error) = match outcome {
+                Ok(id) => (Some(id), None),
+                Err(e) => (None, Some(e.to_string())),
+            };
+            rows.push(UserImportRowResult {
+                line: row.line,
+                name: row.name,
+                id,
+                error,
+            });
+        }
+
+        let failed = rows.iter().filter(|r| r.error.is_some()).count();
+        Ok(UserImportResult {
+            dry_run: command.dry_run,
+            imported: rows.len() - failed,
+            failed,
+            rows,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    use crate::domain::model::{
+        audit::entity::Actor,
+        user::{entity::UserName, factory::UserFactory, repository::UserRepositoryTrait},
+    };
+    use crate::infrastructure::database::user::InMemoryUserDatabase;
+    use crate::interface::repository::user::UserRepository;
+
+    use super::{UserImportCommand, UserImportRow, UserImportService};
+
+    #[tokio::test]
+    async fn reports_invalid_rows_and_saves_nothing_in_dry_run() {
+        let user_repository = UserRepository::new(Box::new(InMemoryUserDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let user_factory = Arc::new(Mutex::new(UserFactory::new()));
+        let import_repository = Arc::clone(&user_repository);
+        let service = UserImportService::new(import_repository, user_factory);
+        let actor = Actor::System("test".to_string());
+        let rows = || {
+            [("import-ok", true), ("ab", false), ("import-ok", false)]
+                .into_iter()
+                .enumerate()
+                .map(|(i, (name, is_premium))| UserImportRow {
+                    line: i + 2,
+                    name: name.to_string(),
+                    is_premium,
+                })
+                .collect::<Vec<UserImportRow>>()
+        };
+
+        let report = service
+            .handle(UserImportCommand::new(&actor, rows(), true))
+            .await
+            .unwrap();
+        assert_eq!((report.imported, report.failed), (1, 2));
+        assert_eq!(
+            report.rows[1].error.as_deref(),
+            Some("The length of a user name must be greater than 3")
+        );
+        let name = UserName::new("import-ok").unwrap();
+        let found = user_repository.lock().await.find_by_name(&name).await;
+        assert!(found.unwrap().is_none());
+
+        service
+            .handle(UserImportCommand::new(&actor, rows(), false))
+            .await
+            .unwrap();
+        let found = user_repository.lock().await.find_by_name(&name).await;
+        assert!(found.unwrap().unwrap().get_is_premium().to_inner());
+    }
+}
@@ -0,0 +1,237 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::application::{
+    shared::{decode_cursor, encode_cursor},
+    user::UserData,
+};
+use crate::domain::model::{
+    error::DomainError,
+    user::{
+        entity::{UserId, UserName},
+        repository::{SortOrder, UserListCursor, UserListQuery, UserRepositoryTrait, UserSortKey},
+    },
+};
+
+const DEFAULT_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 100;
+
+pub struct UserListService {
+    user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+}
+
+pub struct UserListCommand {
+    cursor: Option<String>,
+    limit: Option<usize>,
+    is_premium: Option<bool>,
+    name_prefix: Option<String>,
+    sort: Option<String>,
+}
+
+impl UserListCommand {
+    pub fn new(
+        cursor: Option<&str>,
+        limit: Option<usize>,
+        is_premium: Option<bool>,
+        name_prefix: Option<&str>,
+        sort: Option<&str>,
+    ) -> Self {
+        Self {
+            cursor: cursor.map(|x| x.to_string()),
+            limit,
+            is_premium,
+            name_prefix: name_prefix.map(|x| x.to_string()),
+            sort: sort.map(|x| x.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UserListResult {
+    pub users: Vec<UserData>,
+    pub next_cursor: Option<String>,
+}
+
+/// The cursor handed out to clients. It records the sort it was created for
+/// so that it cannot be replayed against a different ordering.
+#[derive(Serialize, Deserialize)]
+struct Cursor {
+    sort: String,
+    id: String,
+    name: String,
+}
+
+impl UserListService {
+    pub fn new(user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn handle(&self, command: UserListCommand) -> Result<UserListResult> {
+        let sort = command.sort.unwrap_or_else(|| "name".to_string());
+        let (sort_key, sort_order) = parse_sort(&sort)?;
+        let limit = match command.limit {
+            None => DEFAULT_LIMIT,
+            Some(limit) if (1..=MAX_LIMIT).contains(&limit) => limit,
+            Some(_) => {
+                return Err(DomainError::invalid_field(
+                    "limit",
+                    "range",
+                    &format!("limit must be between 1 and {}", MAX_LIMIT),
+                )
+                .into())
+            }
+        };
+        let after = match command.cursor {
+            Some(cursor) => Some(decode_position(&cursor, &sort)?),
+            None => None,
+        };
+
+        // Fetch one extra user to find out whether there is a next page.
+        let query = UserListQuery {
+            is_premium: command.is_premium,
+            name_prefix: command.name_prefix.filter(|p| !p.is_empty()),
+            sort_key,
+            sort_order,
+            after,
+            limit: limit + 1,
+        };
+        let repo = self.user_repository.lock().await;
+        let mut users = repo.find_page(&query).await?;
+
+        let next_cursor = if users.len() > limit {
+            users.truncate(limit);
+            users.last().map(|u| {
+                encode_cursor(&Cursor {
+                    sort: sort.to_string(),
+                    id: u.get_id().to_string(),
+                    name: u.get_name().to_string(),
+                })
+            })
+        } else {
+            None
+        };
+
+        Ok(UserListResult {
+            users: users.iter().map(UserData::new).collect(),
+            next_cursor,
+        })
+    }
+}
+
+fn parse_sort(sort: &str) -> Result<(UserSortKey, SortOrder)> {
+    match sort {
+        "name" => Ok((UserSortKey::Name, SortOrder::Asc)),
+        "-name" => Ok((UserSortKey::Name, SortOrder::Desc)),
+        "id" => Ok((UserSortKey::Id, SortOrder::Asc)),
+        "-id" => Ok((UserSortKey::Id, SortOrder::Desc)),
+        _ => Err(DomainError::invalid_field(
+            "sort",
+            "one_of",
+            &format!(
+                "Unknown sort `{}`. Expected one of name, -name, id, -id",
+                sort
+            ),
+        )
+        .into()),
+    }
+}
+
+fn decode_position(cursor: &str, sort: &str) -> Result<UserListCursor> {
+    let cursor = decode_cursor::<Cursor>(cursor)?;
+    if cursor.sort != sort {
+        return Err(DomainError::invalid_field(
+            "cursor",
+            "sort",
+            "The cursor was created for a different sort",
+        )
+        .into());
+    }
+
+    Ok(UserListCursor {
+        id: UserId::new(&cursor.id)?,
+        name: UserName::new(&cursor.name)?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    use crate::domain::model::user::{
+        entity::{User, UserId, UserIsPremium, UserName},
+        repository::UserRepositoryTrait,
+    };
+    use crate::infrastructure::database::user::InMemoryUserDatabase;
+    use crate::interface::repository::user::UserRepository;
+
+    use super::{UserListCommand, UserListService};
+
+    #[tokio::test]
+    async fn pages_through_filtered_users() {
+        let user_database = InMemoryUserDatabase::new();
+        let user_repository = UserRepository::new(Box::new(user_database))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        for (id, name, is_premium) in [
+            ("list-1", "listed-c", true),
+            ("list-2", "listed-a", true),
+            ("list-3", "listed-b", false),
+            ("list-4", "listed-d", true),
+        ] {
+            let mut user = User::new(
+                UserId::new(id).unwrap(),
+                UserName::new(name).unwrap(),
+                UserIsPremium::new(is_premium),
+            )
+            .unwrap();
+            user_repository.lock().await.save(&mut user).await.unwrap();
+        }
+        let user_list_service = UserListService::new(user_repository);
+
+        let first = user_list_service
+            .handle(UserListCommand::new(
+                None,
+                Some(2),
+                Some(true),
+                Some("listed-"),
+                None,
+            ))
+            .await
+            .unwrap();
+        let names = first.users.iter().map(|u| u.get_name()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["listed-a", "listed-c"]);
+
+        let second = user_list_service
+            .handle(UserListCommand::new(
+                first.next_cursor.as_deref(),
+                Some(2),
+                Some(true),
+                Some("listed-"),
+                None,
+            ))
+            .await
+            .unwrap();
+        let names = second
+            .users
+            .iter()
+            .map(|u| u.get_name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["listed-d"]);
+        assert!(second.next_cursor.is_none());
+
+        let wrong_sort = user_list_service
+            .handle(UserListCommand::new(
+                first.next_cursor.as_deref(),
+                Some(2),
+                Some(true),
+                Some("listed-"),
+                Some("-name"),
+            ))
+            .await;
+        assert!(wrong_sort.is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use crate::domain::model::{
+    club::repository::ClubRepositoryTrait, user::repository::UserRepositoryTrait,
+};
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub struct UserPurgeService {
+    user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+    club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+    retention: Duration,
+}
+
+impl UserPurgeService {
+    pub fn new(
+        user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+        club_repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
+        retention: Duration,
+    ) -> Self {
+        Self {
+            user_repository,
+            club_repository,
+            retention,
+        }
+    }
+
+    /// Removes the users deleted longer than the retention window ago for
+    /// good, along with their club memberships, and returns their ids. Users
+    /// who still own a club are kept until it is handed over.
+    pub async fn handle(&self) -> Result<Vec<String>> {
+        let deleted_before = Utc::now() - self.retention;
+        let repo = self.user_repository.lock().await;
+        let club_repo = self.club_repository.lock().await;
+
+        let mut purged = Vec::new();
+        for id in repo.find_deleted_before(deleted_before).await? {
+            if !club_repo.find_by_owner(&id).await?.is_empty() {
+                continue;
+            }
+            // Leaving through the clubs records the events, whichever store
+            // holds them.
+            for mut club in club_repo.find_by_member(&id).await? {
+                club.leave(&id)?;
+                club_repo.save(&mut club).await?;
+            }
+            repo.purge(&id).await?;
+            purged.push(id.to_string());
+        }
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, Utc};
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    use crate::domain::model::{
+        club::{
+            entity::{Club, ClubId, ClubName},
+            repository::ClubRepositoryTrait,
+        },
+        user::{
+            entity::{User, UserId, UserIsPremium, UserName},
+            repository::UserRepositoryTrait,
+        },
+    };
+    use crate::infrastructure::database::{club::InMemoryClubDatabase, user::InMemoryUserDatabase};
+    use crate::interface::repository::{club::ClubRepository, user::UserRepository};
+
+    use super::UserPurgeService;
+
+    #[tokio::test]
+    async fn purges_expired_users_except_club_owners() {
+        let user_repository = UserRepository::new(Box::new(InMemoryUserDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let club_repository = ClubRepository::new(Box::new(InMemoryClubDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let service = UserPurgeService::new(
+            Arc::clone(&user_repository) as _,
+            Arc::clone(&club_repository) as _,
+            Duration::days(7),
+        );
+
+        let expired = UserId::new("purge-expired").unwrap();
+        let recent = UserId::new("purge-recent").unwrap();
+        let owner = UserId::new("purge-owner").unwrap();
+        for (id, deleted_days_ago) in [(&expired, 10), (&recent, 1), (&owner, 10)] {
+            let mut user = User::restore(
+                id.clone(),
+                UserName::new(&id.to_string()).unwrap(),
+                UserIsPremium::new(false),
+                None,
+                None,
+                Utc::now() - Duration::days(30),
+                Some(Utc::now() - Duration::days(deleted_days_ago)),
+            )
+            .unwrap();
+            user_repository.lock().await.save(&mut user).await.unwrap();
+        }
+        let club_id = ClubId::new("purge-club").unwrap();
+        let mut club = Club::new(
+            club_id.clone(),
+            ClubName::new("purge club").unwrap(),
+            vec![expired.clone(), recent.clone()],
+            owner.clone(),
+        )
+        .unwrap();
+        club_repository.lock().await.save(&mut club).await.unwrap();
+
+        let purged = service.handle().await.unwrap();
+        assert!(purged.contains(&expired.to_string()));
+        assert!(!purged.contains(&recent.to_string()));
+        assert!(!purged.contains(&owner.to_string()));
+
+        let repo = user_repository.lock().await;
+        assert!(repo.find_deleted_by_id(&expired).await.unwrap().is_none());
+        assert!(repo.find_deleted_by_id(&recent).await.unwrap().is_some());
+        assert!(repo.find_deleted_by_id(&owner).await.unwrap().is_some());
+        let club = club_repository
+            .lock()
+            .await
+            .find_by_id(&club_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(club.get_members(), &vec![recent]);
+    }
+}
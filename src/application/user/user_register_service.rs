@@ -1,16 +1,36 @@
-use crate::domain::model::user::{
-    entity::UserName, factory::UserFactoryTrait, repository::UserRepositoryTrait,
-    service::UserService,
+use crate::application::{audit::audit_entry, user::UserProfile};
+use crate::domain::model::{
+    audit::entity::Actor,
+    error::DomainError,
+    user::{
+        entity::UserName, factory::UserFactoryTrait, repository::UserRepositoryTrait,
+        service::UserService,
+    },
 };
 
-use anyhow::{anyhow, Result};
-use std::sync::{Arc, Mutex};
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
 pub struct UserRegisterService {
     user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
     user_factory: Arc<Mutex<dyn UserFactoryTrait>>,
 }
 
+pub struct UserRegisterCommand {
+    actor: Actor,
+    name: String,
+}
+
+impl UserRegisterCommand {
+    pub fn new(actor: &Actor, name: &str) -> Self {
+        Self {
+            actor: actor.clone(),
+            name: name.to_string(),
+        }
+    }
+}
+
 impl UserRegisterService {
     pub fn new(
         user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
@@ -22,32 +42,49 @@ impl UserRegisterService {
         }
     }
 
-    pub async fn handle(&self, name: &str) -> Result<()> {
-        let name = UserName::new(name)?;
+    /// Registers a user and returns their profile.
+    pub async fn handle(&self, command: UserRegisterCommand) -> Result<UserProfile> {
+        let name = UserName::new(&command.name)?;
         let factory = Arc::clone(&self.user_factory);
-        let user = factory.lock().unwrap().create(name)?;
+        let mut user = factory.lock().await.create(name)?;
 
-        let repo = self.user_repository.lock().unwrap();
+        let repo = self.user_repository.lock().await;
         let user_service = UserService::new(&*repo);
         if user_service.exists(&user).await {
-            return Err(anyhow!("User already exists"));
+            return Err(DomainError::Conflict("User already exists".to_string()).into());
         }
 
-        repo.save(&user).await
+        let entry = audit_entry(&command.actor, "user.register", None, Some(&user));
+        repo.save_audited(&mut user, &entry).await?;
+        Ok(UserProfile::new(&user, 0))
     }
 }
 
 #[cfg(test)]
 mod test {
-    use std::sync::{Arc, Mutex};
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
 
-    use crate::domain::model::user::{
-        entity::UserName, factory::UserFactory, repository::UserRepositoryTrait,
+    use crate::domain::model::{
+        audit::{entity::Actor, repository::AuditRepositoryTrait},
+        error::{DomainError, FieldError},
+        user::{entity::UserName, factory::UserFactory, repository::UserRepositoryTrait},
+    };
+    use crate::infrastructure::database::{
+        audit::InMemoryAuditDatabase, user::InMemoryUserDatabase,
     };
-    use crate::infrastructure::database::user::InMemoryUserDatabase;
-    use crate::interface::repository::user::UserRepository;
+    use crate::interface::repository::{audit::AuditRepository, user::UserRepository};
 
-    use super::UserRegisterService;
+    use super::{UserRegisterCommand, UserRegisterService};
+
+    async fn audit_repository() -> Arc<AuditRepository> {
+        let audit_database = InMemoryAuditDatabase::new();
+        Arc::new(
+            AuditRepository::new(Box::new(audit_database))
+                .await
+                .unwrap(),
+        )
+    }
 
     #[tokio::test]
     async fn can_register_min_user_name() {
@@ -58,24 +95,33 @@ mod test {
             .unwrap();
         let registry_repository = Arc::clone(&user_repository);
         let user_factory = Arc::new(Mutex::new(UserFactory::new()));
+        let audit_repository = audit_repository().await;
         let user_register_service = UserRegisterService::new(registry_repository, user_factory);
 
         let min_name = "abc";
-        user_register_service.handle(min_name).await.unwrap();
+        let actor = Actor::System("test".to_string());
+        let command = UserRegisterCommand::new(&actor, min_name);
+        let profile = user_register_service.handle(command).await.unwrap();
 
         let read_repository = Arc::clone(&user_repository);
         let target_name = UserName::new(min_name).unwrap();
         let target = read_repository
             .lock()
-            .unwrap()
+            .await
             .find_by_name(&target_name)
-            .await;
-        let res = match target {
-            Ok(x) => x.is_some(),
-            Err(_) => false,
-        };
+            .await
+            .unwrap()
+            .unwrap();
 
-        assert!(res);
+        let target_id = target.get_id().to_string();
+        assert_eq!(profile.id, target_id);
+        assert_eq!(profile.name, min_name);
+        let entries = audit_repository.find(Some(&target_id), 10).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].actor, actor);
+        assert_eq!(entries[0].action, "user.register");
+        assert!(entries[0].before.is_none());
+        assert_eq!(entries[0].after.as_ref().unwrap()["name"], min_name);
     }
 
     #[tokio::test]
@@ -90,7 +136,8 @@ mod test {
         let user_register_service = UserRegisterService::new(registry_repository, user_factory);
 
         let short_name = "ab";
-        let error_msg = match user_register_service.handle(short_name).await {
+        let command = UserRegisterCommand::new(&Actor::Anonymous, short_name);
+        let error_msg = match user_register_service.handle(command).await {
             Ok(_) => panic!(),
             Err(e) => e.to_string(),
         };
@@ -101,6 +148,30 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn reports_a_short_name_as_a_field_error() {
+        let user_database = InMemoryUserDatabase::new();
+        let user_repository = UserRepository::new(Box::new(user_database))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let user_factory = Arc::new(Mutex::new(UserFactory::new()));
+        let user_register_service = UserRegisterService::new(user_repository, user_factory);
+
+        let command = UserRegisterCommand::new(&Actor::Anonymous, "ab");
+        let error = user_register_service.handle(command).await.unwrap_err();
+
+        let expected = FieldError::new(
+            "name",
+            "length",
+            "The length of a user name must be greater than 3",
+        );
+        assert_eq!(
+            error.downcast_ref::<DomainError>(),
+            Some(&DomainError::Validation(vec![expected]))
+        );
+    }
+
     #[tokio::test]
     async fn cannot_register_dupulicate_name() {
         let user_database = InMemoryUserDatabase::new();
@@ -113,10 +184,12 @@ mod test {
         let user_register_service = UserRegisterService::new(registry_repository, user_factory);
 
         let min_name = "duplicate";
-        user_register_service.handle(min_name).await.unwrap();
+        let command = UserRegisterCommand::new(&Actor::Anonymous, min_name);
+        user_register_service.handle(command).await.unwrap();
 
         let duplicate_name = min_name;
-        let res = user_register_service.handle(duplicate_name).await;
+        let command = UserRegisterCommand::new(&Actor::Anonymous, duplicate_name);
+        let res = user_register_service.handle(command).await;
 
         let error_msg = match res {
             Ok(_) => panic!(),
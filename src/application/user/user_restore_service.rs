@@ -0,0 +1,129 @@
+use crate::application::audit::audit_entry;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    error::DomainError,
+    user::{entity::UserId, repository::UserRepositoryTrait},
+};
+
+use anyhow::Result;
+use chrono::Duration;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub struct UserRestoreService {
+    user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+    retention: Duration,
+}
+
+pub struct UserRestoreCommand {
+    actor: Actor,
+    id: String,
+}
+
+impl UserRestoreCommand {
+    pub fn new(actor: &Actor, id: &str) -> Self {
+        Self {
+            actor: actor.clone(),
+            id: id.to_string(),
+        }
+    }
+}
+
+impl UserRestoreService {
+    pub fn new(
+        user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
+        retention: Duration,
+    ) -> Self {
+        Self {
+            user_repository,
+            retention,
+        }
+    }
+
+    /// Brings back a user deleted less than the retention window ago, with
+    /// their name, plan and clubs.
+    pub async fn handle(&self, command: UserRestoreCommand) -> Result<()> {
+        let id = UserId::new(&command.id)?;
+        command.actor.authorize(ApiKeyScope::UsersWrite, &[&id])?;
+        let repo = self.user_repository.lock().await;
+
+        let mut user = repo.find_deleted_by_id(&id).await?.ok_or_else(|| {
+            DomainError::NotFound("Could not find a deleted user with this id.".to_string())
+        })?;
+        user.undelete(self.retention)?;
+
+        let entry = audit_entry(&command.actor, "user.restore", None, Some(&user));
+        repo.save_audited(&mut user, &entry).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, Utc};
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    use crate::domain::model::{
+        audit::entity::Actor,
+        error::DomainError,
+        user::{
+            entity::{User, UserId, UserIsPremium, UserName},
+            repository::UserRepositoryTrait,
+        },
+    };
+    use crate::infrastructure::database::user::InMemoryUserDatabase;
+    use crate::interface::repository::user::UserRepository;
+
+    use super::{UserRestoreCommand, UserRestoreService};
+
+    #[tokio::test]
+    async fn restores_only_within_the_retention_window() {
+        let user_repository = UserRepository::new(Box::new(InMemoryUserDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let service = UserRestoreService::new(Arc::clone(&user_repository) as _, Duration::days(1));
+
+        let recent = UserId::new("restore-recent").unwrap();
+        let expired = UserId::new("restore-expired").unwrap();
+        for (id, deleted_hours_ago) in [(&recent, 1), (&expired, 36)] {
+            let mut user = User::restore(
+                id.clone(),
+                UserName::new(&id.to_string()).unwrap(),
+                UserIsPremium::new(false),
+                None,
+                None,
+                Utc::now() - Duration::days(3),
+                Some(Utc::now() - Duration::hours(deleted_hours_ago)),
+            )
+            .unwrap();
+            user_repository.lock().await.save(&mut user).await.unwrap();
+        }
+
+        service
+            .handle(UserRestoreCommand::new(
+                &Actor::User(recent.clone()),
+                "restore-recent",
+            ))
+            .await
+            .unwrap();
+        let error = service
+            .handle(UserRestoreCommand::new(
+                &Actor::User(expired.clone()),
+                "restore-expired",
+            ))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<DomainError>(),
+            Some(DomainError::Conflict(_))
+        ));
+
+        let repo = user_repository.lock().await;
+        assert!(repo.find_by_id(&recent).await.unwrap().is_some());
+        assert!(repo.find_by_id(&expired).await.unwrap().is_none());
+        assert!(repo.find_deleted_by_id(&expired).await.unwrap().is_some());
+    }
+}
@@ -1,24 +1,33 @@
-use crate::domain::model::user::{
-    entity::{UserId, UserName},
-    repository::UserRepositoryTrait,
-    service::UserService,
+use crate::application::audit::audit_entry;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    error::DomainError,
+    user::{
+        entity::{UserId, UserName},
+        repository::UserRepositoryTrait,
+        service::UserService,
+    },
 };
 
-use anyhow::{anyhow, Result};
-use std::sync::{Arc, Mutex};
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
 pub struct UserUpdateInfoService {
     user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
 }
 
 pub struct UserUpdateCommand {
+    actor: Actor,
     id: String,
     name: Option<String>,
 }
 
 impl UserUpdateCommand {
-    pub fn new(id: &str, name: Option<&str>) -> Self {
+    pub fn new(actor: &Actor, id: &str, name: Option<&str>) -> Self {
         Self {
+            actor: actor.clone(),
             id: id.to_string(),
             name: name.map(|x| x.to_string()),
         }
@@ -32,12 +41,16 @@ impl UserUpdateInfoService {
 
     pub async fn handle(&self, command: UserUpdateCommand) -> Result<()> {
         let target_id = UserId::new(&command.id)?;
-        let repo = self.user_repository.lock().unwrap();
+        command
+            .actor
+            .authorize(ApiKeyScope::UsersWrite, &[&target_id])?;
+        let repo = self.user_repository.lock().await;
 
         let mut user = repo
             .find_by_id(&target_id)
             .await?
-            .ok_or_else(|| anyhow!("Could not find the target user."))?;
+            .ok_or_else(|| DomainError::NotFound("Could not find the target user.".to_string()))?;
+        let before = user.clone();
 
         if let Some(name) = command.name {
             let new_user_name = UserName::new(&name)?;
@@ -45,10 +58,12 @@ impl UserUpdateInfoService {
 
             let user_service = UserService::new(&*repo);
             if user_service.exists(&user).await {
-                return Err(anyhow!("User already exists"));
+                return Err(DomainError::Conflict("User already exists".to_string()).into());
             }
         }
 
-        repo.save(&user).await
+        let entry = audit_entry(&command.actor, "user.rename", Some(&before), Some(&user));
+        repo.save_audited(&mut user, &entry).await?;
+        Ok(())
     }
 }
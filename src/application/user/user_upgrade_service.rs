@@ -1,19 +1,30 @@
-use crate::domain::model::user::{entity::UserId, repository::UserRepositoryTrait};
+use crate::application::audit::audit_entry;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    error::DomainError,
+    user::{entity::UserId, repository::UserRepositoryTrait},
+};
 
-use anyhow::{anyhow, Result};
-use std::sync::{Arc, Mutex};
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
 pub struct UserUpgradeService {
     user_repository: Arc<Mutex<dyn UserRepositoryTrait + Send + Sync>>,
 }
 
 pub struct UserUpgradeCommand {
+    actor: Actor,
     id: String,
 }
 
 impl UserUpgradeCommand {
-    pub fn new(id: &str) -> Self {
-        Self { id: id.to_string() }
+    pub fn new(actor: &Actor, id: &str) -> Self {
+        Self {
+            actor: actor.clone(),
+            id: id.to_string(),
+        }
     }
 }
 
@@ -24,15 +35,102 @@ impl UserUpgradeService {
 
     pub async fn handle(&self, command: UserUpgradeCommand) -> Result<()> {
         let target_id = UserId::new(&command.id)?;
-        let repo = self.user_repository.lock().unwrap();
+        // Premium is the paid plan, so users cannot change their own.
+        command.actor.authorize(ApiKeyScope::PremiumWrite, &[])?;
+        let repo = self.user_repository.lock().await;
 
         let mut user = repo
             .find_by_id(&target_id)
             .await?
-            .ok_or_else(|| anyhow!("Could not find the target user."))?;
+            .ok_or_else(|| DomainError::NotFound("Could not find the target user.".to_string()))?;
 
+        let before = user.clone();
         user.upgrade()?;
 
-        repo.save(&user).await
+        let entry = audit_entry(&command.actor, "user.upgrade", Some(&before), Some(&user));
+        repo.save_audited(&mut user, &entry).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use serde_json::json;
+    use tokio::sync::Mutex;
+
+    use crate::domain::model::{
+        api_key::entity::{ApiKeyId, ApiKeyScope},
+        audit::{entity::Actor, repository::AuditRepositoryTrait},
+        error::DomainError,
+        user::{
+            entity::{User, UserId, UserIsPremium, UserName},
+            repository::UserRepositoryTrait,
+        },
+    };
+    use crate::infrastructure::database::{
+        audit::InMemoryAuditDatabase, user::InMemoryUserDatabase,
+    };
+    use crate::interface::repository::{audit::AuditRepository, user::UserRepository};
+
+    use super::{UserUpgradeCommand, UserUpgradeService};
+
+    #[tokio::test]
+    async fn records_the_upgrade_by_a_premium_key_in_the_audit_log() {
+        let user_repository = UserRepository::new(Box::new(InMemoryUserDatabase::new()))
+            .await
+            .map(|repo| Arc::new(Mutex::new(repo)))
+            .unwrap();
+        let audit_repository = AuditRepository::new(Box::new(InMemoryAuditDatabase::new()))
+            .await
+            .unwrap();
+        let service = UserUpgradeService::new(Arc::clone(&user_repository) as _);
+
+        let id = UserId::new("3f1b7c2e-6a4d-4e8f-9b0c-2d5e8a1f4c67").unwrap();
+        let mut user = User::new(
+            id.clone(),
+            UserName::new("audited").unwrap(),
+            UserIsPremium::new(false),
+        )
+        .unwrap();
+        user_repository.lock().await.save(&mut user).await.unwrap();
+
+        let error = service
+            .handle(UserUpgradeCommand::new(
+                &Actor::User(id.clone()),
+                &id.to_string(),
+            ))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<DomainError>(),
+            Some(DomainError::Forbidden(_))
+        ));
+
+        let actor = Actor::ApiKey {
+            id: ApiKeyId::new("9d2e4f61-0b3a-4c7d-8e5f-1a6b2c3d4e5f").unwrap(),
+            scopes: vec![ApiKeyScope::PremiumWrite],
+        };
+        service
+            .handle(UserUpgradeCommand::new(&actor, &id.to_string()))
+            .await
+            .unwrap();
+
+        let entries = audit_repository
+            .find(Some(&id.to_string()), 10)
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.actor.to_string(), actor.to_string());
+        assert_eq!(entry.action, "user.upgrade");
+        assert_eq!(entry.target_type, "user");
+        let before = entry.before.as_ref().unwrap();
+        let after = entry.after.as_ref().unwrap();
+        assert_eq!(before["is_premium"], json!(false));
+        assert_eq!(before["premium_since"], json!(null));
+        assert_eq!(after["is_premium"], json!(true));
+        assert!(after["premium_since"].is_string());
     }
 }
@@ -0,0 +1,11 @@
+mod webhook_create_service;
+mod webhook_delete_service;
+mod webhook_deliveries_service;
+mod webhook_get_service;
+mod webhook_list_service;
+
+pub use webhook_create_service::{WebhookCreateCommand, WebhookCreateService};
+pub use webhook_delete_service::WebhookDeleteService;
+pub use webhook_deliveries_service::{WebhookDeliveriesService, WebhookDeliveryData};
+pub use webhook_get_service::{WebhookData, WebhookGetService};
+pub use webhook_list_service::WebhookListService;
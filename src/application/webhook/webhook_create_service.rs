@@ -0,0 +1,74 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use super::WebhookData;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    webhook::{
+        entity::{WebhookEvents, WebhookSecret, WebhookUrl},
+        factory::WebhookFactoryTrait,
+        repository::WebhookRepositoryTrait,
+    },
+};
+
+pub struct WebhookCreateService {
+    webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>,
+    webhook_factory: Arc<dyn WebhookFactoryTrait + Send + Sync>,
+    allow_private_hosts: bool,
+}
+
+pub struct WebhookCreateCommand {
+    actor: Actor,
+    url: String,
+    events: Vec<String>,
+    secret: Option<String>,
+}
+
+impl WebhookCreateCommand {
+    pub fn new(actor: &Actor, url: &str, events: &[String], secret: Option<&str>) -> Self {
+        Self {
+            actor: actor.clone(),
+            url: url.to_string(),
+            events: events.to_vec(),
+            secret: secret.map(|x| x.to_string()),
+        }
+    }
+}
+
+impl WebhookCreateService {
+    pub fn new(
+        webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>,
+        webhook_factory: Arc<dyn WebhookFactoryTrait + Send + Sync>,
+        allow_private_hosts: bool,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            webhook_factory,
+            allow_private_hosts,
+        }
+    }
+
+    /// Registers the webhook. The result is the only place the generated
+    /// secret is returned. URLs of private hosts are refused unless they are
+    /// allowed.
+    pub async fn handle(&self, command: WebhookCreateCommand) -> Result<WebhookData> {
+        command.actor.authorize(ApiKeyScope::WebhooksWrite, &[])?;
+        let url = WebhookUrl::new(&command.url)?;
+        if !self.allow_private_hosts {
+            url.ensure_public_host()?;
+        }
+        let events = WebhookEvents::new(&command.events)?;
+        let secret = command
+            .secret
+            .as_deref()
+            .map(WebhookSecret::new)
+            .transpose()?;
+
+        let webhook = self.webhook_factory.create(url, events, secret)?;
+        self.webhook_repository.lock().await.save(&webhook).await?;
+
+        Ok(WebhookData::new(&webhook))
+    }
+}
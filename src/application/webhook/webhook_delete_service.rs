@@ -0,0 +1,29 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    webhook::{entity::WebhookId, repository::WebhookRepositoryTrait},
+};
+
+pub struct WebhookDeleteService {
+    webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>,
+}
+
+impl WebhookDeleteService {
+    pub fn new(webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn handle(&self, actor: &Actor, id: &str) -> Result<()> {
+        actor.authorize(ApiKeyScope::WebhooksWrite, &[])?;
+        let id = WebhookId::new(id)?;
+        let repo = self.webhook_repository.lock().await;
+        match repo.find_by_id(&id).await? {
+            Some(_) => repo.delete(&id).await,
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    error::DomainError,
+    webhook::{
+        entity::{WebhookDelivery, WebhookId},
+        repository::WebhookRepositoryTrait,
+    },
+};
+
+const DEFAULT_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 100;
+
+pub struct WebhookDeliveriesService {
+    webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>,
+}
+
+#[derive(Debug)]
+pub struct WebhookDeliveryData {
+    pub id: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub status: &'static str,
+    pub attempts: u32,
+    pub response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDeliveryData {
+    pub fn new(delivery: &WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id.to_string(),
+            event_id: delivery.event_id.to_string(),
+            event_type: delivery.event_type.to_string(),
+            status: delivery.status.as_str(),
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error.clone(),
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
+impl WebhookDeliveriesService {
+    pub fn new(webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>) -> Self {
+        Self { webhook_repository }
+    }
+
+    /// The latest deliveries to the webhook, newest first.
+    pub async fn handle(
+        &self,
+        actor: &Actor,
+        id: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<WebhookDeliveryData>> {
+        actor.authorize(ApiKeyScope::WebhooksWrite, &[])?;
+        let limit = match limit {
+            None => DEFAULT_LIMIT,
+            Some(limit) if (1..=MAX_LIMIT).contains(&limit) => limit,
+            Some(_) => {
+                return Err(DomainError::invalid_field(
+                    "limit",
+                    "range",
+                    &format!("limit must be between 1 and {}", MAX_LIMIT),
+                )
+                .into())
+            }
+        };
+
+        let id = WebhookId::new(id)?;
+        let repo = self.webhook_repository.lock().await;
+        if repo.find_by_id(&id).await?.is_none() {
+            return Err(DomainError::NotFound("Could not find the webhook.".to_string()).into());
+        }
+
+        Ok(repo
+            .find_deliveries(&id, limit)
+            .await?
+            .iter()
+            .map(WebhookDeliveryData::new)
+            .collect())
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope,
+    audit::entity::Actor,
+    webhook::{
+        entity::{Webhook, WebhookId},
+        repository::WebhookRepositoryTrait,
+    },
+};
+
+pub struct WebhookGetService {
+    webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>,
+}
+
+#[derive(Debug)]
+pub struct WebhookData {
+    pub id: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookData {
+    pub fn new(webhook: &Webhook) -> Self {
+        Self {
+            id: webhook.get_id().to_string(),
+            url: webhook.get_url().to_string(),
+            events: webhook.get_events().to_vec(),
+            secret: webhook.get_secret().to_string(),
+            created_at: *webhook.get_created_at(),
+        }
+    }
+}
+
+impl WebhookGetService {
+    pub fn new(webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn handle(&self, actor: &Actor, id: &str) -> Result<Option<WebhookData>> {
+        actor.authorize(ApiKeyScope::WebhooksWrite, &[])?;
+        let id = WebhookId::new(id)?;
+        let repo = self.webhook_repository.lock().await;
+        Ok(repo.find_by_id(&id).await?.as_ref().map(WebhookData::new))
+    }
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use super::WebhookData;
+use crate::domain::model::{
+    api_key::entity::ApiKeyScope, audit::entity::Actor, webhook::repository::WebhookRepositoryTrait,
+};
+
+pub struct WebhookListService {
+    webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>,
+}
+
+impl WebhookListService {
+    pub fn new(webhook_repository: Arc<Mutex<dyn WebhookRepositoryTrait + Send + Sync>>) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn handle(&self, actor: &Actor) -> Result<Vec<WebhookData>> {
+        actor.authorize(ApiKeyScope::WebhooksWrite, &[])?;
+        let repo = self.webhook_repository.lock().await;
+        Ok(repo
+            .find_all()
+            .await?
+            .iter()
+            .map(WebhookData::new)
+            .collect())
+    }
+}
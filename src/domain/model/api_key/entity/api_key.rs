@@ -0,0 +1,139 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use validator::Validate;
+
+use super::{ApiKeyId, ApiKeyName, ApiKeyScope};
+use crate::domain::model::error::DomainError;
+
+/// How many leading characters of a key are kept in the clear to tell keys
+/// apart.
+const PREFIX_LENGTH: usize = 12;
+
+/// A credential other services use to call us with the permissions of its
+/// scopes. Only a hash of the key is kept, so a lost key cannot be shown
+/// again, only revoked and replaced.
+#[derive(Debug, Clone, Validate)]
+pub struct ApiKey {
+    #[validate]
+    id: ApiKeyId,
+    #[validate]
+    name: ApiKeyName,
+    prefix: String,
+    key_hash: String,
+    scopes: Vec<ApiKeyScope>,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    /// A new key for the plain text `key`, which the caller hands out once.
+    pub fn new(id: ApiKeyId, name: ApiKeyName, key: &str, scopes: &[ApiKeyScope]) -> Result<Self> {
+        let prefix = key.chars().take(PREFIX_LENGTH).collect();
+        Self::restore(
+            id,
+            name,
+            prefix,
+            Self::hash(key),
+            scopes,
+            Utc::now(),
+            None,
+            None,
+        )
+    }
+
+    /// Rebuilds a key that already exists, e.g. when loading it from a database.
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: ApiKeyId,
+        name: ApiKeyName,
+        prefix: String,
+        key_hash: String,
+        scopes: &[ApiKeyScope],
+        created_at: DateTime<Utc>,
+        last_used_at: Option<DateTime<Utc>>,
+        revoked_at: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
+        if scopes.is_empty() {
+            return Err(DomainError::invalid_field(
+                "scopes",
+                "length",
+                "An API key needs at least one scope.",
+            )
+            .into());
+        }
+        let mut unique = Vec::new();
+        for scope in scopes {
+            if !unique.contains(scope) {
+                unique.push(*scope);
+            }
+        }
+
+        let data = Self {
+            id,
+            name,
+            prefix,
+            key_hash,
+            scopes: unique,
+            created_at,
+            last_used_at,
+            revoked_at,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// The hash a key is stored and looked up by. Keys are long random
+    /// strings, so an unsalted hash is enough to keep them from being read
+    /// back.
+    pub fn hash(key: &str) -> String {
+        hex::encode(Sha256::digest(key.as_bytes()))
+    }
+
+    pub fn get_id(&self) -> &ApiKeyId {
+        &self.id
+    }
+
+    pub fn get_name(&self) -> &ApiKeyName {
+        &self.name
+    }
+
+    pub fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn get_key_hash(&self) -> &str {
+        &self.key_hash
+    }
+
+    pub fn get_scopes(&self) -> &[ApiKeyScope] {
+        &self.scopes
+    }
+
+    pub fn get_created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn get_last_used_at(&self) -> Option<&DateTime<Utc>> {
+        self.last_used_at.as_ref()
+    }
+
+    pub fn get_revoked_at(&self) -> Option<&DateTime<Utc>> {
+        self.revoked_at.as_ref()
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    pub fn revoke(&mut self) -> Result<()> {
+        if self.is_revoked() {
+            return Err(
+                DomainError::Conflict("The API key is already revoked.".to_string()).into(),
+            );
+        }
+        self.revoked_at = Some(Utc::now());
+        Ok(())
+    }
+}
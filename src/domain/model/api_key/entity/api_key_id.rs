@@ -0,0 +1,29 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use validator::Validate;
+
+use crate::domain::model::error::{DomainError, FieldError};
+
+#[derive(Debug, Clone, Validate, PartialEq, Eq)]
+pub struct ApiKeyId {
+    #[validate(length(min = 1))]
+    value: String,
+}
+
+impl ApiKeyId {
+    pub fn new(value: &str) -> Result<Self> {
+        let data = Self {
+            value: value.to_string(),
+        };
+        data.validate()
+            .map_err(|e| DomainError::Validation(FieldError::from_validation("api_key_id", &e)))?;
+        Ok(data)
+    }
+}
+
+impl Display for ApiKeyId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
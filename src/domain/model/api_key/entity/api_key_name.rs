@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use validator::Validate;
+
+use crate::domain::model::error::{DomainError, FieldError};
+
+/// What the key is for, e.g. the tool that uses it.
+#[derive(Debug, Clone, Validate, PartialEq, Eq)]
+pub struct ApiKeyName {
+    #[validate(length(min = 1, max = 64))]
+    value: String,
+}
+
+impl ApiKeyName {
+    pub fn new(value: &str) -> Result<Self> {
+        let data = Self {
+            value: value.trim().to_string(),
+        };
+        data.validate()
+            .map_err(|e| DomainError::Validation(FieldError::from_validation("name", &e)))?;
+        Ok(data)
+    }
+}
+
+impl Display for ApiKeyName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+
+use crate::domain::model::error::DomainError;
+
+/// What an API key may do. A key acts for no user in particular, so a scope
+/// covers every user or club.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKeyScope {
+    /// Rename, delete and import users.
+    UsersWrite,
+    /// Upgrade users to premium and downgrade them.
+    PremiumWrite,
+    /// Create clubs and change their members and owner.
+    ClubsWrite,
+    /// Register, inspect and remove webhooks.
+    WebhooksWrite,
+    /// Read the audit log.
+    AuditRead,
+    /// Export every user or club.
+    ExportRead,
+}
+
+impl ApiKeyScope {
+    pub const ALL: [Self; 6] = [
+        Self::UsersWrite,
+        Self::PremiumWrite,
+        Self::ClubsWrite,
+        Self::WebhooksWrite,
+        Self::AuditRead,
+        Self::ExportRead,
+    ];
+
+    pub fn parse(value: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == value)
+            .ok_or_else(|| {
+                DomainError::invalid_field(
+                    "scopes",
+                    "one_of",
+                    &format!(
+                        "Unknown scope `{}`. Expected one of {}",
+                        value,
+                        Self::ALL.map(|s| s.as_str()).join(", ")
+                    ),
+                )
+                .into()
+            })
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UsersWrite => "users:write",
+            Self::PremiumWrite => "premium:write",
+            Self::ClubsWrite => "clubs:write",
+            Self::WebhooksWrite => "webhooks:write",
+            Self::AuditRead => "audit:read",
+            Self::ExportRead => "export:read",
+        }
+    }
+}
+
+impl Display for ApiKeyScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,9 @@
+mod api_key;
+mod api_key_id;
+mod api_key_name;
+mod api_key_scope;
+
+pub use api_key::ApiKey;
+pub use api_key_id::ApiKeyId;
+pub use api_key_name::ApiKeyName;
+pub use api_key_scope::ApiKeyScope;
@@ -0,0 +1,32 @@
+use rand::{distributions::Alphanumeric, Rng};
+
+use super::ApiKeyFactoryTrait;
+use crate::domain::model::api_key::entity::{ApiKey, ApiKeyId, ApiKeyName, ApiKeyScope};
+
+use anyhow::Result;
+
+const KEY_PREFIX: &str = "ddd_";
+const KEY_LENGTH: usize = 40;
+
+pub struct ApiKeyFactory {}
+
+impl ApiKeyFactory {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ApiKeyFactoryTrait for ApiKeyFactory {
+    fn create(&self, name: ApiKeyName, scopes: &[ApiKeyScope]) -> Result<(ApiKey, String)> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let id = ApiKeyId::new(&id)?;
+        let secret = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(KEY_LENGTH)
+            .map(char::from)
+            .collect::<String>();
+        let key = format!("{}{}", KEY_PREFIX, secret);
+
+        Ok((ApiKey::new(id, name, &key, scopes)?, key))
+    }
+}
@@ -0,0 +1,8 @@
+use super::super::entity::{ApiKey, ApiKeyName, ApiKeyScope};
+use anyhow::Result;
+
+pub trait ApiKeyFactoryTrait {
+    /// Creates a key with a new id and a random secret. The plain text key is
+    /// returned next to it and cannot be recovered later.
+    fn create(&self, name: ApiKeyName, scopes: &[ApiKeyScope]) -> Result<(ApiKey, String)>;
+}
@@ -0,0 +1,4 @@
+mod api_key_factory;
+mod api_key_factory_trait;
+
+pub use self::{api_key_factory::ApiKeyFactory, api_key_factory_trait::*};
@@ -0,0 +1,15 @@
+use crate::domain::model::api_key::entity::{ApiKey, ApiKeyId};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ApiKeyRepositoryTrait {
+    async fn save(&self, api_key: &ApiKey) -> Result<()>;
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>>;
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>>;
+    async fn find_all(&self) -> Result<Vec<ApiKey>>;
+    /// Records that the key was used at `used_at`, leaving the rest as it is.
+    async fn touch(&self, id: &ApiKeyId, used_at: DateTime<Utc>) -> Result<()>;
+}
@@ -0,0 +1,3 @@
+mod api_key_repository_trait;
+
+pub use self::api_key_repository_trait::*;
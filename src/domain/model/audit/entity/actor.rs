@@ -0,0 +1,83 @@
+use std::fmt;
+
+use anyhow::Result;
+
+use crate::domain::model::{
+    api_key::entity::{ApiKeyId, ApiKeyScope},
+    error::DomainError,
+    user::entity::UserId,
+};
+
+/// Who handles a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Actor {
+    /// A caller that has not identified itself.
+    Anonymous,
+    /// A registered user.
+    User(UserId),
+    /// Another service calling with an API key, limited to the key's scopes.
+    ApiKey {
+        id: ApiKeyId,
+        scopes: Vec<ApiKeyScope>,
+    },
+    /// The application itself, such as the command line or a background job.
+    System(String),
+}
+
+impl Actor {
+    /// Reads the form written by `to_string`: `anonymous`, `user:{id}`,
+    /// `api_key:{id}` or `system:{name}`. The form does not hold the scopes of
+    /// an API key, so the key is read back without any.
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.split_once(':') {
+            None if value == "anonymous" => Ok(Self::Anonymous),
+            Some(("user", id)) => Ok(Self::User(UserId::new(id)?)),
+            Some(("api_key", id)) => Ok(Self::ApiKey {
+                id: ApiKeyId::new(id)?,
+                scopes: Vec::new(),
+            }),
+            Some(("system", name)) if !name.is_empty() => Ok(Self::System(name.to_string())),
+            _ => Err(DomainError::invalid_field(
+                "actor",
+                "format",
+                &format!("Unknown actor `{}`", value),
+            )
+            .into()),
+        }
+    }
+
+    /// Fails unless the actor may act for one of `users`: a user may change
+    /// only their own account and the clubs they own, an API key anything its
+    /// scopes include `scope` for, and the system anything.
+    pub fn authorize(&self, scope: ApiKeyScope, users: &[&UserId]) -> Result<()> {
+        match self {
+            Self::System(_) => Ok(()),
+            Self::ApiKey { scopes, .. } if scopes.contains(&scope) => Ok(()),
+            Self::ApiKey { .. } => Err(DomainError::Forbidden(format!(
+                "The API key does not have the `{}` scope.",
+                scope
+            ))
+            .into()),
+            Self::User(id) if users.contains(&id) => Ok(()),
+            Self::User(_) => Err(DomainError::Forbidden(
+                "You are not allowed to change this resource.".to_string(),
+            )
+            .into()),
+            Self::Anonymous => Err(DomainError::Unauthorized(
+                "You must be authenticated to change this resource.".to_string(),
+            )
+            .into()),
+        }
+    }
+}
+
+impl fmt::Display for Actor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Anonymous => write!(f, "anonymous"),
+            Self::User(id) => write!(f, "user:{}", id),
+            Self::ApiKey { id, .. } => write!(f, "api_key:{}", id),
+            Self::System(name) => write!(f, "system:{}", name),
+        }
+    }
+}
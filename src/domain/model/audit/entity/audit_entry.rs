@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use super::Actor;
+
+/// A command that changed a user or a club. `before` and `after` are
+/// snapshots of the target; `before` is missing when the command created it
+/// and `after` when the command deleted it.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub id: String,
+    pub actor: Actor,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub occurred_at: DateTime<Utc>,
+}
@@ -0,0 +1,4 @@
+mod actor;
+mod audit_entry;
+
+pub use self::{actor::*, audit_entry::*};
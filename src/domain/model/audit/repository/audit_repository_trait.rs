@@ -0,0 +1,11 @@
+use crate::domain::model::audit::entity::AuditEntry;
+use anyhow::Result;
+
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AuditRepositoryTrait {
+    /// The latest entries, newest first, only those about `target_id` when
+    /// it is given.
+    async fn find(&self, target_id: Option<&str>, limit: usize) -> Result<Vec<AuditEntry>>;
+}
@@ -0,0 +1,3 @@
+mod audit_repository_trait;
+
+pub use audit_repository_trait::*;
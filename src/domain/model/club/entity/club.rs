@@ -1,7 +1,11 @@
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use validator::Validate;
 
-use crate::domain::model::user::entity::{User, UserId};
+use crate::domain::model::{
+    error::DomainError,
+    event::DomainEvent,
+    user::entity::{User, UserId},
+};
 
 use super::{ClubId, ClubName};
 
@@ -14,20 +18,110 @@ pub struct Club {
     members: Vec<UserId>,
     #[validate]
     owner: UserId,
+    events: Vec<DomainEvent>,
+    version: u64,
 }
 
 impl Club {
     pub fn new(id: ClubId, name: ClubName, members: Vec<UserId>, owner: UserId) -> Result<Self> {
+        let mut club = Self::restore(id, name, members, owner)?;
+        club.events.push(DomainEvent::ClubCreated {
+            club_id: club.id.clone(),
+            name: club.name.clone(),
+            owner: club.owner.clone(),
+        });
+        Ok(club)
+    }
+
+    /// Rebuilds a club that already exists, e.g. when loading it from a database.
+    pub fn restore(
+        id: ClubId,
+        name: ClubName,
+        members: Vec<UserId>,
+        owner: UserId,
+    ) -> Result<Self> {
         let data = Self {
             id,
             name,
             members,
             owner,
+            events: Vec::new(),
+            version: 0,
         };
         data.validate()?;
         Ok(data)
     }
 
+    /// Rebuilds a club from a snapshot taken after `version` events of its
+    /// stream. The events recorded after the snapshot are applied on top.
+    pub fn from_snapshot(
+        id: ClubId,
+        name: ClubName,
+        members: Vec<UserId>,
+        owner: UserId,
+        version: u64,
+    ) -> Result<Self> {
+        let mut club = Self::restore(id, name, members, owner)?;
+        club.version = version;
+        Ok(club)
+    }
+
+    /// Rebuilds a club by replaying its stream, which starts with
+    /// `ClubCreated`.
+    pub fn from_events(events: &[DomainEvent]) -> Result<Self> {
+        let (first, rest) = events
+            .split_first()
+            .ok_or_else(|| anyhow!("A club stream cannot be empty"))?;
+        let mut club = match first {
+            DomainEvent::ClubCreated {
+                club_id,
+                name,
+                owner,
+            } => Self::restore(club_id.clone(), name.clone(), Vec::new(), owner.clone())?,
+            other => bail!(
+                "A club stream starts with {} instead of ClubCreated",
+                other.name()
+            ),
+        };
+        club.version = 1;
+        for event in rest {
+            club.apply(event)?;
+        }
+        Ok(club)
+    }
+
+    /// Applies an event of the club's stream while it is rebuilt. The event
+    /// is not recorded again and the rules it passed are not checked again.
+    pub fn apply(&mut self, event: &DomainEvent) -> Result<()> {
+        if event.aggregate_id() != self.id.to_string() {
+            bail!(
+                "{} belongs to {} instead of club {}",
+                event.name(),
+                event.aggregate_id(),
+                self.id
+            );
+        }
+
+        match event {
+            DomainEvent::ClubRenamed { name, .. } => self.name = name.clone(),
+            DomainEvent::MemberJoined { user_id, .. } => {
+                if !self.members.contains(user_id) {
+                    self.members.push(user_id.clone());
+                }
+            }
+            DomainEvent::MemberLeft { user_id, .. } => self.members.retain(|m| m != user_id),
+            DomainEvent::OwnershipTransferred {
+                previous_owner,
+                new_owner,
+                ..
+            } => self.hand_over(previous_owner, new_owner),
+            other => bail!("{} cannot be applied to an existing club", other.name()),
+        }
+        self.version += 1;
+
+        Ok(())
+    }
+
     pub fn get_name(&self) -> &ClubName {
         &self.name
     }
@@ -44,9 +138,32 @@ impl Club {
         &self.members
     }
 
+    /// Events recorded since the club was created, loaded or last saved.
+    pub fn get_events(&self) -> &[DomainEvent] {
+        &self.events
+    }
+
+    /// Forgets the recorded events once they are saved, so that saving the
+    /// club again does not record them twice. The saved events now count
+    /// towards the club's version.
+    pub fn clear_events(&mut self) {
+        self.version += self.events.len() as u64;
+        self.events.clear();
+    }
+
+    /// The number of stored events the club was rebuilt from. It is zero for
+    /// a new club and for a club loaded from the state-based tables.
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+
     pub fn change_name(&mut self, name: ClubName) -> Result<()> {
         self.name = name;
         self.validate()?;
+        self.events.push(DomainEvent::ClubRenamed {
+            club_id: self.id.clone(),
+            name: self.name.clone(),
+        });
 
         Ok(())
     }
@@ -62,13 +179,67 @@ impl Club {
 
     pub fn join(&mut self, user: User) -> Result<()> {
         if self.is_full() {
-            return Err(anyhow!(
-                "Club members have already reached the upper limmit."
-            ));
+            return Err(DomainError::Conflict(
+                "Club members have already reached the upper limmit.".to_string(),
+            )
+            .into());
         }
 
         self.members.push(user.get_id().to_owned());
+        self.events.push(DomainEvent::MemberJoined {
+            club_id: self.id.clone(),
+            user_id: user.get_id().clone(),
+        });
+
+        Ok(())
+    }
+
+    pub fn leave(&mut self, user_id: &UserId) -> Result<()> {
+        if !self.members.contains(user_id) {
+            return Err(DomainError::NotFound(
+                "The user is not a member of this club.".to_string(),
+            )
+            .into());
+        }
+
+        self.members.retain(|m| m != user_id);
+        self.events.push(DomainEvent::MemberLeft {
+            club_id: self.id.clone(),
+            user_id: user_id.clone(),
+        });
 
         Ok(())
     }
+
+    /// Makes a member the owner. The previous owner stays in the club as a
+    /// member.
+    pub fn transfer_ownership(&mut self, new_owner: &UserId) -> Result<()> {
+        if *new_owner == self.owner {
+            return Err(
+                DomainError::Conflict("The user already owns this club.".to_string()).into(),
+            );
+        }
+        if !self.members.contains(new_owner) {
+            return Err(DomainError::NotFound(
+                "The user is not a member of this club.".to_string(),
+            )
+            .into());
+        }
+
+        let previous_owner = self.owner.clone();
+        self.hand_over(&previous_owner, new_owner);
+        self.events.push(DomainEvent::OwnershipTransferred {
+            club_id: self.id.clone(),
+            previous_owner,
+            new_owner: new_owner.clone(),
+        });
+
+        Ok(())
+    }
+
+    fn hand_over(&mut self, previous_owner: &UserId, new_owner: &UserId) {
+        self.members.retain(|m| m != new_owner);
+        self.members.push(previous_owner.clone());
+        self.owner = new_owner.clone();
+    }
 }
@@ -3,9 +3,11 @@ use std::fmt::Display;
 use anyhow::Result;
 use validator::Validate;
 
+use crate::domain::model::error::{DomainError, FieldError};
+
 #[derive(Debug, Clone, Validate, PartialEq, Eq)]
 pub struct ClubName {
-    #[validate(length(min = 3))]
+    #[validate(length(min = 3, message = "The length of a club name must be at least 3"))]
     value: String,
 }
 
@@ -14,7 +16,8 @@ impl ClubName {
         let data = Self {
             value: value.to_string(),
         };
-        data.validate()?;
+        data.validate()
+            .map_err(|e| DomainError::Validation(FieldError::from_validation("name", &e)))?;
         Ok(data)
     }
 }
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::model::user::entity::{User, UserId};
+
+use super::{Club, ClubId, ClubName};
+
+/// The read model of a club's membership. It is kept up to date from the
+/// domain events, so the counts can be read without loading the members.
+/// Neither count includes the owner.
+#[derive(Debug, Clone)]
+pub struct ClubStatistics {
+    pub club_id: ClubId,
+    pub name: ClubName,
+    pub owner_id: UserId,
+    pub member_count: usize,
+    pub premium_member_count: usize,
+    pub last_joined_at: Option<DateTime<Utc>>,
+}
+
+impl ClubStatistics {
+    /// Counts the members of `club`. `members` are the users behind its
+    /// member ids; deleted users and users that no longer exist are not
+    /// counted.
+    pub fn tally(club: &Club, members: &[User], last_joined_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            club_id: club.get_id().clone(),
+            name: club.get_name().clone(),
+            owner_id: club.get_owner_id().clone(),
+            member_count: members.len(),
+            premium_member_count: members
+                .iter()
+                .filter(|m| m.get_is_premium().to_inner())
+                .count(),
+            last_joined_at,
+        }
+    }
+
+    /// The number of members including the owner, as `Club::count_members`.
+    pub fn count_members(&self) -> usize {
+        self.member_count + 1
+    }
+}
@@ -2,5 +2,6 @@ mod club;
 mod club_id;
 mod club_members;
 mod club_name;
+mod club_statistics;
 
-pub use self::{club::*, club_id::*, club_members::*, club_name::*};
+pub use self::{club::*, club_id::*, club_members::*, club_name::*, club_statistics::*};
@@ -0,0 +1,16 @@
+use crate::domain::model::club::entity::{ClubId, ClubName};
+
+/// The position of the last club of the previous page. Clubs are listed by
+/// name, with the id breaking ties.
+#[derive(Debug, Clone)]
+pub struct ClubListCursor {
+    pub id: ClubId,
+    pub name: ClubName,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClubListQuery {
+    pub name_contains: Option<String>,
+    pub after: Option<ClubListCursor>,
+    pub limit: usize,
+}
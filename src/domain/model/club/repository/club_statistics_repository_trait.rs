@@ -0,0 +1,15 @@
+use crate::domain::model::club::entity::{ClubId, ClubStatistics};
+use anyhow::Result;
+
+use async_trait::async_trait;
+
+/// The club statistics projection. It lags behind the clubs by the time the
+/// outbox relay takes to publish their events.
+#[async_trait]
+pub trait ClubStatisticsRepositoryTrait {
+    async fn save(&self, statistics: &ClubStatistics) -> Result<()>;
+    async fn find_by_id(&self, id: &ClubId) -> Result<Option<ClubStatistics>>;
+    async fn find_all(&self) -> Result<Vec<ClubStatistics>>;
+    async fn delete(&self, id: &ClubId) -> Result<()>;
+    async fn delete_all(&self) -> Result<()>;
+}
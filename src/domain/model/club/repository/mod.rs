@@ -1,3 +1,7 @@
+mod club_list_query;
+mod club_statistics_repository_trait;
 mod repository_trait;
 
-pub use self::repository_trait::*;
\ No newline at end of file
+pub use self::club_list_query::*;
+pub use self::club_statistics_repository_trait::*;
+pub use self::repository_trait::*;
@@ -1,12 +1,26 @@
-use crate::domain::model::club::entity::{Club, ClubId, ClubName};
+use crate::domain::model::{
+    audit::entity::AuditEntry,
+    club::{
+        entity::{Club, ClubId, ClubName},
+        repository::ClubListQuery,
+    },
+    user::entity::UserId,
+};
 use anyhow::Result;
 
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait ClubRepositoryTrait {
-    async fn save(&self, club: &Club) -> Result<()>;
+    /// Saves the club along with the events it recorded, then clears them.
+    async fn save(&self, club: &mut Club) -> Result<()>;
+    /// Saves the club and records `entry` in the audit log in the same
+    /// transaction, so that the change is never saved without its entry.
+    async fn save_audited(&self, club: &mut Club, entry: &AuditEntry) -> Result<()>;
     async fn find_by_name(&self, club_name: &ClubName) -> Result<Option<Club>>;
     async fn find_by_id(&self, id: &ClubId) -> Result<Option<Club>>;
     async fn find_all(&self) -> Result<Vec<Club>>;
+    async fn find_page(&self, query: &ClubListQuery) -> Result<Vec<Club>>;
+    async fn find_by_owner(&self, owner: &UserId) -> Result<Vec<Club>>;
+    async fn find_by_member(&self, member: &UserId) -> Result<Vec<Club>>;
 }
@@ -1,19 +1,20 @@
 use crate::domain::model::club::{entity::Club, repository::ClubRepositoryTrait};
 
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
 pub struct ClubService {
-    repository: Arc<Mutex<dyn ClubRepositoryTrait>>,
+    repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>,
 }
 
 impl ClubService {
-    pub fn new(repository: Arc<Mutex<dyn ClubRepositoryTrait>>) -> ClubService {
+    pub fn new(repository: Arc<Mutex<dyn ClubRepositoryTrait + Send + Sync>>) -> ClubService {
         ClubService { repository }
     }
 
     pub async fn exists(&self, club: &Club) -> bool {
         let name = club.get_name();
-        let repository = self.repository.lock().unwrap();
+        let repository = self.repository.lock().await;
         match repository.find_by_name(name).await {
             Ok(maybe_user) => maybe_user.is_some(),
             Err(_) => false,
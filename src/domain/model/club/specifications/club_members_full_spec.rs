@@ -8,8 +8,15 @@ impl ClubMembersFullSpec {
     }
 
     pub fn is_satisfied_by(&self, members: ClubMembers) -> bool {
-        let premium_num = members.count_premium_members(false);
-        let limit = if premium_num < 1 { 3 } else { 50 };
-        members.count_members() >= limit
+        self.is_satisfied_by_counts(
+            members.count_members(),
+            members.count_premium_members(false),
+        )
+    }
+
+    /// The same check from the counts alone, neither including the owner.
+    pub fn is_satisfied_by_counts(&self, member_count: usize, premium_member_count: usize) -> bool {
+        let limit = if premium_member_count < 1 { 3 } else { 50 };
+        member_count >= limit
     }
 }
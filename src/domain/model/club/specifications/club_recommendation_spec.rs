@@ -1,4 +1,4 @@
-use crate::domain::model::club::entity::Club;
+use crate::domain::model::club::entity::{Club, ClubStatistics};
 
 pub struct ClubRecommendationSpec;
 
@@ -8,7 +8,15 @@ impl ClubRecommendationSpec {
     }
 
     pub fn is_satisfied_by(&self, club: &Club) -> bool {
-        club.count_members() >= 3
+        Self::is_large_enough(club.count_members())
+    }
+
+    pub fn is_satisfied_by_statistics(&self, statistics: &ClubStatistics) -> bool {
+        Self::is_large_enough(statistics.count_members())
+    }
+
+    fn is_large_enough(member_count: usize) -> bool {
+        member_count >= 3
         // TODO: ”設立１ヶ月以内”の条件も加える
     }
 }
@@ -0,0 +1,45 @@
+use std::fmt::Display;
+
+use super::FieldError;
+
+/// The kinds of failure callers are expected to tell apart. Anything else is
+/// reported as a plain `anyhow::Error`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainError {
+    /// Request fields break the rules of the value objects they are read
+    /// into, e.g. a user name that is too short.
+    Validation(Vec<FieldError>),
+    /// The user, club or membership the command refers to does not exist.
+    NotFound(String),
+    /// The command contradicts the current state, e.g. a duplicate name.
+    Conflict(String),
+    /// The command needs a caller who has identified itself.
+    Unauthorized(String),
+    /// The caller is known but may not run the command, e.g. on another
+    /// user's account.
+    Forbidden(String),
+}
+
+impl DomainError {
+    /// A `Validation` error for one broken rule of one field.
+    pub fn invalid_field(field: &str, rule: &str, message: &str) -> Self {
+        Self::Validation(vec![FieldError::new(field, rule, message)])
+    }
+}
+
+impl Display for DomainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(message)
+            | Self::Conflict(message)
+            | Self::Unauthorized(message)
+            | Self::Forbidden(message) => f.write_str(message),
+            Self::Validation(errors) => {
+                let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                f.write_str(&messages.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
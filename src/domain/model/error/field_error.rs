@@ -0,0 +1,45 @@
+use std::fmt::Display;
+
+use validator::ValidationErrors;
+
+/// One rule a request field breaks, e.g. the `length` of a `name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: String,
+    pub rule: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, rule: &str, message: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            rule: rule.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// The rules `validator` found broken on a value object, reported under
+    /// `field`, the name the value has in requests, rather than the name of
+    /// the value object's own field.
+    pub fn from_validation(field: &str, errors: &ValidationErrors) -> Vec<Self> {
+        errors
+            .field_errors()
+            .into_values()
+            .flatten()
+            .map(|error| {
+                let message = match &error.message {
+                    Some(message) => message.to_string(),
+                    None => format!("The {} is invalid", field.replace('_', " ")),
+                };
+                Self::new(field, &error.code, &message)
+            })
+            .collect()
+    }
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
@@ -0,0 +1,4 @@
+mod domain_error;
+mod field_error;
+
+pub use self::{domain_error::*, field_error::*};
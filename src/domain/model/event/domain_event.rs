@@ -0,0 +1,102 @@
+use crate::domain::model::{
+    club::entity::{ClubId, ClubName},
+    user::entity::{UserId, UserName},
+};
+
+/// Something that happened to an aggregate. Aggregates record events as their
+/// state changes and repositories dispatch them once the change is saved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainEvent {
+    UserRegistered {
+        user_id: UserId,
+        name: UserName,
+    },
+    UserRenamed {
+        user_id: UserId,
+        name: UserName,
+    },
+    UserUpgraded {
+        user_id: UserId,
+    },
+    UserDowngraded {
+        user_id: UserId,
+    },
+    UserDeleted {
+        user_id: UserId,
+    },
+    UserRestored {
+        user_id: UserId,
+    },
+    ClubCreated {
+        club_id: ClubId,
+        name: ClubName,
+        owner: UserId,
+    },
+    ClubRenamed {
+        club_id: ClubId,
+        name: ClubName,
+    },
+    MemberJoined {
+        club_id: ClubId,
+        user_id: UserId,
+    },
+    MemberLeft {
+        club_id: ClubId,
+        user_id: UserId,
+    },
+    OwnershipTransferred {
+        club_id: ClubId,
+        previous_owner: UserId,
+        new_owner: UserId,
+    },
+}
+
+impl DomainEvent {
+    /// The names of every kind of event, as returned by `name`.
+    pub const NAMES: [&'static str; 11] = [
+        "UserRegistered",
+        "UserRenamed",
+        "UserUpgraded",
+        "UserDowngraded",
+        "UserDeleted",
+        "UserRestored",
+        "ClubCreated",
+        "ClubRenamed",
+        "MemberJoined",
+        "MemberLeft",
+        "OwnershipTransferred",
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UserRegistered { .. } => "UserRegistered",
+            Self::UserRenamed { .. } => "UserRenamed",
+            Self::UserUpgraded { .. } => "UserUpgraded",
+            Self::UserDowngraded { .. } => "UserDowngraded",
+            Self::UserDeleted { .. } => "UserDeleted",
+            Self::UserRestored { .. } => "UserRestored",
+            Self::ClubCreated { .. } => "ClubCreated",
+            Self::ClubRenamed { .. } => "ClubRenamed",
+            Self::MemberJoined { .. } => "MemberJoined",
+            Self::MemberLeft { .. } => "MemberLeft",
+            Self::OwnershipTransferred { .. } => "OwnershipTransferred",
+        }
+    }
+
+    /// The id of the user or club the event belongs to.
+    pub fn aggregate_id(&self) -> String {
+        match self {
+            Self::UserRegistered { user_id, .. }
+            | Self::UserRenamed { user_id, .. }
+            | Self::UserUpgraded { user_id }
+            | Self::UserDowngraded { user_id }
+            | Self::UserDeleted { user_id }
+            | Self::UserRestored { user_id } => user_id.to_string(),
+            Self::ClubCreated { club_id, .. }
+            | Self::ClubRenamed { club_id, .. }
+            | Self::MemberJoined { club_id, .. }
+            | Self::MemberLeft { club_id, .. }
+            | Self::OwnershipTransferred { club_id, .. } => club_id.to_string(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::DomainEvent;
+
+/// Hands domain events to the parts of the system that react to them.
+/// Repositories do not call it when they save: they write the events to the
+/// outbox in the transaction of the change, and the outbox relay dispatches
+/// them after the commit, so no handler sees the event of a rolled back
+/// change.
+#[async_trait]
+pub trait DomainEventDispatcherTrait {
+    async fn dispatch(&self, events: &[DomainEvent]) -> Result<()>;
+}
@@ -0,0 +1,10 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::DomainEvent;
+
+/// Reacts to events dispatched after an aggregate is saved.
+#[async_trait]
+pub trait DomainEventHandlerTrait {
+    async fn handle(&self, event: &DomainEvent) -> Result<()>;
+}
@@ -0,0 +1,5 @@
+mod domain_event;
+mod domain_event_dispatcher_trait;
+mod domain_event_handler_trait;
+
+pub use self::{domain_event::*, domain_event_dispatcher_trait::*, domain_event_handler_trait::*};
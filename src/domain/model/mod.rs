@@ -1,2 +1,7 @@
+pub mod api_key;
+pub mod audit;
 pub mod club;
-pub mod user;
\ No newline at end of file
+pub mod error;
+pub mod event;
+pub mod user;
+pub mod webhook;
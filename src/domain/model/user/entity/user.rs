@@ -1,7 +1,9 @@
 use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
 use validator::Validate;
 
 use super::{UserId, UserIsPremium, UserName};
+use crate::domain::model::{error::DomainError, event::DomainEvent};
 
 #[derive(Debug, Clone, Validate)]
 pub struct User {
@@ -10,14 +12,48 @@ pub struct User {
     #[validate]
     name: UserName,
     is_premium: UserIsPremium,
+    premium_since: Option<DateTime<Utc>>,
+    premium_expires_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    events: Vec<DomainEvent>,
 }
 
 impl User {
     pub fn new(id: UserId, name: UserName, is_premium: UserIsPremium) -> Result<Self> {
+        let now = Utc::now();
+        let premium_since = if is_premium.to_inner() {
+            Some(now)
+        } else {
+            None
+        };
+        let mut user = Self::restore(id, name, is_premium, premium_since, None, now, None)?;
+        user.events.push(DomainEvent::UserRegistered {
+            user_id: user.id.clone(),
+            name: user.name.clone(),
+        });
+        Ok(user)
+    }
+
+    /// Rebuilds a user that already exists, e.g. when loading it from a database.
+    pub fn restore(
+        id: UserId,
+        name: UserName,
+        is_premium: UserIsPremium,
+        premium_since: Option<DateTime<Utc>>,
+        premium_expires_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        deleted_at: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
         let data = Self {
             id,
             name,
             is_premium,
+            premium_since,
+            premium_expires_at,
+            created_at,
+            deleted_at,
+            events: Vec::new(),
         };
         data.validate()?;
         Ok(data)
@@ -35,22 +71,148 @@ impl User {
         &self.is_premium
     }
 
+    /// When the user last became premium.
+    pub fn get_premium_since(&self) -> Option<&DateTime<Utc>> {
+        self.premium_since.as_ref()
+    }
+
+    /// When the user's premium membership ended, if they were downgraded
+    /// since they last became premium.
+    pub fn get_premium_expires_at(&self) -> Option<&DateTime<Utc>> {
+        self.premium_expires_at.as_ref()
+    }
+
+    pub fn get_created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn get_deleted_at(&self) -> Option<&DateTime<Utc>> {
+        self.deleted_at.as_ref()
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Events recorded since the user was created, loaded or last saved.
+    pub fn get_events(&self) -> &[DomainEvent] {
+        &self.events
+    }
+
+    /// Forgets the recorded events once they are saved, so that saving the
+    /// user again does not record them twice.
+    pub fn clear_events(&mut self) {
+        self.events.clear();
+    }
+
     pub fn change_name(&mut self, name: UserName) -> Result<()> {
         self.name = name;
         self.validate()?;
+        self.events.push(DomainEvent::UserRenamed {
+            user_id: self.id.clone(),
+            name: self.name.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Marks the user as deleted. The user keeps their name and clubs until
+    /// they are purged, so that they can be restored as they were.
+    pub fn delete(&mut self) -> Result<()> {
+        if self.is_deleted() {
+            return Err(DomainError::Conflict("The user is already deleted.".to_string()).into());
+        }
+        self.deleted_at = Some(Utc::now());
+        self.events.push(DomainEvent::UserDeleted {
+            user_id: self.id.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Brings back a deleted user, provided they were deleted less than
+    /// `retention` ago.
+    pub fn undelete(&mut self, retention: Duration) -> Result<()> {
+        let deleted_at = match self.deleted_at {
+            Some(deleted_at) => deleted_at,
+            None => {
+                return Err(DomainError::Conflict("The user is not deleted.".to_string()).into())
+            }
+        };
+        if deleted_at + retention <= Utc::now() {
+            return Err(DomainError::Conflict(
+                "The user was deleted too long ago to be restored.".to_string(),
+            )
+            .into());
+        }
+        self.deleted_at = None;
+        self.events.push(DomainEvent::UserRestored {
+            user_id: self.id.clone(),
+        });
 
         Ok(())
     }
 
     pub fn upgrade(&mut self) -> Result<()> {
+        if !self.is_premium.to_inner() {
+            self.premium_since = Some(Utc::now());
+            self.premium_expires_at = None;
+            self.events.push(DomainEvent::UserUpgraded {
+                user_id: self.id.clone(),
+            });
+        }
         self.is_premium = UserIsPremium::new(true);
 
         Ok(())
     }
 
     pub fn downgrade(&mut self) -> Result<()> {
+        if self.is_premium.to_inner() {
+            self.premium_expires_at = Some(Utc::now());
+            self.events.push(DomainEvent::UserDowngraded {
+                user_id: self.id.clone(),
+            });
+        }
         self.is_premium = UserIsPremium::new(false);
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::{UserId, UserIsPremium, UserName};
+    use super::User;
+
+    #[test]
+    fn upgrade_records_premium_since_and_downgrade_its_expiry() {
+        let mut user = User::new(
+            UserId::new("user").unwrap(),
+            UserName::new("alice").unwrap(),
+            UserIsPremium::new(false),
+        )
+        .unwrap();
+        assert!(user.get_premium_since().is_none());
+
+        user.upgrade().unwrap();
+        let since = *user.get_premium_since().unwrap();
+        assert!(user.get_is_premium().to_inner());
+
+        // Upgrading a premium user keeps the original date.
+        user.upgrade().unwrap();
+        assert_eq!(user.get_premium_since(), Some(&since));
+
+        user.downgrade().unwrap();
+        assert!(!user.get_is_premium().to_inner());
+        assert_eq!(user.get_premium_since(), Some(&since));
+        let expires_at = *user.get_premium_expires_at().unwrap();
+        assert!(expires_at >= since);
+
+        // Downgrading a regular user keeps the original expiry.
+        user.downgrade().unwrap();
+        assert_eq!(user.get_premium_expires_at(), Some(&expires_at));
+
+        user.upgrade().unwrap();
+        assert!(user.get_premium_expires_at().is_none());
+    }
+}
@@ -3,9 +3,11 @@ use std::fmt::Display;
 use anyhow::Result;
 use validator::Validate;
 
+use crate::domain::model::error::{DomainError, FieldError};
+
 #[derive(Debug, Clone, Validate, PartialEq, Eq)]
 pub struct UserId {
-    #[validate(length(min = 1))]
+    #[validate(length(min = 1, message = "A user id must not be empty"))]
     value: String,
 }
 
@@ -14,7 +16,8 @@ impl UserId {
         let data = Self {
             value: value.to_string(),
         };
-        data.validate()?;
+        data.validate()
+            .map_err(|e| DomainError::Validation(FieldError::from_validation("user_id", &e)))?;
         Ok(data)
     }
 }
@@ -1,3 +1,5 @@
+mod user_list_query;
 mod user_repository_trait;
 
-pub use user_repository_trait::*;
\ No newline at end of file
+pub use user_list_query::*;
+pub use user_repository_trait::*;
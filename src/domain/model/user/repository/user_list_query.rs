@@ -0,0 +1,31 @@
+use crate::domain::model::user::entity::{UserId, UserName};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserSortKey {
+    Id,
+    Name,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// The position of the last user of the previous page. The next page starts
+/// right after it in the requested order.
+#[derive(Debug, Clone)]
+pub struct UserListCursor {
+    pub id: UserId,
+    pub name: UserName,
+}
+
+#[derive(Debug, Clone)]
+pub struct UserListQuery {
+    pub is_premium: Option<bool>,
+    pub name_prefix: Option<String>,
+    pub sort_key: UserSortKey,
+    pub sort_order: SortOrder,
+    pub after: Option<UserListCursor>,
+    pub limit: usize,
+}
@@ -1,13 +1,32 @@
-use crate::domain::model::user::entity::{User, UserId, UserName};
+use crate::domain::model::{
+    audit::entity::AuditEntry,
+    user::{
+        entity::{User, UserId, UserName},
+        repository::UserListQuery,
+    },
+};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait UserRepositoryTrait {
-    async fn save(&self, user: &User) -> Result<()>;
+    /// Saves the user along with the events it recorded, then clears them.
+    async fn save(&self, user: &mut User) -> Result<()>;
+    /// Saves the user and records `entry` in the audit log in the same
+    /// transaction, so that the change is never saved without its entry.
+    async fn save_audited(&self, user: &mut User, entry: &AuditEntry) -> Result<()>;
     async fn find_by_name(&self, user_name: &UserName) -> Result<Option<User>>;
     async fn find_by_id(&self, id: &UserId) -> Result<Option<User>>;
-    async fn delete(&self, id: &UserId) -> Result<()>;
+    /// Finds a user who was deleted but not purged yet. Every other lookup
+    /// leaves deleted users out.
+    async fn find_deleted_by_id(&self, id: &UserId) -> Result<Option<User>>;
+    /// The ids of the users deleted before `deleted_before`, longest deleted
+    /// first.
+    async fn find_deleted_before(&self, deleted_before: DateTime<Utc>) -> Result<Vec<UserId>>;
+    /// Removes a deleted user for good. Users who are not deleted are kept.
+    async fn purge(&self, id: &UserId) -> Result<()>;
     async fn batch_find(&self, users: Vec<UserId>) -> Result<Vec<User>>;
+    async fn find_page(&self, query: &UserListQuery) -> Result<Vec<User>>;
 }
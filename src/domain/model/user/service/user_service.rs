@@ -5,7 +5,7 @@ pub struct UserService<'a> {
 }
 
 impl UserService<'_> {
-    pub fn new(repository: &dyn UserRepositoryTrait) -> UserService {
+    pub fn new(repository: &dyn UserRepositoryTrait) -> UserService<'_> {
         UserService { repository }
     }
 
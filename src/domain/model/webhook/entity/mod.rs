@@ -0,0 +1,13 @@
+mod webhook;
+mod webhook_delivery;
+mod webhook_events;
+mod webhook_id;
+mod webhook_secret;
+mod webhook_url;
+
+pub use webhook::Webhook;
+pub use webhook_delivery::{DeliveryStatus, WebhookDelivery};
+pub use webhook_events::WebhookEvents;
+pub use webhook_id::WebhookId;
+pub use webhook_secret::WebhookSecret;
+pub use webhook_url::{is_public_address, WebhookUrl};
@@ -0,0 +1,117 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use super::{WebhookEvents, WebhookId, WebhookSecret, WebhookUrl};
+
+/// A subscription of another service to our domain events.
+#[derive(Debug, Clone, Validate)]
+pub struct Webhook {
+    #[validate]
+    id: WebhookId,
+    #[validate]
+    url: WebhookUrl,
+    events: WebhookEvents,
+    #[validate]
+    secret: WebhookSecret,
+    created_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    pub fn new(
+        id: WebhookId,
+        url: WebhookUrl,
+        events: WebhookEvents,
+        secret: WebhookSecret,
+    ) -> Result<Self> {
+        Self::restore(id, url, events, secret, Utc::now())
+    }
+
+    /// Rebuilds a webhook that already exists, e.g. when loading it from a database.
+    pub fn restore(
+        id: WebhookId,
+        url: WebhookUrl,
+        events: WebhookEvents,
+        secret: WebhookSecret,
+        created_at: DateTime<Utc>,
+    ) -> Result<Self> {
+        let data = Self {
+            id,
+            url,
+            events,
+            secret,
+            created_at,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    pub fn get_id(&self) -> &WebhookId {
+        &self.id
+    }
+
+    pub fn get_url(&self) -> &WebhookUrl {
+        &self.url
+    }
+
+    pub fn get_events(&self) -> &WebhookEvents {
+        &self.events
+    }
+
+    pub fn get_secret(&self) -> &WebhookSecret {
+        &self.secret
+    }
+
+    pub fn get_created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Whether events of this kind are delivered to the webhook.
+    pub fn matches(&self, event_type: &str) -> bool {
+        self.events.matches(event_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{WebhookEvents, WebhookId, WebhookSecret, WebhookUrl};
+    use super::Webhook;
+
+    #[test]
+    fn matches_only_the_subscribed_events() {
+        let events = ["MemberJoined".to_string(), "UserUpgraded".to_string()];
+        let webhook = Webhook::new(
+            WebhookId::new("webhook").unwrap(),
+            WebhookUrl::new("https://example.com/hook").unwrap(),
+            WebhookEvents::new(&events).unwrap(),
+            WebhookSecret::new("0123456789abcdef").unwrap(),
+        )
+        .unwrap();
+
+        assert!(webhook.matches("MemberJoined"));
+        assert!(!webhook.matches("MemberLeft"));
+        assert!(WebhookEvents::new(&[]).unwrap().matches("MemberLeft"));
+        assert!(WebhookEvents::new(&["MemberQuit".to_string()]).is_err());
+        assert!(WebhookUrl::new("ftp://example.com").is_err());
+    }
+
+    #[test]
+    fn rejects_urls_of_private_hosts() {
+        for url in [
+            "http://localhost:9000/hook",
+            "http://127.0.0.1/hook",
+            "http://10.1.2.3/hook",
+            "http://192.168.0.10/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/hook",
+            "http://[::ffff:127.0.0.1]/hook",
+            "http://[fd00::1]/hook",
+        ] {
+            let url = WebhookUrl::new(url).unwrap();
+            assert!(url.ensure_public_host().is_err(), "{}", url);
+        }
+        for url in ["https://example.com/hook", "http://93.184.216.34/hook"] {
+            assert!(WebhookUrl::new(url).unwrap().ensure_public_host().is_ok());
+        }
+    }
+}
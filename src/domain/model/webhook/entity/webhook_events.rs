@@ -0,0 +1,42 @@
+use anyhow::Result;
+
+use crate::domain::model::{error::DomainError, event::DomainEvent};
+
+/// The kinds of events a webhook is subscribed to. An empty filter subscribes
+/// to every event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WebhookEvents {
+    values: Vec<String>,
+}
+
+impl WebhookEvents {
+    pub fn new(values: &[String]) -> Result<Self> {
+        let mut events = Vec::new();
+        for value in values {
+            if !DomainEvent::NAMES.contains(&value.as_str()) {
+                return Err(DomainError::invalid_field(
+                    "events",
+                    "one_of",
+                    &format!(
+                        "Unknown event `{}`. Expected one of {}",
+                        value,
+                        DomainEvent::NAMES.join(", ")
+                    ),
+                )
+                .into());
+            }
+            if !events.contains(value) {
+                events.push(value.to_string());
+            }
+        }
+        Ok(Self { values: events })
+    }
+
+    pub fn matches(&self, event_type: &str) -> bool {
+        self.values.is_empty() || self.values.iter().any(|e| e == event_type)
+    }
+
+    pub fn to_vec(&self) -> Vec<String> {
+        self.values.clone()
+    }
+}
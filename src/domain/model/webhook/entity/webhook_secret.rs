@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use validator::Validate;
+
+use crate::domain::model::error::{DomainError, FieldError};
+
+/// The key deliveries are signed with. Receivers use it to check that a
+/// request really comes from us.
+#[derive(Debug, Clone, Validate, PartialEq, Eq)]
+pub struct WebhookSecret {
+    #[validate(length(min = 16))]
+    value: String,
+}
+
+impl WebhookSecret {
+    pub fn new(value: &str) -> Result<Self> {
+        let data = Self {
+            value: value.to_string(),
+        };
+        data.validate()
+            .map_err(|e| DomainError::Validation(FieldError::from_validation("secret", &e)))?;
+        Ok(data)
+    }
+}
+
+impl Display for WebhookSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
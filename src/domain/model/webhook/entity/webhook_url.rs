@@ -0,0 +1,99 @@
+use std::fmt::Display;
+use std::net::IpAddr;
+
+use anyhow::Result;
+use url::{Host, Url};
+use validator::Validate;
+
+use crate::domain::model::error::{DomainError, FieldError};
+
+#[derive(Debug, Clone, Validate, PartialEq, Eq)]
+pub struct WebhookUrl {
+    #[validate(url)]
+    value: String,
+}
+
+impl WebhookUrl {
+    pub fn new(value: &str) -> Result<Self> {
+        let data = Self {
+            value: value.to_string(),
+        };
+        data.validate()
+            .map_err(|e| DomainError::Validation(FieldError::from_validation("url", &e)))?;
+        if !value.starts_with("http://") && !value.starts_with("https://") {
+            return Err(DomainError::invalid_field(
+                "url",
+                "scheme",
+                &format!(
+                    "The webhook URL `{}` must start with http:// or https://",
+                    value
+                ),
+            )
+            .into());
+        }
+        Ok(data)
+    }
+
+    /// Rejects a URL that names the server itself or its private network,
+    /// e.g. `localhost`, `10.0.0.1` or the cloud metadata address
+    /// `169.254.169.254`. Host names are checked again when a delivery is
+    /// sent, against the addresses they resolve to.
+    pub fn ensure_public_host(&self) -> Result<()> {
+        let url = Url::parse(&self.value)
+            .map_err(|e| DomainError::invalid_field("url", "url", &e.to_string()))?;
+        let public = match url.host() {
+            Some(Host::Domain(domain)) => {
+                let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+                domain != "localhost" && !domain.ends_with(".localhost")
+            }
+            Some(Host::Ipv4(address)) => is_public_address(&IpAddr::V4(address)),
+            Some(Host::Ipv6(address)) => is_public_address(&IpAddr::V6(address)),
+            None => false,
+        };
+        if !public {
+            return Err(DomainError::invalid_field(
+                "url",
+                "public_host",
+                &format!(
+                "The webhook URL `{}` must not point to a loopback, link-local or private address",
+                self.value
+            ),
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Whether an address may be reached from the public internet: not loopback,
+/// link-local, private, shared (`100.64.0.0/10`) or unspecified.
+pub fn is_public_address(address: &IpAddr) -> bool {
+    match address {
+        IpAddr::V4(address) => {
+            let [first, second, ..] = address.octets();
+            !(address.is_loopback()
+                || address.is_private()
+                || address.is_link_local()
+                || address.is_unspecified()
+                || address.is_broadcast()
+                || first == 0
+                || (first == 100 && second & 0xc0 == 64))
+        }
+        IpAddr::V6(address) => match address.to_ipv4_mapped() {
+            Some(address) => is_public_address(&IpAddr::V4(address)),
+            None => {
+                let first = address.segments()[0];
+                !(address.is_loopback()
+                    || address.is_unspecified()
+                    || first & 0xfe00 == 0xfc00
+                    || first & 0xffc0 == 0xfe80)
+            }
+        },
+    }
+}
+
+impl Display for WebhookUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
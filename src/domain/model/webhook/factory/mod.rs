@@ -0,0 +1,4 @@
+mod webhook_factory;
+mod webhook_factory_trait;
+
+pub use self::{webhook_factory::WebhookFactory, webhook_factory_trait::*};
@@ -0,0 +1,42 @@
+use rand::{distributions::Alphanumeric, Rng};
+
+use super::WebhookFactoryTrait;
+use crate::domain::model::webhook::entity::{
+    Webhook, WebhookEvents, WebhookId, WebhookSecret, WebhookUrl,
+};
+
+use anyhow::Result;
+
+const SECRET_LENGTH: usize = 32;
+
+pub struct WebhookFactory {}
+
+impl WebhookFactory {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl WebhookFactoryTrait for WebhookFactory {
+    fn create(
+        &self,
+        url: WebhookUrl,
+        events: WebhookEvents,
+        secret: Option<WebhookSecret>,
+    ) -> Result<Webhook> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let id = WebhookId::new(&id)?;
+        let secret = match secret {
+            Some(secret) => secret,
+            None => {
+                let value = rand::thread_rng()
+                    .sample_iter(&Alphanumeric)
+                    .take(SECRET_LENGTH)
+                    .map(char::from)
+                    .collect::<String>();
+                WebhookSecret::new(&value)?
+            }
+        };
+        Webhook::new(id, url, events, secret)
+    }
+}
@@ -0,0 +1,12 @@
+use super::super::entity::{Webhook, WebhookEvents, WebhookSecret, WebhookUrl};
+use anyhow::Result;
+
+pub trait WebhookFactoryTrait {
+    /// Creates a webhook with a new id, and a random secret unless one is given.
+    fn create(
+        &self,
+        url: WebhookUrl,
+        events: WebhookEvents,
+        secret: Option<WebhookSecret>,
+    ) -> Result<Webhook>;
+}
@@ -0,0 +1,3 @@
+mod webhook_repository_trait;
+
+pub use self::webhook_repository_trait::*;
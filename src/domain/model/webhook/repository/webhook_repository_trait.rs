@@ -0,0 +1,15 @@
+use crate::domain::model::webhook::entity::{Webhook, WebhookDelivery, WebhookId};
+use anyhow::Result;
+
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait WebhookRepositoryTrait {
+    async fn save(&self, webhook: &Webhook) -> Result<()>;
+    async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>>;
+    async fn find_all(&self) -> Result<Vec<Webhook>>;
+    /// Deletes the webhook together with its delivery log.
+    async fn delete(&self, id: &WebhookId) -> Result<()>;
+    /// The latest deliveries to the webhook, newest first.
+    async fn find_deliveries(&self, id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>>;
+}
@@ -0,0 +1,3 @@
+mod token_authenticator;
+
+pub use self::token_authenticator::*;
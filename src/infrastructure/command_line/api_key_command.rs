@@ -0,0 +1,86 @@
+use anyhow::Result;
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::output::Output;
+use crate::application::api_key::ApiKeyData;
+use crate::infrastructure::{config::Config, database::shared::DatabaseConnection};
+use crate::interface::controller::api_key_controller::{
+    ApiKeyController, PostApiKeyArgs, RevokeApiKeyArgs,
+};
+
+#[derive(Subcommand, Debug)]
+pub enum ApiKeyCommand {
+    /// Create an API key and print it (it is not shown again)
+    Create {
+        name: String,
+        /// Permission of the key: users:write, premium:write or clubs:write (repeatable)
+        #[clap(long = "scope", required = true)]
+        scopes: Vec<String>,
+    },
+    /// List the API keys
+    List,
+    /// Revoke an API key for good
+    Revoke { id: String },
+}
+
+#[derive(Serialize)]
+struct ApiKeyRow {
+    id: String,
+    name: String,
+    prefix: String,
+    scopes: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key: Option<String>,
+    created_at: String,
+    last_used_at: Option<String>,
+    revoked_at: Option<String>,
+}
+
+impl From<ApiKeyData> for ApiKeyRow {
+    fn from(api_key: ApiKeyData) -> Self {
+        Self {
+            id: api_key.id,
+            name: api_key.name,
+            prefix: api_key.prefix,
+            scopes: api_key.scopes.join(","),
+            key: api_key.key,
+            created_at: api_key.created_at.to_rfc3339(),
+            last_used_at: api_key.last_used_at.map(|t| t.to_rfc3339()),
+            revoked_at: api_key.revoked_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+impl ApiKeyCommand {
+    pub async fn run(&self, config: &Config) -> Result<Output> {
+        let connection = DatabaseConnection::connect(&config.database).await?;
+        let controller = ApiKeyController::new(&connection).await?;
+
+        match self {
+            Self::Create { name, scopes } => {
+                let args = PostApiKeyArgs {
+                    name: name.clone(),
+                    scopes: scopes.clone(),
+                };
+                let api_key = controller.post_api_key(args).await?;
+                Output::record(&ApiKeyRow::from(api_key))
+            }
+            Self::List => {
+                let rows = controller
+                    .list_api_keys()
+                    .await?
+                    .into_iter()
+                    .map(ApiKeyRow::from)
+                    .collect::<Vec<_>>();
+                Output::rows(&rows)
+            }
+            Self::Revoke { id } => {
+                controller
+                    .revoke_api_key(RevokeApiKeyArgs { id: id.clone() })
+                    .await?;
+                Ok(Output::message("API key revoked."))
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::Result;
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::{cli_actor, output::Output};
+use crate::application::audit::AuditEntryData;
+use crate::infrastructure::{config::Config, database::shared::DatabaseConnection};
+use crate::interface::controller::audit_controller::{AuditController, GetAuditArgs};
+
+#[derive(Subcommand, Debug)]
+pub enum AuditCommand {
+    /// Show the latest changes, newest first
+    List {
+        /// Only show changes to the user or club with this id
+        #[clap(long)]
+        target: Option<String>,
+        /// Maximum number of entries to list
+        #[clap(long)]
+        limit: Option<usize>,
+    },
+}
+
+#[derive(Serialize)]
+struct AuditRow {
+    occurred_at: String,
+    actor: String,
+    action: String,
+    target_type: String,
+    target_id: String,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+impl From<AuditEntryData> for AuditRow {
+    fn from(entry: AuditEntryData) -> Self {
+        Self {
+            occurred_at: entry.occurred_at.to_rfc3339(),
+            actor: entry.actor,
+            action: entry.action,
+            target_type: entry.target_type,
+            target_id: entry.target_id,
+            before: entry.before.map(|v| v.to_string()),
+            after: entry.after.map(|v| v.to_string()),
+        }
+    }
+}
+
+impl AuditCommand {
+    pub async fn run(&self, config: &Config) -> Result<Output> {
+        let connection = DatabaseConnection::connect(&config.database).await?;
+        let controller = AuditController::new(&connection).await?;
+
+        match self {
+            Self::List { target, limit } => {
+                let args = GetAuditArgs {
+                    actor: cli_actor(),
+                    target: target.clone(),
+                    limit: *limit,
+                };
+                let rows = controller
+                    .get_audit(args)
+                    .await?
+                    .into_iter()
+                    .map(AuditRow::from)
+                    .collect::<Vec<_>>();
+                Output::rows(&rows)
+            }
+        }
+    }
+}
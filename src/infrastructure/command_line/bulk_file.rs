@@ -0,0 +1,38 @@
+use std::io::Read;
+
+use anyhow::{Context, Result};
+
+use super::output::Output;
+use crate::interface::controller::bulk_format::BulkFormat;
+
+/// Picks the format given with `--data-format`, falling back to the file
+/// extension and then to JSON lines.
+pub fn data_format(explicit: Option<&str>, path: Option<&str>) -> Result<BulkFormat> {
+    match explicit {
+        Some(format) => BulkFormat::parse(format),
+        None => Ok(path
+            .and_then(BulkFormat::from_path)
+            .unwrap_or(BulkFormat::JsonLines)),
+    }
+}
+
+/// Reads `path`, or stdin when it is `-`.
+pub fn read_input(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut data = String::new();
+        std::io::stdin().read_to_string(&mut data)?;
+        return Ok(data);
+    }
+    std::fs::read_to_string(path).with_context(|| format!("Could not read {}", path))
+}
+
+/// Writes exported data to `path`, or hands it back to be printed as is.
+pub fn write_output(data: String, path: Option<&str>, what: &str) -> Result<Output> {
+    match path {
+        Some(path) => {
+            std::fs::write(path, data).with_context(|| format!("Could not write {}", path))?;
+            Ok(Output::Message(format!("{} exported to {}.", what, path)))
+        }
+        None => Ok(Output::Raw(data)),
+    }
+}
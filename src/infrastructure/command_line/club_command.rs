@@ -0,0 +1,186 @@
+use anyhow::Result;
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::{bulk_file, cli_actor, output::Output};
+use crate::application::club::ClubStatisticsData;
+use crate::domain::model::error::DomainError;
+use crate::infrastructure::{config::Config, database::shared::DatabaseConnection};
+use crate::interface::controller::club_controller::{
+    ClubController, DeleteMemberArgs, ExportClubsArgs, GetClubArgs, GetRecommendationArgs,
+    PostClubArgs, PostMemberArgs, PutOwnerArgs, RebuildStatisticsArgs,
+};
+
+#[derive(Subcommand, Debug)]
+pub enum ClubCommand {
+    /// Create a club owned by an existing user
+    Create {
+        name: String,
+        /// Id of the user who owns the club
+        #[clap(long)]
+        owner: String,
+    },
+    /// Add a user to a club
+    Join { club_id: String, user_id: String },
+    /// Remove a member from a club
+    Leave { club_id: String, user_id: String },
+    /// Make a member the owner of a club; the previous owner stays a member
+    Transfer { club_id: String, user_id: String },
+    /// Show a club with its owner and members
+    Show { club_id: String },
+    /// List recommended clubs
+    Recommend {
+        /// Maximum number of clubs to list
+        #[clap(long)]
+        limit: Option<usize>,
+        /// members, -members, name or -name
+        #[clap(long)]
+        sort: Option<String>,
+    },
+    /// Inspect or rebuild the club statistics projection
+    Stats {
+        #[clap(subcommand)]
+        action: StatsAction,
+    },
+    /// Write every club with its members as CSV or JSON lines
+    Export {
+        /// csv or jsonl (defaults to the output file extension, then jsonl)
+        #[clap(long)]
+        data_format: Option<String>,
+        /// File to write instead of stdout
+        #[clap(long)]
+        output: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StatsAction {
+    /// List the member counts of every club
+    List,
+    /// Recount every club from scratch
+    Rebuild,
+}
+
+#[derive(Serialize)]
+struct StatisticsRow {
+    club_id: String,
+    club_name: String,
+    member_count: usize,
+    premium_member_count: usize,
+    last_joined_at: Option<String>,
+}
+
+impl From<ClubStatisticsData> for StatisticsRow {
+    fn from(statistics: ClubStatisticsData) -> Self {
+        Self {
+            club_id: statistics.club_id,
+            club_name: statistics.club_name,
+            member_count: statistics.member_count,
+            premium_member_count: statistics.premium_member_count,
+            last_joined_at: statistics.last_joined_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+impl ClubCommand {
+    pub async fn run(&self, config: &Config) -> Result<Output> {
+        let connection = DatabaseConnection::connect(&config.database).await?;
+        let controller = ClubController::new(&connection).await?;
+
+        match self {
+            Self::Create { name, owner } => {
+                let args = PostClubArgs {
+                    actor: cli_actor(),
+                    user_id: owner.clone(),
+                    name: name.clone(),
+                };
+                let club = controller.post_club(args).await?;
+                Output::record(&club)
+            }
+            Self::Join { club_id, user_id } => {
+                let args = PostMemberArgs {
+                    actor: cli_actor(),
+                    user_id: user_id.clone(),
+                    club_id: club_id.clone(),
+                };
+                controller.post_member(args).await?;
+                Ok(Output::message("User joined the club."))
+            }
+            Self::Leave { club_id, user_id } => {
+                let args = DeleteMemberArgs {
+                    actor: cli_actor(),
+                    user_id: user_id.clone(),
+                    club_id: club_id.clone(),
+                };
+                controller.delete_member(args).await?;
+                Ok(Output::message("User left the club."))
+            }
+            Self::Transfer { club_id, user_id } => {
+                let args = PutOwnerArgs {
+                    actor: cli_actor(),
+                    club_id: club_id.clone(),
+                    user_id: user_id.clone(),
+                };
+                controller.put_owner(args).await?;
+                Ok(Output::message("Ownership transferred."))
+            }
+            Self::Show { club_id } => {
+                let club = controller
+                    .get_club(GetClubArgs {
+                        id: club_id.clone(),
+                    })
+                    .await?
+                    .ok_or_else(|| DomainError::NotFound("Could not find club.".to_string()))?;
+                Output::record(&club)
+            }
+            Self::Recommend { limit, sort } => {
+                let args = GetRecommendationArgs {
+                    limit: *limit,
+                    sort: sort.clone(),
+                };
+                Output::rows(&controller.get_recommendation(args).await?)
+            }
+            Self::Stats {
+                action: StatsAction::List,
+            } => {
+                let rows = controller
+                    .list_statistics()
+                    .await?
+                    .into_iter()
+                    .map(StatisticsRow::from)
+                    .collect::<Vec<_>>();
+                Output::rows(&rows)
+            }
+            Self::Stats {
+                action: StatsAction::Rebuild,
+            } => {
+                // The outbox keeps every event, so it still knows when each
+                // club was last joined.
+                let last_joined_at = connection
+                    .outbox_database()?
+                    .last_occurred_at("MemberJoined")
+                    .await?;
+                let count = controller
+                    .rebuild_statistics(RebuildStatisticsArgs { last_joined_at })
+                    .await?;
+                Ok(Output::Message(format!(
+                    "Rebuilt the statistics of {} clubs.",
+                    count
+                )))
+            }
+            Self::Export {
+                data_format,
+                output,
+            } => {
+                let format = bulk_file::data_format(data_format.as_deref(), output.as_deref())?;
+                let data = controller
+                    .export_clubs(ExportClubsArgs {
+                        actor: cli_actor(),
+                        format,
+                    })
+                    .await?;
+                bulk_file::write_output(data, output.as_deref(), "Clubs")
+            }
+        }
+    }
+}
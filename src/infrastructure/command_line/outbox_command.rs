@@ -0,0 +1,62 @@
+use anyhow::Result;
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::output::Output;
+use crate::infrastructure::{
+    config::Config,
+    database::shared::DatabaseConnection,
+    event::{EventDispatcher, OutboxRelay},
+};
+
+#[derive(Subcommand, Debug)]
+pub enum OutboxCommand {
+    /// Count pending, retrying, dead and published events
+    Status,
+    /// Publish pending events to the configured sinks
+    Relay {
+        /// Publish what is due and exit instead of polling
+        #[clap(long)]
+        once: bool,
+    },
+}
+
+impl OutboxCommand {
+    pub async fn run(&self, config: &Config, events: &EventDispatcher) -> Result<Output> {
+        let connection = DatabaseConnection::connect(&config.database).await?;
+
+        match self {
+            Self::Status => {
+                #[derive(Serialize)]
+                struct OutboxStatus {
+                    pending: u64,
+                    retrying: u64,
+                    dead: u64,
+                    published: u64,
+                }
+
+                let outbox = connection.outbox_database()?;
+                let stats = outbox.stats(config.outbox.max_attempts).await?;
+                Output::record(&OutboxStatus {
+                    pending: stats.pending,
+                    retrying: stats.retrying,
+                    dead: stats.dead,
+                    published: stats.published,
+                })
+            }
+            Self::Relay { once: true } => {
+                let relay = OutboxRelay::from_config(&connection, &config.outbox, events).await?;
+                let report = relay.drain().await?;
+                Ok(Output::Message(format!(
+                    "Published {} events, {} failed.",
+                    report.published, report.failed
+                )))
+            }
+            Self::Relay { once: false } => {
+                let relay = OutboxRelay::from_config(&connection, &config.outbox, events).await?;
+                relay.run().await;
+                Ok(Output::message("Outbox relay stopped."))
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::io::Write;
+
+use crate::domain::model::error::DomainError;
+
+/// Exit codes scripts can rely on. Usage errors exit with 2, as reported by
+/// clap before any command runs.
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_INVALID: i32 = 3;
+pub const EXIT_NOT_FOUND: i32 = 4;
+pub const EXIT_CONFLICT: i32 = 5;
+pub const EXIT_UNAUTHORIZED: i32 = 6;
+pub const EXIT_FORBIDDEN: i32 = 7;
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Yaml,
+}
+
+/// What a command reports on success.
+pub enum Output {
+    /// A confirmation such as "User deleted."
+    Message(String),
+    /// A single object, shown as `key: value` lines in a table.
+    Record(Value),
+    /// A list of objects, shown with one column per field in a table.
+    Rows(Vec<Value>),
+    /// Data that already has a format of its own, such as an export. It is
+    /// printed unchanged whatever `--format` says.
+    Raw(String),
+}
+
+impl Output {
+    pub fn message(message: &str) -> Self {
+        Self::Message(message.to_string())
+    }
+
+    pub fn record<T: Serialize>(record: &T) -> Result<Self> {
+        Ok(Self::Record(serde_json::to_value(record)?))
+    }
+
+    pub fn rows<T: Serialize>(rows: &[T]) -> Result<Self> {
+        let rows = rows
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<serde_json::Result<Vec<Value>>>()?;
+        Ok(Self::Rows(rows))
+    }
+
+    pub fn print(&self, format: OutputFormat) -> Result<()> {
+        let text = match (self, format) {
+            (Self::Raw(data), _) => data.clone(),
+            (_, OutputFormat::Table) => self.to_table(),
+            (_, OutputFormat::Json) => {
+                format!("{}\n", serde_json::to_string_pretty(&self.to_value())?)
+            }
+            (_, OutputFormat::Yaml) => serde_yaml::to_string(&self.to_value())?,
+        };
+
+        // Write instead of print! so that a closed pipe is an error, not a panic.
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(text.as_bytes())?;
+        stdout.flush()?;
+        Ok(())
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Self::Message(message) => json!({ "message": message }),
+            Self::Record(record) => record.clone(),
+            Self::Rows(rows) => Value::Array(rows.clone()),
+            Self::Raw(data) => Value::String(data.clone()),
+        }
+    }
+
+    fn to_table(&self) -> String {
+        match self {
+            Self::Message(message) => format!("{}\n", message),
+            Self::Record(Value::Object(record)) => record_table(record),
+            Self::Record(value) => format!("{}\n", cell(value)),
+            Self::Rows(rows) => rows_table(rows, ""),
+            Self::Raw(data) => data.clone(),
+        }
+    }
+}
+
+/// Prints `error` to stderr in the requested format and returns the exit code
+/// for its kind.
+pub fn report_error(error: &anyhow::Error, format: OutputFormat) -> i32 {
+    let (kind, code) = match error.downcast_ref::<DomainError>() {
+        Some(DomainError::Validation(_)) => ("invalid", EXIT_INVALID),
+        Some(DomainError::NotFound(_)) => ("not_found", EXIT_NOT_FOUND),
+        Some(DomainError::Conflict(_)) => ("conflict", EXIT_CONFLICT),
+        Some(DomainError::Unauthorized(_)) => ("unauthorized", EXIT_UNAUTHORIZED),
+        Some(DomainError::Forbidden(_)) => ("forbidden", EXIT_FORBIDDEN),
+        None => ("failure", EXIT_FAILURE),
+    };
+    let mut report = json!({
+        "error": {
+            "kind": kind,
+            "message": format!("{:#}", error),
+        }
+    });
+    if let Some(DomainError::Validation(errors)) = error.downcast_ref::<DomainError>() {
+        let errors: Vec<Value> = errors
+            .iter()
+            .map(|e| json!({ "field": e.field, "rule": e.rule, "message": e.message }))
+            .collect();
+        report["error"]["errors"] = Value::Array(errors);
+    }
+
+    match format {
+        OutputFormat::Table => eprintln!("Error: {:#}", error),
+        OutputFormat::Json => eprintln!("{}", report),
+        OutputFormat::Yaml => eprint!("{}", serde_yaml::to_string(&report).unwrap_or_default()),
+    }
+
+    code
+}
+
+fn label(key: &str) -> String {
+    key.replace('_', " ")
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter().map(cell).collect::<Vec<String>>().join(", "),
+        Value::Object(fields) => fields.values().map(cell).collect::<Vec<String>>().join(" "),
+        other => other.to_string(),
+    }
+}
+
+/// Scalars become aligned `key: value` lines. Nested objects are flattened
+/// into prefixed keys and lists of objects are shown as an indented table.
+fn record_table(record: &Map<String, Value>) -> String {
+    let mut lines = Vec::new();
+    flatten(record, "", &mut lines);
+
+    let width = lines.iter().map(|(key, _)| key.len()).max().unwrap_or(0) + 1;
+    let mut out = String::new();
+    for (key, value) in lines {
+        let key = format!("{}:", key);
+        match value {
+            Value::Array(rows) if rows.iter().any(Value::is_object) => {
+                out.push_str(&format!("{:<width$} {}\n", key, rows.len()));
+                out.push_str(&rows_table(&rows, "  "));
+            }
+            value => out.push_str(&format!("{:<width$} {}\n", key, cell(&value))),
+        }
+    }
+    out
+}
+
+fn flatten(record: &Map<String, Value>, prefix: &str, lines: &mut Vec<(String, Value)>) {
+    for (key, value) in record {
+        let key = format!("{}{}", prefix, label(key));
+        match value {
+            Value::Object(fields) => flatten(fields, &format!("{} ", key), lines),
+            value => lines.push((key, value.clone())),
+        }
+    }
+}
+
+fn rows_table(rows: &[Value], indent: &str) -> String {
+    let columns = match rows.first() {
+        Some(Value::Object(first)) => first.keys().cloned().collect::<Vec<String>>(),
+        Some(_) => {
+            return rows
+                .iter()
+                .map(|v| format!("{}{}\n", indent, cell(v)))
+                .collect()
+        }
+        None => return String::new(),
+    };
+
+    let cells = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| cell(row.get(column).unwrap_or(&Value::Null)))
+                .collect::<Vec<String>>()
+        })
+        .collect::<Vec<Vec<String>>>();
+    let header = columns
+        .iter()
+        .map(|column| label(column).to_uppercase())
+        .collect::<Vec<String>>();
+    let widths = (0..columns.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .chain(std::iter::once(header[i].len()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<usize>>();
+
+    std::iter::once(&header)
+        .chain(cells.iter())
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(value, width)| format!("{:<width$}", value, width = width))
+                .collect::<Vec<String>>()
+                .join("  ");
+            format!("{}{}\n", indent, line.trim_end())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn table_flattens_records_and_aligns_rows() {
+        let record = Output::Record(json!({
+            "id": "c1",
+            "owner": { "id": "u1", "name": "alice" },
+            "members": [
+                { "id": "u2", "name": "bob" },
+                { "id": "u3", "name": "carol" },
+            ],
+        }));
+
+        assert_eq!(
+            record.to_table(),
+            "id:         c1\n\
+             owner id:   u1\n\
+             owner name: alice\n\
+             members:    2\n  \
+               ID  NAME\n  \
+               u2  bob\n  \
+               u3  carol\n"
+        );
+    }
+}
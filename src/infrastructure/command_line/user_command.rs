@@ -0,0 +1,179 @@
+use anyhow::{anyhow, Result};
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::{bulk_file, cli_actor, output::Output};
+use crate::domain::model::{error::DomainError, user::entity::UserId};
+use crate::infrastructure::{
+    auth::TokenAuthenticator, config::Config, database::shared::DatabaseConnection,
+};
+use crate::interface::controller::user_controller::{
+    DeleteArgs, DeletePremiumArgs, ExportArgs, GetArgs, ImportArgs, PostArgs, PostPremiumArgs,
+    PutArgs, RestoreArgs, UserController,
+};
+
+#[derive(Subcommand, Debug)]
+pub enum UserCommand {
+    /// Register a new user
+    Create { name: String },
+    /// Show a user's profile
+    Get { id: String },
+    /// Rename a user
+    Update { id: String, name: String },
+    /// Delete a user (they can be restored until the retention window ends)
+    Delete { id: String },
+    /// Bring back a deleted user
+    Restore { id: String },
+    /// Remove the users deleted longer than the retention window ago for good
+    Purge,
+    /// Make a user a premium member
+    Upgrade { id: String },
+    /// Turn a premium member back into a regular user
+    Downgrade { id: String },
+    /// Issue an access token that lets the user call the web server
+    Token { id: String },
+    /// Register users from a CSV or JSON lines file with a `name` and an
+    /// optional `is_premium` column
+    Import {
+        /// File to read, or - for stdin
+        file: String,
+        /// csv or jsonl (defaults to the file extension)
+        #[clap(long)]
+        data_format: Option<String>,
+        /// Validate every row without saving anything
+        #[clap(long)]
+        dry_run: bool,
+    },
+    /// Write every user as CSV or JSON lines
+    Export {
+        /// csv or jsonl (defaults to the output file extension, then jsonl)
+        #[clap(long)]
+        data_format: Option<String>,
+        /// File to write instead of stdout
+        #[clap(long)]
+        output: Option<String>,
+    },
+}
+
+#[derive(Serialize)]
+struct TokenRow {
+    user_id: String,
+    token: String,
+    expires_at: String,
+}
+
+impl UserCommand {
+    pub async fn run(&self, config: &Config) -> Result<Output> {
+        let connection = DatabaseConnection::connect(&config.database).await?;
+        let controller = UserController::new(&connection).await?;
+
+        match self {
+            Self::Create { name } => {
+                let user = controller
+                    .post(PostArgs {
+                        actor: cli_actor(),
+                        name: name.clone(),
+                    })
+                    .await?;
+                Output::record(&user)
+            }
+            Self::Get { id } => {
+                let user = controller
+                    .get(GetArgs { id: id.clone() })
+                    .await?
+                    .ok_or_else(|| DomainError::NotFound("Could not find user.".to_string()))?;
+                Output::record(&user)
+            }
+            Self::Update { id, name } => {
+                let args = PutArgs {
+                    actor: cli_actor(),
+                    id: id.clone(),
+                    name: name.clone(),
+                };
+                controller.put(args).await?;
+                Ok(Output::message("User updated."))
+            }
+            Self::Delete { id } => {
+                controller
+                    .delete(DeleteArgs {
+                        actor: cli_actor(),
+                        id: id.clone(),
+                    })
+                    .await?;
+                Ok(Output::message("User deleted."))
+            }
+            Self::Restore { id } => {
+                controller
+                    .restore(RestoreArgs {
+                        actor: cli_actor(),
+                        id: id.clone(),
+                    })
+                    .await?;
+                Ok(Output::message("User restored."))
+            }
+            Self::Purge => {
+                let ids = controller.purge().await?;
+                Ok(Output::Message(format!(
+                    "Purged {} deleted users.",
+                    ids.len()
+                )))
+            }
+            Self::Upgrade { id } => {
+                let args = PostPremiumArgs {
+                    actor: cli_actor(),
+                    id: id.clone(),
+                };
+                controller.post_premium(args).await?;
+                Ok(Output::message("User upgraded to premium."))
+            }
+            Self::Downgrade { id } => {
+                let args = DeletePremiumArgs {
+                    actor: cli_actor(),
+                    id: id.clone(),
+                };
+                controller.delete_premium(args).await?;
+                Ok(Output::message("User downgraded."))
+            }
+            Self::Token { id } => {
+                let authenticator = TokenAuthenticator::from_config(&config.auth)?
+                    .ok_or_else(|| anyhow!("Set auth.secret or AUTH_SECRET to issue tokens."))?;
+                let user = controller
+                    .get(GetArgs { id: id.clone() })
+                    .await?
+                    .ok_or_else(|| DomainError::NotFound("Could not find user.".to_string()))?;
+                let issued = authenticator.issue(&UserId::new(&user.id)?)?;
+                Output::record(&TokenRow {
+                    user_id: user.id,
+                    token: issued.token,
+                    expires_at: issued.expires_at.to_rfc3339(),
+                })
+            }
+            Self::Import {
+                file,
+                data_format,
+                dry_run,
+            } => {
+                let args = ImportArgs {
+                    actor: cli_actor(),
+                    format: bulk_file::data_format(data_format.as_deref(), Some(file))?,
+                    data: bulk_file::read_input(file)?,
+                    dry_run: *dry_run,
+                };
+                Output::record(&controller.import(args).await?)
+            }
+            Self::Export {
+                data_format,
+                output,
+            } => {
+                let format = bulk_file::data_format(data_format.as_deref(), output.as_deref())?;
+                let data = controller
+                    .export(ExportArgs {
+                        actor: cli_actor(),
+                        format,
+                    })
+                    .await?;
+                bulk_file::write_output(data, output.as_deref(), "Users")
+            }
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::Result;
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::{cli_actor, output::Output};
+use crate::application::webhook::{WebhookData, WebhookDeliveryData};
+use crate::domain::model::error::DomainError;
+use crate::infrastructure::{
+    config::Config, database::shared::DatabaseConnection, event::WebhookDeliveryWorker,
+    http::HttpClient,
+};
+use crate::interface::controller::webhook_controller::{
+    DeleteWebhookArgs, GetDeliveriesArgs, GetWebhookArgs, ListWebhooksArgs, PostWebhookArgs,
+    WebhookController,
+};
+
+#[derive(Subcommand, Debug)]
+pub enum WebhookCommand {
+    /// Register a webhook and print its signing secret
+    Create {
+        url: String,
+        /// Event type to send, e.g. UserRegistered (repeatable; all events when omitted)
+        #[clap(long = "event")]
+        events: Vec<String>,
+        /// Signing secret of at least 16 characters (generated when omitted)
+        #[clap(long)]
+        secret: Option<String>,
+    },
+    /// List the registered webhooks
+    List,
+    /// Show a registered webhook
+    Show { id: String },
+    /// Remove a webhook along with its delivery log
+    Delete { id: String },
+    /// Show the latest deliveries to a webhook
+    Deliveries {
+        id: String,
+        /// Maximum number of deliveries to list
+        #[clap(long)]
+        limit: Option<usize>,
+    },
+    /// Send the queued deliveries to the webhooks
+    Deliver {
+        /// Send what is due and exit instead of polling
+        #[clap(long)]
+        once: bool,
+    },
+}
+
+#[derive(Serialize)]
+struct WebhookRow {
+    id: String,
+    url: String,
+    events: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret: Option<String>,
+    created_at: String,
+}
+
+impl WebhookRow {
+    fn new(webhook: WebhookData, with_secret: bool) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url,
+            events: if webhook.events.is_empty() {
+                "*".to_string()
+            } else {
+                webhook.events.join(",")
+            },
+            secret: with_secret.then_some(webhook.secret),
+            created_at: webhook.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DeliveryRow {
+    id: String,
+    event_type: String,
+    status: &'static str,
+    attempts: u32,
+    response_status: Option<u16>,
+    last_error: Option<String>,
+    created_at: String,
+}
+
+impl From<WebhookDeliveryData> for DeliveryRow {
+    fn from(delivery: WebhookDeliveryData) -> Self {
+        Self {
+            id: delivery.id,
+            event_type: delivery.event_type,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at.to_rfc3339(),
+        }
+    }
+}
+
+impl WebhookCommand {
+    pub async fn run(&self, config: &Config) -> Result<Output> {
+        let connection = DatabaseConnection::connect(&config.database).await?;
+        let controller = WebhookController::new(&connection, &config.webhook).await?;
+
+        match self {
+            Self::Create {
+                url,
+                events,
+                secret,
+            } => {
+                let args = PostWebhookArgs {
+                    actor: cli_actor(),
+                    url: url.clone(),
+                    events: events.clone(),
+                    secret: secret.clone(),
+                };
+                let webhook = controller.post_webhook(args).await?;
+                Output::record(&WebhookRow::new(webhook, true))
+            }
+            Self::List => {
+                let rows = controller
+                    .list_webhooks(ListWebhooksArgs { actor: cli_actor() })
+                    .await?
+                    .into_iter()
+                    .map(|w| WebhookRow::new(w, false))
+                    .collect::<Vec<_>>();
+                Output::rows(&rows)
+            }
+            Self::Show { id } => {
+                let webhook = controller
+                    .get_webhook(GetWebhookArgs {
+                        actor: cli_actor(),
+                        id: id.clone(),
+                    })
+                    .await?
+                    .ok_or_else(|| {
+                        DomainError::NotFound("Could not find the webhook.".to_string())
+                    })?;
+                Output::record(&WebhookRow::new(webhook, false))
+            }
+            Self::Delete { id } => {
+                controller
+                    .delete_webhook(DeleteWebhookArgs {
+                        actor: cli_actor(),
+                        id: id.clone(),
+                    })
+                    .await?;
+                Ok(Output::message("Webhook deleted."))
+            }
+            Self::Deliveries { id, limit } => {
+                let args = GetDeliveriesArgs {
+                    actor: cli_actor(),
+                    id: id.clone(),
+                    limit: *limit,
+                };
+                let rows = controller
+                    .get_deliveries(args)
+                    .await?
+                    .into_iter()
+                    .map(DeliveryRow::from)
+                    .collect::<Vec<_>>();
+                Output::rows(&rows)
+            }
+            Self::Deliver { once } => {
+                let worker = WebhookDeliveryWorker::new(
+                    connection.webhook_database()?,
+                    HttpClient::new(config.webhook.timeout()),
+                    config.webhook.clone(),
+                );
+                if *once {
+                    let report = worker.drain().await?;
+                    Ok(Output::Message(format!(
+                        "Delivered {} events, {} failed.",
+                        report.delivered, report.failed
+                    )))
+                } else {
+                    worker.run().await;
+                    Ok(Output::message("Webhook delivery stopped."))
+                }
+            }
+        }
+    }
+}
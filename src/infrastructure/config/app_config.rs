@@ -0,0 +1,642 @@
+use std::{
+    collections::HashMap,
+    net::ToSocketAddrs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+
+const DEFAULT_CONFIG_FILE: &str = "ddd-in-rust.toml";
+const DEFAULT_SQLITE_URL: &str = "sqlite://ddd-in-rust.sqlite3";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseBackend {
+    Postgres,
+    Sqlite,
+}
+
+impl FromStr for DatabaseBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "postgres" => Ok(Self::Postgres),
+            "sqlite" => Ok(Self::Sqlite),
+            _ => Err(anyhow!(
+                "Unknown database backend `{}`. Expected `postgres` or `sqlite`.",
+                value
+            )),
+        }
+    }
+}
+
+/// How clubs are stored: as rows of their current state, or as streams of
+/// the events that changed them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClubStore {
+    State,
+    Events,
+}
+
+impl FromStr for ClubStore {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "state" => Ok(Self::State),
+            "events" => Ok(Self::Events),
+            _ => Err(anyhow!(
+                "Unknown club store `{}`. Expected `state` or `events`.",
+                value
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub bind_address: String,
+    pub workers: Option<usize>,
+    pub request_timeout_secs: u64,
+    /// How long the response to a request with an `Idempotency-Key` header is
+    /// kept to be sent again on retries.
+    pub idempotency_ttl_secs: u64,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: "127.0.0.1:8080".to_string(),
+            workers: None,
+            request_timeout_secs: 5,
+            idempotency_ttl_secs: 24 * 3600,
+        }
+    }
+}
+
+impl ServerConfig {
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_secs(self.request_timeout_secs)
+    }
+
+    pub fn idempotency_ttl(&self) -> Duration {
+        Duration::from_secs(self.idempotency_ttl_secs)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub backend: DatabaseBackend,
+    pub url: Option<String>,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub connect_timeout_secs: u64,
+    pub idle_timeout_secs: Option<u64>,
+    pub club_store: ClubStore,
+    pub club_snapshot_interval: u64,
+    /// How long deleted users are kept, and may be restored, before they are
+    /// purged.
+    pub user_retention_days: u64,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            backend: DatabaseBackend::Postgres,
+            url: None,
+            max_connections: 20,
+            min_connections: 0,
+            connect_timeout_secs: 30,
+            idle_timeout_secs: Some(600),
+            club_store: ClubStore::State,
+            club_snapshot_interval: 50,
+            user_retention_days: 30,
+        }
+    }
+}
+
+impl DatabaseConfig {
+    pub fn database_url(&self) -> String {
+        match (&self.url, self.backend) {
+            (Some(url), _) => url.to_string(),
+            (None, DatabaseBackend::Sqlite) => DEFAULT_SQLITE_URL.to_string(),
+            (None, DatabaseBackend::Postgres) => String::new(),
+        }
+    }
+
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_secs(self.connect_timeout_secs)
+    }
+
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        self.idle_timeout_secs.map(Duration::from_secs)
+    }
+
+    pub fn user_retention(&self) -> Duration {
+        Duration::from_secs(self.user_retention_days.saturating_mul(24 * 3600))
+    }
+}
+
+/// How the outbox relay publishes domain events. Events are always handed to
+/// in-process subscribers; `log` and `webhook_url` enable the other sinks.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutboxConfig {
+    pub poll_interval_ms: u64,
+    pub batch_size: usize,
+    pub lease_secs: u64,
+    pub max_attempts: u32,
+    pub retry_base_secs: u64,
+    pub retry_max_secs: u64,
+    pub log: bool,
+    pub webhook_url: Option<String>,
+    pub webhook_timeout_secs: u64,
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_ms: 1000,
+            batch_size: 100,
+            lease_secs: 60,
+            max_attempts: 10,
+            retry_base_secs: 1,
+            retry_max_secs: 300,
+            log: false,
+            webhook_url: None,
+            webhook_timeout_secs: 5,
+        }
+    }
+}
+
+impl OutboxConfig {
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    pub fn lease(&self) -> Duration {
+        Duration::from_secs(self.lease_secs)
+    }
+
+    /// The delay before the next attempt after `attempts` failed ones. It
+    /// doubles with every failure up to `retry_max_secs`.
+    pub fn retry_delay(&self, attempts: u32) -> Duration {
+        backoff(self.retry_base_secs, self.retry_max_secs, attempts)
+    }
+
+    pub fn webhook_timeout(&self) -> Duration {
+        Duration::from_secs(self.webhook_timeout_secs)
+    }
+}
+
+/// How the delivery worker sends events to the registered webhooks. A
+/// delivery that still fails after `max_attempts` is given up. Webhooks may
+/// only point to public hosts unless `allow_private_hosts` is set, e.g. to
+/// test against a receiver on localhost.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WebhookConfig {
+    pub poll_interval_ms: u64,
+    pub batch_size: usize,
+    pub lease_secs: u64,
+    pub max_attempts: u32,
+    pub retry_base_secs: u64,
+    pub retry_max_secs: u64,
+    pub timeout_secs: u64,
+    pub allow_private_hosts: bool,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_ms: 1000,
+            batch_size: 50,
+            lease_secs: 60,
+            max_attempts: 8,
+            retry_base_secs: 5,
+            retry_max_secs: 3600,
+            timeout_secs: 5,
+            allow_private_hosts: false,
+        }
+    }
+}
+
+impl WebhookConfig {
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    pub fn lease(&self) -> Duration {
+        Duration::from_secs(self.lease_secs)
+    }
+
+    /// The delay before the next attempt after `attempts` failed ones, as for
+    /// the outbox.
+    pub fn retry_delay(&self, attempts: u32) -> Duration {
+        backoff(self.retry_base_secs, self.retry_max_secs, attempts)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+}
+
+/// How callers authenticate to the web server. Access tokens are signed with
+/// `secret`; without one every request is anonymous.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    pub secret: Option<String>,
+    pub token_ttl_secs: u64,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            secret: None,
+            token_ttl_secs: 7 * 24 * 3600,
+        }
+    }
+}
+
+impl AuthConfig {
+    pub fn token_ttl(&self) -> Duration {
+        Duration::from_secs(self.token_ttl_secs)
+    }
+}
+
+/// How many requests the web server accepts before answering `429`. Each
+/// client IP and each signed-in user has a token bucket holding up to `burst`
+/// requests that refills at `per_minute`. Requests made with an API key are
+/// not limited.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    pub ip_burst: u32,
+    pub ip_per_minute: u32,
+    pub user_burst: u32,
+    pub user_per_minute: u32,
+    /// Take the client IP from the `Forwarded` or `X-Forwarded-For` header,
+    /// for a server behind a reverse proxy. Clients can forge those headers,
+    /// so this is only safe when the proxy overwrites them.
+    pub trust_forwarded: bool,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ip_burst: 30,
+            ip_per_minute: 60,
+            user_burst: 60,
+            user_per_minute: 120,
+            trust_forwarded: false,
+        }
+    }
+}
+
+fn backoff(base_secs: u64, max_secs: u64, attempts: u32) -> Duration {
+    let factor = 2u64.saturating_pow(attempts.saturating_sub(1));
+    Duration::from_secs(base_secs.saturating_mul(factor).min(max_secs))
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub outbox: OutboxConfig,
+    pub webhook: WebhookConfig,
+    pub auth: AuthConfig,
+    pub rate_limit: RateLimitConfig,
+}
+
+/// Values given on the command line. They take precedence over the
+/// environment, which takes precedence over the config file.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    pub config_file: Option<PathBuf>,
+    pub database_backend: Option<String>,
+    pub database_url: Option<String>,
+    pub bind_address: Option<String>,
+}
+
+impl Config {
+    pub fn load(overrides: &ConfigOverrides) -> Result<Self> {
+        let env = std::env::vars().collect::<HashMap<String, String>>();
+
+        let path = overrides
+            .config_file
+            .clone()
+            .or_else(|| env.get("DDD_CONFIG").map(PathBuf::from));
+        let file = match path {
+            Some(path) => Some(read_config_file(&path)?),
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => {
+                Some(read_config_file(Path::new(DEFAULT_CONFIG_FILE))?)
+            }
+            None => None,
+        };
+
+        Self::from_sources(file.as_deref(), &env, overrides)
+    }
+
+    pub fn from_sources(
+        file: Option<&str>,
+        env: &HashMap<String, String>,
+        overrides: &ConfigOverrides,
+    ) -> Result<Self> {
+        let mut config = match file {
+            Some(file) => toml::from_str::<Config>(file).context("Invalid config file")?,
+            None => Config::default(),
+        };
+
+        config.apply_env(env)?;
+        config.apply_overrides(overrides)?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    fn apply_env(&mut self, env: &HashMap<String, String>) -> Result<()> {
+        if let Some(backend) = env.get("DATABASE_BACKEND") {
+            self.database.backend = backend.parse().context("Invalid DATABASE_BACKEND")?;
+        }
+        if let Some(url) = env.get("DATABASE_URL") {
+            self.database.url = Some(url.to_string());
+        } else if let Some(url) = postgres_url_from_env(env) {
+            if self.database.url.is_none() && self.database.backend == DatabaseBackend::Postgres {
+                self.database.url = Some(url);
+            }
+        }
+        if let Some(value) = env.get("DATABASE_MAX_CONNECTIONS") {
+            self.database.max_connections =
+                value.parse().context("Invalid DATABASE_MAX_CONNECTIONS")?;
+        }
+        if let Some(value) = env.get("DATABASE_CONNECT_TIMEOUT") {
+            self.database.connect_timeout_secs =
+                value.parse().context("Invalid DATABASE_CONNECT_TIMEOUT")?;
+        }
+        if let Some(value) = env.get("USER_RETENTION_DAYS") {
+            self.database.user_retention_days =
+                value.parse().context("Invalid USER_RETENTION_DAYS")?;
+        }
+        if let Some(store) = env.get("CLUB_STORE") {
+            self.database.club_store = store.parse().context("Invalid CLUB_STORE")?;
+        }
+        if let Some(address) = env.get("BIND_ADDRESS") {
+            self.server.bind_address = address.to_string();
+        }
+        if let Some(url) = env.get("OUTBOX_WEBHOOK_URL") {
+            self.outbox.webhook_url = Some(url.to_string());
+        }
+        if let Some(value) = env.get("WEBHOOK_ALLOW_PRIVATE_HOSTS") {
+            self.webhook.allow_private_hosts = value
+                .parse()
+                .context("Invalid WEBHOOK_ALLOW_PRIVATE_HOSTS")?;
+        }
+        if let Some(secret) = env.get("AUTH_SECRET") {
+            self.auth.secret = Some(secret.to_string());
+        }
+        if let Some(value) = env.get("RATE_LIMIT_ENABLED") {
+            self.rate_limit.enabled = value.parse().context("Invalid RATE_LIMIT_ENABLED")?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_overrides(&mut self, overrides: &ConfigOverrides) -> Result<()> {
+        if let Some(backend) = &overrides.database_backend {
+            self.database.backend = backend.parse().context("Invalid --database-backend")?;
+        }
+        if let Some(url) = &overrides.database_url {
+            self.database.url = Some(url.to_string());
+        }
+        if let Some(address) = &overrides.bind_address {
+            self.server.bind_address = address.to_string();
+        }
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<()> {
+        let mut errors = Vec::new();
+
+        match (self.database.backend, &self.database.url) {
+            (DatabaseBackend::Postgres, None) => errors.push(
+                "database.url is required for the postgres backend. Set DATABASE_URL or the POSTGRES_* variables."
+                    .to_string(),
+            ),
+            (DatabaseBackend::Postgres, Some(url))
+                if !url.starts_with("postgres://") && !url.starts_with("postgresql://") =>
+            {
+                errors.push(format!(
+                    "database.url `{}` is not a postgres:// URL but the backend is postgres.",
+                    url
+                ))
+            }
+            (DatabaseBackend::Sqlite, Some(url)) if !url.starts_with("sqlite:") => {
+                errors.push(format!(
+                    "database.url `{}` is not a sqlite: URL but the backend is sqlite.",
+                    url
+                ))
+            }
+            _ => {}
+        }
+        if self.database.max_connections == 0 {
+            errors.push("database.max_connections must be at least 1.".to_string());
+        }
+        if self.database.min_connections > self.database.max_connections {
+            errors.push(format!(
+                "database.min_connections ({}) must not exceed database.max_connections ({}).",
+                self.database.min_connections, self.database.max_connections
+            ));
+        }
+        if self.database.connect_timeout_secs == 0 {
+            errors.push("database.connect_timeout_secs must be at least 1.".to_string());
+        }
+        if self.database.club_snapshot_interval == 0 {
+            errors.push("database.club_snapshot_interval must be at least 1.".to_string());
+        }
+        if self.database.user_retention_days == 0 {
+            errors.push("database.user_retention_days must be at least 1.".to_string());
+        }
+        if self.server.bind_address.to_socket_addrs().is_err() {
+            errors.push(format!(
+                "server.bind_address `{}` is not a valid host:port.",
+                self.server.bind_address
+            ));
+        }
+        if self.server.idempotency_ttl_secs == 0 {
+            errors.push("server.idempotency_ttl_secs must be at least 1.".to_string());
+        }
+        if self.server.workers == Some(0) {
+            errors.push("server.workers must be at least 1.".to_string());
+        }
+        if self.outbox.batch_size == 0 {
+            errors.push("outbox.batch_size must be at least 1.".to_string());
+        }
+        if self.outbox.max_attempts == 0 {
+            errors.push("outbox.max_attempts must be at least 1.".to_string());
+        }
+        if self.outbox.lease_secs == 0 {
+            errors.push("outbox.lease_secs must be at least 1.".to_string());
+        }
+        if self.webhook.batch_size == 0 {
+            errors.push("webhook.batch_size must be at least 1.".to_string());
+        }
+        if self.webhook.max_attempts == 0 {
+            errors.push("webhook.max_attempts must be at least 1.".to_string());
+        }
+        if self.webhook.lease_secs <= self.webhook.timeout_secs {
+            errors.push(format!(
+                "webhook.lease_secs ({}) must exceed webhook.timeout_secs ({}).",
+                self.webhook.lease_secs, self.webhook.timeout_secs
+            ));
+        }
+        if matches!(&self.auth.secret, Some(secret) if secret.len() < 32) {
+            errors.push("auth.secret must be at least 32 characters long.".to_string());
+        }
+        if self.auth.token_ttl_secs == 0 {
+            errors.push("auth.token_ttl_secs must be at least 1.".to_string());
+        }
+        if self.rate_limit.enabled {
+            for (name, value) in [
+                ("ip_burst", self.rate_limit.ip_burst),
+                ("ip_per_minute", self.rate_limit.ip_per_minute),
+                ("user_burst", self.rate_limit.user_burst),
+                ("user_per_minute", self.rate_limit.user_per_minute),
+            ] {
+                if value == 0 {
+                    errors.push(format!("rate_limit.{} must be at least 1.", name));
+                }
+            }
+        }
+        if let Some(url) = &self.outbox.webhook_url {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                errors.push(format!(
+                    "outbox.webhook_url `{}` is not an http:// or https:// URL.",
+                    url
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "Invalid configuration:\n  - {}",
+                errors.join("\n  - ")
+            ))
+        }
+    }
+}
+
+fn read_config_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path)
+        .with_context(|| format!("Could not read config file {}", path.display()))
+}
+
+fn postgres_url_from_env(env: &HashMap<String, String>) -> Option<String> {
+    Some(format!(
+        "postgres://{}:{}@{}:{}/{}",
+        env.get("POSTGRES_USER")?,
+        env.get("POSTGRES_PASSWORD")?,
+        env.get("POSTGRES_HOSTNAME")?,
+        env.get("POSTGRES_PORT")?,
+        env.get("POSTGRES_DB")?,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{Config, ConfigOverrides, DatabaseBackend};
+
+    fn env(vars: &[(&str, &str)]) -> HashMap<String, String> {
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn cli_overrides_env_which_overrides_file() {
+        let file = r#"
+            [server]
+            bind_address = "0.0.0.0:3000"
+
+            [database]
+            backend = "sqlite"
+            url = "sqlite://file.sqlite3"
+            max_connections = 5
+        "#;
+        let env = env(&[("DATABASE_URL", "sqlite://env.sqlite3")]);
+        let overrides = ConfigOverrides {
+            bind_address: Some("127.0.0.1:9000".to_string()),
+            ..ConfigOverrides::default()
+        };
+
+        let config = Config::from_sources(Some(file), &env, &overrides).unwrap();
+
+        assert_eq!(config.server.bind_address, "127.0.0.1:9000");
+        assert_eq!(config.database.backend, DatabaseBackend::Sqlite);
+        assert_eq!(config.database.database_url(), "sqlite://env.sqlite3");
+        assert_eq!(config.database.max_connections, 5);
+    }
+
+    #[test]
+    fn builds_postgres_url_from_legacy_variables() {
+        let env = env(&[
+            ("POSTGRES_USER", "user"),
+            ("POSTGRES_PASSWORD", "secret"),
+            ("POSTGRES_HOSTNAME", "db"),
+            ("POSTGRES_PORT", "5432"),
+            ("POSTGRES_DB", "ddd"),
+        ]);
+
+        let config = Config::from_sources(None, &env, &ConfigOverrides::default()).unwrap();
+
+        assert_eq!(
+            config.database.database_url(),
+            "postgres://user:secret@db:5432/ddd"
+        );
+    }
+
+    #[test]
+    fn reports_every_invalid_value() {
+        let file = r#"
+            [server]
+            bind_address = "nowhere"
+
+            [database]
+            max_connections = 0
+        "#;
+
+        let error = Config::from_sources(Some(file), &HashMap::new(), &ConfigOverrides::default())
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("database.url is required"));
+        assert!(error.contains("database.max_connections must be at least 1"));
+        assert!(error.contains("server.bind_address `nowhere`"));
+    }
+
+    #[test]
+    fn reports_an_unknown_backend_instead_of_panicking() {
+        let env = env(&[("DATABASE_BACKEND", "mysql")]);
+
+        let error = Config::from_sources(None, &env, &ConfigOverrides::default())
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("Invalid DATABASE_BACKEND"));
+    }
+}
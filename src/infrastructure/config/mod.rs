@@ -0,0 +1,3 @@
+mod app_config;
+
+pub use self::app_config::*;
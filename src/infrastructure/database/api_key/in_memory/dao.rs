@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+
+use crate::interface::repository::api_key::{ApiKeyDatabaseTrait, PrimitiveApiKey};
+
+static STATIC_API_KEY_TABLE: Lazy<Mutex<HashMap<String, PrimitiveApiKey>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub struct InMemoryApiKeyDatabase {}
+
+impl InMemoryApiKeyDatabase {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl ApiKeyDatabaseTrait for InMemoryApiKeyDatabase {
+    async fn save(&self, api_key: &PrimitiveApiKey) -> Result<()> {
+        let mut table = STATIC_API_KEY_TABLE.lock().await;
+        table.insert(api_key.id.to_string(), api_key.clone());
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<PrimitiveApiKey>> {
+        let table = STATIC_API_KEY_TABLE.lock().await;
+        Ok(table.get(id).cloned())
+    }
+
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<PrimitiveApiKey>> {
+        let table = STATIC_API_KEY_TABLE.lock().await;
+        Ok(table.values().find(|k| k.key_hash == key_hash).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<PrimitiveApiKey>> {
+        let table = STATIC_API_KEY_TABLE.lock().await;
+        let mut api_keys = table.values().cloned().collect::<Vec<_>>();
+        api_keys.sort_by(|a, b| (a.created_at, &a.id).cmp(&(b.created_at, &b.id)));
+        Ok(api_keys)
+    }
+
+    async fn touch(&self, id: &str, used_at: DateTime<Utc>) -> Result<()> {
+        let mut table = STATIC_API_KEY_TABLE.lock().await;
+        if let Some(api_key) = table.get_mut(id) {
+            if api_key.last_used_at.is_none_or(|t| t < used_at) {
+                api_key.last_used_at = Some(used_at);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, types::Uuid, Pool, Postgres};
+
+use crate::domain::model::error::DomainError;
+use crate::interface::repository::api_key::{ApiKeyDatabaseTrait, PrimitiveApiKey};
+
+pub struct PostgresApiKeyDatabase {
+    pool: Arc<Pool<Postgres>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresApiKeyRecord {
+    id: Uuid,
+    name: String,
+    prefix: String,
+    key_hash: String,
+    scopes: Vec<String>,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<PostgresApiKeyRecord> for PrimitiveApiKey {
+    fn from(r: PostgresApiKeyRecord) -> Self {
+        Self {
+            id: r.id.to_string(),
+            name: r.name,
+            prefix: r.prefix,
+            key_hash: r.key_hash,
+            scopes: r.scopes,
+            created_at: r.created_at,
+            last_used_at: r.last_used_at,
+            revoked_at: r.revoked_at,
+        }
+    }
+}
+
+const API_KEY_COLUMNS: &str =
+    "id, name, prefix, key_hash, scopes, created_at, last_used_at, revoked_at";
+
+fn to_uuid(value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value).map_err(|e| {
+        DomainError::invalid_field("id", "uuid", &format!("Invalid id `{}`: {}", value, e)).into()
+    })
+}
+
+#[async_trait]
+impl ApiKeyDatabaseTrait for PostgresApiKeyDatabase {
+    async fn save(&self, api_key: &PrimitiveApiKey) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+insert into public.api_key (id, name, prefix, key_hash, scopes, created_at, last_used_at, revoked_at)
+values ($1, $2, $3, $4, $5, $6, $7, $8)
+on conflict (id)
+do
+update set name = $2, scopes = $5, revoked_at = $8;
+            ",
+        )
+        .bind(to_uuid(&api_key.id)?)
+        .bind(&api_key.name)
+        .bind(&api_key.prefix)
+        .bind(&api_key.key_hash)
+        .bind(&api_key.scopes)
+        .bind(api_key.created_at)
+        .bind(api_key.last_used_at)
+        .bind(api_key.revoked_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<PrimitiveApiKey>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from public.api_key where id = $1",
+            API_KEY_COLUMNS
+        );
+        let data = sqlx::query_as::<_, PostgresApiKeyRecord>(&query)
+            .bind(to_uuid(id)?)
+            .fetch_optional(&mut conn)
+            .await?;
+
+        Ok(data.map(PrimitiveApiKey::from))
+    }
+
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<PrimitiveApiKey>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from public.api_key where key_hash = $1",
+            API_KEY_COLUMNS
+        );
+        let data = sqlx::query_as::<_, PostgresApiKeyRecord>(&query)
+            .bind(key_hash)
+            .fetch_optional(&mut conn)
+            .await?;
+
+        Ok(data.map(PrimitiveApiKey::from))
+    }
+
+    async fn find_all(&self) -> Result<Vec<PrimitiveApiKey>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from public.api_key order by created_at, id",
+            API_KEY_COLUMNS
+        );
+        let data = sqlx::query_as::<_, PostgresApiKeyRecord>(&query)
+            .fetch_all(&mut conn)
+            .await?;
+
+        Ok(data.into_iter().map(PrimitiveApiKey::from).collect())
+    }
+
+    async fn touch(&self, id: &str, used_at: DateTime<Utc>) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+update public.api_key set last_used_at = $2
+where id = $1 and (last_used_at is null or last_used_at < $2);
+            ",
+        )
+        .bind(to_uuid(id)?)
+        .bind(used_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresApiKeyDatabase {
+    pub fn new(pool: Arc<Pool<Postgres>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+}
@@ -0,0 +1,3 @@
+mod dao;
+
+pub use self::dao::*;
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, Pool, Sqlite};
+
+use crate::interface::repository::api_key::{ApiKeyDatabaseTrait, PrimitiveApiKey};
+
+pub struct SqliteApiKeyDatabase {
+    pool: Arc<Pool<Sqlite>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteApiKeyRecord {
+    id: String,
+    name: String,
+    prefix: String,
+    key_hash: String,
+    scopes: String,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<SqliteApiKeyRecord> for PrimitiveApiKey {
+    type Error = anyhow::Error;
+
+    fn try_from(r: SqliteApiKeyRecord) -> Result<Self> {
+        Ok(Self {
+            id: r.id,
+            name: r.name,
+            prefix: r.prefix,
+            key_hash: r.key_hash,
+            scopes: serde_json::from_str(&r.scopes)?,
+            created_at: r.created_at,
+            last_used_at: r.last_used_at,
+            revoked_at: r.revoked_at,
+        })
+    }
+}
+
+const API_KEY_COLUMNS: &str =
+    "id, name, prefix, key_hash, scopes, created_at, last_used_at, revoked_at";
+
+#[async_trait]
+impl ApiKeyDatabaseTrait for SqliteApiKeyDatabase {
+    async fn save(&self, api_key: &PrimitiveApiKey) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+insert into api_key (id, name, prefix, key_hash, scopes, created_at, last_used_at, revoked_at)
+values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+on conflict (id)
+do
+update set name = ?2, scopes = ?5, revoked_at = ?8;
+            ",
+        )
+        .bind(&api_key.id)
+        .bind(&api_key.name)
+        .bind(&api_key.prefix)
+        .bind(&api_key.key_hash)
+        .bind(serde_json::to_string(&api_key.scopes)?)
+        .bind(api_key.created_at)
+        .bind(api_key.last_used_at)
+        .bind(api_key.revoked_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<PrimitiveApiKey>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!("select {} from api_key where id = ?1", API_KEY_COLUMNS);
+        sqlx::query_as::<_, SqliteApiKeyRecord>(&query)
+            .bind(id)
+            .fetch_optional(&mut conn)
+            .await?
+            .map(PrimitiveApiKey::try_from)
+            .transpose()
+    }
+
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<PrimitiveApiKey>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from api_key where key_hash = ?1",
+            API_KEY_COLUMNS
+        );
+        sqlx::query_as::<_, SqliteApiKeyRecord>(&query)
+            .bind(key_hash)
+            .fetch_optional(&mut conn)
+            .await?
+            .map(PrimitiveApiKey::try_from)
+            .transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<PrimitiveApiKey>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from api_key order by created_at, id",
+            API_KEY_COLUMNS
+        );
+        sqlx::query_as::<_, SqliteApiKeyRecord>(&query)
+            .fetch_all(&mut conn)
+            .await?
+            .into_iter()
+            .map(PrimitiveApiKey::try_from)
+            .collect()
+    }
+
+    async fn touch(&self, id: &str, used_at: DateTime<Utc>) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+update api_key set last_used_at = ?2
+where id = ?1 and (last_used_at is null or last_used_at < ?2);
+            ",
+        )
+        .bind(id)
+        .bind(used_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl SqliteApiKeyDatabase {
+    pub fn new(pool: Arc<Pool<Sqlite>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+}
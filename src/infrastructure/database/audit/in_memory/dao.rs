@@ -0,0 +1,40 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+
+use crate::interface::repository::audit::{AuditDatabaseTrait, PrimitiveAuditEntry};
+
+static STATIC_AUDIT_TABLE: Lazy<Mutex<Vec<PrimitiveAuditEntry>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
+pub struct InMemoryAuditDatabase {}
+
+impl InMemoryAuditDatabase {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub async fn append(entry: &PrimitiveAuditEntry) {
+        let mut table = STATIC_AUDIT_TABLE.lock().await;
+        table.push(entry.clone());
+    }
+}
+
+#[async_trait]
+impl AuditDatabaseTrait for InMemoryAuditDatabase {
+    async fn find(
+        &self,
+        target_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<PrimitiveAuditEntry>> {
+        let table = STATIC_AUDIT_TABLE.lock().await;
+        Ok(table
+            .iter()
+            .rev()
+            .filter(|entry| target_id.is_none_or(|id| entry.target_id == id))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, types::Uuid, PgConnection, Pool, Postgres};
+
+use crate::interface::repository::audit::{AuditDatabaseTrait, PrimitiveAuditEntry};
+
+pub struct PostgresAuditDatabase {
+    pool: Arc<Pool<Postgres>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresAuditRecord {
+    id: Uuid,
+    actor: String,
+    action: String,
+    target_type: String,
+    target_id: String,
+    before_snapshot: Option<String>,
+    after_snapshot: Option<String>,
+    occurred_at: DateTime<Utc>,
+}
+
+impl From<PostgresAuditRecord> for PrimitiveAuditEntry {
+    fn from(r: PostgresAuditRecord) -> Self {
+        Self {
+            id: r.id.to_string(),
+            actor: r.actor,
+            action: r.action,
+            target_type: r.target_type,
+            target_id: r.target_id,
+            before: r.before_snapshot,
+            after: r.after_snapshot,
+            occurred_at: r.occurred_at,
+        }
+    }
+}
+
+#[async_trait]
+impl AuditDatabaseTrait for PostgresAuditDatabase {
+    async fn find(
+        &self,
+        target_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<PrimitiveAuditEntry>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let data = sqlx::query_as::<_, PostgresAuditRecord>(
+            "
+select id, actor, action, target_type, target_id,
+    before_snapshot::text as before_snapshot, after_snapshot::text as after_snapshot, occurred_at
+from public.audit_log
+where $1::text is null or target_id = $1
+order by occurred_at desc, id
+limit $2;
+            ",
+        )
+        .bind(target_id)
+        .bind(limit as i64)
+        .fetch_all(&mut conn)
+        .await?;
+
+        Ok(data.into_iter().map(PrimitiveAuditEntry::from).collect())
+    }
+}
+
+impl PostgresAuditDatabase {
+    pub fn new(pool: Arc<Pool<Postgres>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+
+    /// Appends an entry on the connection of the transaction that saves the
+    /// change it records.
+    pub async fn append(conn: &mut PgConnection, entry: &PrimitiveAuditEntry) -> Result<()> {
+        sqlx::query(
+            "
+insert into public.audit_log (id, actor, action, target_type, target_id, before_snapshot, after_snapshot, occurred_at)
+values ($1, $2, $3, $4, $5, $6::jsonb, $7::jsonb, $8);
+            ",
+        )
+        .bind(Uuid::parse_str(&entry.id)?)
+        .bind(&entry.actor)
+        .bind(&entry.action)
+        .bind(&entry.target_type)
+        .bind(&entry.target_id)
+        .bind(&entry.before)
+        .bind(&entry.after)
+        .bind(entry.occurred_at)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
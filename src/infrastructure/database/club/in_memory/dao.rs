@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use crate::domain::model::error::DomainError;
+use crate::infrastructure::database::{
+    audit::InMemoryAuditDatabase, outbox::InMemoryOutboxDatabase,
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    club::{
+        ClubDatabaseTrait, ClubPageQuery, PrimitiveId, PrimitiveMemberChanges, PrimitiveMembers,
+        PrimitiveName, PrimitiveOwner,
+    },
+    outbox::PrimitiveEvent,
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+
+static STATIC_CLUB_TABLE: Lazy<Mutex<ClubTable>> = Lazy::new(|| {
+    let table = ClubTable::new();
+    Mutex::new(table)
+});
+
+#[derive(Clone, Debug)]
+pub struct ClubRow {
+    id: String,
+    name: String,
+    owner: String,
+    members: Vec<String>,
+}
+
+type ClubTable = HashMap<String, ClubRow>;
+
+pub struct InMemoryClubDatabase {}
+
+impl InMemoryClubDatabase {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Clubs sorted by name like the SQL databases return them.
+fn sorted(mut rows: Vec<ClubRow>) -> Vec<ClubRow> {
+    rows.sort_by(|a, b| (&a.name, &a.id).cmp(&(&b.name, &b.id)));
+    rows
+}
+
+#[async_trait]
+impl ClubDatabaseTrait for InMemoryClubDatabase {
+    type ClubId = String;
+    type ClubName = String;
+    type ClubMembers = Vec<String>;
+    type ClubOwner = String;
+    type ClubData = ClubRow;
+
+    fn from_club_id(id: &Self::ClubId) -> Result<PrimitiveId> {
+        Ok(id.to_owned())
+    }
+    fn from_club_name(name: &Self::ClubName) -> Result<PrimitiveName> {
+        Ok(name.to_owned())
+    }
+    fn from_club_owner(owner: &Self::ClubOwner) -> Result<PrimitiveOwner> {
+        Ok(owner.to_owned())
+    }
+    fn from_club_members(members: &Self::ClubMembers) -> Result<PrimitiveMembers> {
+        Ok(members.to_owned())
+    }
+    fn from_club_data(
+        club: &Self::ClubData,
+    ) -> Result<(PrimitiveId, PrimitiveName, PrimitiveOwner, PrimitiveMembers)> {
+        Ok((
+            club.id.to_owned(),
+            club.name.to_owned(),
+            club.owner.to_owned(),
+            club.members.to_owned(),
+        ))
+    }
+
+    fn to_club_id(value: &PrimitiveId) -> Result<Self::ClubId> {
+        Ok(value.to_owned())
+    }
+    fn to_club_name(value: &PrimitiveName) -> Result<Self::ClubName> {
+        Ok(value.to_owned())
+    }
+    fn to_club_owner(value: &PrimitiveOwner) -> Result<Self::ClubOwner> {
+        Ok(value.to_owned())
+    }
+    fn to_club_members(members: &PrimitiveMembers) -> Result<Self::ClubMembers> {
+        Ok(members.to_owned())
+    }
+    fn to_club_data(
+        id: &PrimitiveId,
+        name: &PrimitiveName,
+        owner_id: &PrimitiveOwner,
+        members: &PrimitiveMembers,
+    ) -> Result<Self::ClubData> {
+        Ok(ClubRow {
+            id: id.to_owned(),
+            name: name.to_owned(),
+            owner: owner_id.to_owned(),
+            members: members.to_owned(),
+        })
+    }
+
+    async fn save(
+        &self,
+        club: &Self::ClubData,
+        changes: &PrimitiveMemberChanges,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()> {
+        let mut table = STATIC_CLUB_TABLE.lock().await;
+        if table
+            .values()
+            .any(|r| r.name == club.name && r.id != club.id)
+        {
+            return Err(DomainError::Conflict("Club already exists".to_string()).into());
+        }
+        let mut row = club.clone();
+        if let Some(saved) = table.get(&club.id) {
+            row.members = saved.members.clone();
+        }
+        row.members.retain(|m| !changes.left.contains(m));
+        for member in &changes.joined {
+            if !row.members.contains(member) {
+                row.members.push(member.to_owned());
+            }
+        }
+        if row.members.contains(&row.owner) {
+            return Err(DomainError::Conflict(
+                "The owner of a club cannot be a member".to_string(),
+            )
+            .into());
+        }
+        table.insert(row.id.to_owned(), row);
+        InMemoryOutboxDatabase::append(events).await;
+        if let Some(entry) = audit {
+            InMemoryAuditDatabase::append(entry).await;
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_name(&self, club_name: &Self::ClubName) -> Result<Option<Self::ClubData>> {
+        let table = STATIC_CLUB_TABLE.lock().await;
+        Ok(table.values().find(|row| row.name == *club_name).cloned())
+    }
+
+    async fn find_by_id(&self, id: &Self::ClubId) -> Result<Option<Self::ClubData>> {
+        let table = STATIC_CLUB_TABLE.lock().await;
+        Ok(table.get(id).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Self::ClubData>> {
+        let table = STATIC_CLUB_TABLE.lock().await;
+        Ok(table.values().cloned().collect())
+    }
+
+    async fn find_page(&self, query: &ClubPageQuery<Self::ClubId>) -> Result<Vec<Self::ClubData>> {
+        let table = STATIC_CLUB_TABLE.lock().await;
+        let rows = table
+            .values()
+            .filter(|row| {
+                query
+                    .name_contains
+                    .as_ref()
+                    .is_none_or(|name| row.name.to_lowercase().contains(&name.to_lowercase()))
+            })
+            .filter(|row| {
+                query
+                    .after
+                    .as_ref()
+                    .is_none_or(|(id, name)| (&row.name, &row.id) > (name, id))
+            })
+            .cloned()
+            .collect();
+
+        Ok(sorted(rows).into_iter().take(query.limit).collect())
+    }
+
+    async fn find_by_owner(&self, owner: &Self::ClubOwner) -> Result<Vec<Self::ClubData>> {
+        let table = STATIC_CLUB_TABLE.lock().await;
+        let rows = table
+            .values()
+            .filter(|row| row.owner == *owner)
+            .cloned()
+            .collect();
+
+        Ok(sorted(rows))
+    }
+
+    async fn find_by_member(&self, user_id: &Self::ClubOwner) -> Result<Vec<Self::ClubData>> {
+        let table = STATIC_CLUB_TABLE.lock().await;
+        let rows = table
+            .values()
+            .filter(|row| row.members.contains(user_id))
+            .cloned()
+            .collect();
+
+        Ok(sorted(rows))
+    }
+}
@@ -1,4 +1,8 @@
+#[cfg(test)]
 mod in_memory;
 mod postgres;
+mod sqlite;
 
-pub use self::{in_memory::*, postgres::*};
+#[cfg(test)]
+pub use self::in_memory::*;
+pub use self::{postgres::*, sqlite::*};
@@ -1,12 +1,20 @@
 use std::{collections::HashMap, sync::Arc};
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::{self, types::Uuid, Pool, Postgres};
-use std::sync::Mutex;
+use sqlx::{self, pool::PoolConnection, types::Uuid, Pool, Postgres};
 
-use crate::interface::repository::club::{
-    ClubDatabaseTrait, PrimitiveId, PrimitiveMembers, PrimitiveName, PrimitiveOwner,
+use crate::domain::model::error::DomainError;
+use crate::infrastructure::database::{
+    audit::PostgresAuditDatabase, outbox::PostgresOutboxDatabase, shared::map_constraint_violation,
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    club::{
+        ClubDatabaseTrait, ClubPageQuery, PrimitiveId, PrimitiveMemberChanges, PrimitiveMembers,
+        PrimitiveName, PrimitiveOwner,
+    },
+    outbox::PrimitiveEvent,
 };
 
 pub struct PostgresClubDatabase {
@@ -51,13 +59,19 @@ impl ClubDatabaseTrait for PostgresClubDatabase {
     }
 
     fn to_club_id(value: &PrimitiveId) -> Result<Self::ClubId> {
-        Uuid::parse_str(value).map_err(|e| anyhow!(e.to_string()))
+        Uuid::parse_str(value).map_err(|e| {
+            DomainError::invalid_field("club_id", "uuid", &format!("Invalid id `{}`: {}", value, e))
+                .into()
+        })
     }
     fn to_club_name(value: &PrimitiveName) -> Result<Self::ClubName> {
         Ok(value.to_owned())
     }
     fn to_club_owner(value: &PrimitiveOwner) -> Result<Self::ClubOwner> {
-        Uuid::parse_str(value).map_err(|e| anyhow!(e.to_string()))
+        Uuid::parse_str(value).map_err(|e| {
+            DomainError::invalid_field("user_id", "uuid", &format!("Invalid id `{}`: {}", value, e))
+                .into()
+        })
     }
     fn to_club_members(members: &PrimitiveMembers) -> Result<Self::ClubMembers> {
         Ok(members.to_owned())
@@ -76,18 +90,39 @@ impl ClubDatabaseTrait for PostgresClubDatabase {
         Ok((id, name, owner, members))
     }
 
-    async fn save(&self, club: &Self::ClubData) -> Result<()> {
-        let mut conn = self.pool.acquire().await?;
+    async fn save(
+        &self,
+        club: &Self::ClubData,
+        changes: &PrimitiveMemberChanges,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
 
         let id = club.0;
         let name = club.1.to_string();
         let owner = club.2;
-        let members = &club.3;
+        let to_uuids = |members: &PrimitiveMembers| {
+            members
+                .iter()
+                .map(|m| Uuid::parse_str(m))
+                .collect::<Result<Vec<Uuid>, _>>()
+        };
+        let joined = to_uuids(&changes.joined)?;
+        let left = to_uuids(&changes.left)?;
+
+        // Members who left are removed first, so that a member who becomes the
+        // owner is no longer in club_members when the owner changes.
+        sqlx::query("delete from public.club_members where club_id = $1 and user_id = any($2)")
+            .bind(id)
+            .bind(&left)
+            .execute(&mut tx)
+            .await?;
 
         sqlx::query(
             "
 insert into public.club (id, name, owner) values ($1, $2, $3)
-on conflict on constraint club_id_key
+on conflict (id)
 do
 update set name = $2, owner = $3;
             ",
@@ -95,29 +130,34 @@ update set name = $2, owner = $3;
         .bind(id.to_owned())
         .bind(name)
         .bind(owner)
-        .execute(&mut *conn)
-        .await?;
+        .execute(&mut tx)
+        .await
+        .map_err(map_constraint_violation)?;
 
-        let mut conn = self.pool.acquire().await?;
-
-        for member in members {
-            let member = Uuid::parse_str(member)?;
+        for member in joined {
             sqlx::query(
                 "
-insert into public.club_members (club_id, user_id) 
-select $1, $2 where not exists (select 1 from public.club_members where club_id = $1 and user_id = $2);
-                "
+insert into public.club_members (club_id, user_id) values ($1, $2)
+on conflict do nothing;
+                ",
             )
             .bind(id)
             .bind(member)
-            .execute(&mut *conn)
-            .await?;
+            .execute(&mut tx)
+            .await
+            .map_err(map_constraint_violation)?;
         }
 
+        PostgresOutboxDatabase::append(&mut tx, events).await?;
+        if let Some(entry) = audit {
+            PostgresAuditDatabase::append(&mut tx, entry).await?;
+        }
+        tx.commit().await?;
+
         Ok(())
     }
 
-    async fn find_by_name(&self, club_name: &Self::ClubName) -> Result<Self::ClubData> {
+    async fn find_by_name(&self, club_name: &Self::ClubName) -> Result<Option<Self::ClubData>> {
         let mut conn = self.pool.acquire().await?;
         type Id = Uuid;
         type Name = String;
@@ -127,8 +167,12 @@ select $1, $2 where not exists (select 1 from public.club_members where club_id
             "select id, name, owner from club where name = $1",
         )
         .bind(club_name)
-        .fetch_one(&mut *conn)
+        .fetch_optional(&mut *conn)
         .await?;
+        let data = match data {
+            Some(data) => data,
+            None => return Ok(None),
+        };
 
         let club_id = data.0;
 
@@ -145,10 +189,10 @@ select $1, $2 where not exists (select 1 from public.club_members where club_id
             .map(|m| m.0.to_string())
             .collect::<Vec<String>>();
 
-        Ok((data.0, data.1, data.2, members))
+        Ok(Some((data.0, data.1, data.2, members)))
     }
 
-    async fn find_by_id(&self, id: &Self::ClubId) -> Result<Self::ClubData> {
+    async fn find_by_id(&self, id: &Self::ClubId) -> Result<Option<Self::ClubData>> {
         let mut conn = self.pool.acquire().await?;
         type Id = Uuid;
         type Name = String;
@@ -160,8 +204,12 @@ select $1, $2 where not exists (select 1 from public.club_members where club_id
             "select id, name, owner from club where id = $1",
         )
         .bind(club_id)
-        .fetch_one(&mut *conn)
+        .fetch_optional(&mut *conn)
         .await?;
+        let data = match data {
+            Some(data) => data,
+            None => return Ok(None),
+        };
 
         #[derive(sqlx::FromRow)]
         struct Response(Uuid);
@@ -176,43 +224,86 @@ select $1, $2 where not exists (select 1 from public.club_members where club_id
             .map(|m| m.0.to_string())
             .collect::<Vec<String>>();
 
-        Ok((data.0, data.1, data.2, members))
+        Ok(Some((data.0, data.1, data.2, members)))
     }
 
     async fn find_all(&self) -> Result<Vec<Self::ClubData>> {
         let mut conn = self.pool.acquire().await?;
-        type Id = Uuid;
-        type Name = String;
-        type Owner = Uuid;
-        type Member = Uuid;
 
-        let data = sqlx::query_as::<_, (Id, Name, Owner, Member)>(
+        let clubs = sqlx::query_as::<_, (Uuid, String, Uuid)>(
+            "select id, name, owner from public.club order by name, id",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        Self::with_members(&mut conn, clubs).await
+    }
+
+    async fn find_page(&self, query: &ClubPageQuery<Self::ClubId>) -> Result<Vec<Self::ClubData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let mut conditions = Vec::new();
+        let mut params = 0;
+        if query.name_contains.is_some() {
+            params += 1;
+            conditions.push(format!("strpos(lower(name), lower(${})) > 0", params));
+        }
+        if query.after.is_some() {
+            params += 2;
+            conditions.push(format!("(name, id) > (${}, ${})", params, params - 1));
+        }
+        let filter = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" where {}", conditions.join(" and "))
+        };
+        let sql = format!(
+            "select id, name, owner from public.club{} order by name, id limit ${}",
+            filter,
+            params + 1
+        );
+
+        let mut page = sqlx::query_as::<_, (Uuid, String, Uuid)>(&sql);
+        if let Some(name) = &query.name_contains {
+            page = page.bind(name);
+        }
+        if let Some((id, name)) = &query.after {
+            page = page.bind(id).bind(name);
+        }
+        let clubs = page.bind(query.limit as i64).fetch_all(&mut conn).await?;
+        Self::with_members(&mut conn, clubs).await
+    }
+
+    async fn find_by_owner(&self, owner: &Self::ClubOwner) -> Result<Vec<Self::ClubData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let clubs = sqlx::query_as::<_, (Uuid, String, Uuid)>(
+            "select id, name, owner from public.club where owner = $1 order by name, id",
+        )
+        .bind(owner)
+        .fetch_all(&mut conn)
+        .await?;
+
+        Self::with_members(&mut conn, clubs).await
+    }
+
+    async fn find_by_member(&self, user_id: &Self::ClubOwner) -> Result<Vec<Self::ClubData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let clubs = sqlx::query_as::<_, (Uuid, String, Uuid)>(
             "
-            select club.id, club.name, club.owner, public.user.id from club
-            left outer join club_members
+            select club.id, club.name, club.owner from public.club
+            inner join public.club_members
             on club.id = club_members.club_id
-            left outer join public.user
-            on club_members.user_id = public.user.id;
+            where club_members.user_id = $1
+            order by club.name, club.id
             ",
         )
+        .bind(user_id)
         .fetch_all(&mut conn)
         .await?;
 
-        let mut clubs: HashMap<String, Mutex<Self::ClubData>> = HashMap::new();
-        data.iter().for_each(|c| {
-            if let Some(club) = clubs.get(&c.0.to_string()) {
-                let mut club = club.lock().unwrap();
-                club.3.push(c.3.to_string());
-            } else {
-                let club = (c.0, c.1.to_string(), c.2, vec![c.3.to_string()]);
-                clubs.insert(c.0.to_string(), Mutex::new(club));
-            }
-        });
-
-        Ok(clubs
-            .into_values()
-            .map(|a| a.into_inner().unwrap())
-            .collect::<Vec<Self::ClubData>>())
+        Self::with_members(&mut conn, clubs).await
     }
 }
 
@@ -220,4 +311,35 @@ impl PostgresClubDatabase {
     pub fn new(pool: Arc<Pool<Postgres>>) -> anyhow::Result<Self> {
         Ok(Self { pool })
     }
+
+    /// Loads the members of all given clubs with a single query.
+    async fn with_members(
+        conn: &mut PoolConnection<Postgres>,
+        clubs: Vec<(Uuid, String, Uuid)>,
+    ) -> Result<Vec<<Self as ClubDatabaseTrait>::ClubData>> {
+        if clubs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids = clubs.iter().map(|c| c.0).collect::<Vec<Uuid>>();
+        let mut members: HashMap<Uuid, Vec<String>> = HashMap::new();
+        sqlx::query_as::<_, (Uuid, Uuid)>(
+            "select club_id, user_id from public.club_members where club_id = any($1)",
+        )
+        .bind(&ids)
+        .fetch_all(conn)
+        .await?
+        .into_iter()
+        .for_each(|(club_id, user_id)| {
+            members
+                .entry(club_id)
+                .or_default()
+                .push(user_id.to_string())
+        });
+
+        Ok(clubs
+            .into_iter()
+            .map(|(id, name, owner)| (id, name, owner, members.remove(&id).unwrap_or_default()))
+            .collect())
+    }
 }
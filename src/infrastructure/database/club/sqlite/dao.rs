@@ -0,0 +1,501 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::{self, pool::PoolConnection, Pool, Sqlite};
+
+use crate::infrastructure::database::{
+    audit::SqliteAuditDatabase, outbox::SqliteOutboxDatabase, shared::map_constraint_violation,
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    club::{
+        ClubDatabaseTrait, ClubPageQuery, PrimitiveId, PrimitiveMemberChanges, PrimitiveMembers,
+        PrimitiveName, PrimitiveOwner,
+    },
+    outbox::PrimitiveEvent,
+};
+
+pub struct SqliteClubDatabase {
+    pool: Arc<Pool<Sqlite>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteClubRecord {
+    id: String,
+    name: String,
+    owner: String,
+    members: Vec<String>,
+}
+
+#[async_trait]
+impl ClubDatabaseTrait for SqliteClubDatabase {
+    type ClubId = String;
+    type ClubName = String;
+    type ClubMembers = Vec<String>;
+    type ClubOwner = String;
+    type ClubData = SqliteClubRecord;
+
+    fn from_club_id(id: &Self::ClubId) -> Result<PrimitiveId> {
+        Ok(id.to_owned())
+    }
+    fn from_club_name(name: &Self::ClubName) -> Result<PrimitiveName> {
+        Ok(name.to_owned())
+    }
+    fn from_club_owner(owner: &Self::ClubOwner) -> Result<PrimitiveOwner> {
+        Ok(owner.to_owned())
+    }
+    fn from_club_members(members: &Self::ClubMembers) -> Result<PrimitiveMembers> {
+        Ok(members.to_owned())
+    }
+    fn from_club_data(
+        club: &Self::ClubData,
+    ) -> Result<(PrimitiveId, PrimitiveName, PrimitiveOwner, PrimitiveMembers)> {
+        Ok((
+            club.id.to_owned(),
+            club.name.to_owned(),
+            club.owner.to_owned(),
+            club.members.to_owned(),
+        ))
+    }
+
+    fn to_club_id(value: &PrimitiveId) -> Result<Self::ClubId> {
+        Ok(value.to_owned())
+    }
+    fn to_club_name(value: &PrimitiveName) -> Result<Self::ClubName> {
+        Ok(value.to_owned())
+    }
+    fn to_club_owner(value: &PrimitiveOwner) -> Result<Self::ClubOwner> {
+        Ok(value.to_owned())
+    }
+    fn to_club_members(members: &PrimitiveMembers) -> Result<Self::ClubMembers> {
+        Ok(members.to_owned())
+    }
+    fn to_club_data(
+        id: &PrimitiveId,
+        name: &PrimitiveName,
+        owner_id: &PrimitiveOwner,
+        members: &PrimitiveMembers,
+    ) -> Result<Self::ClubData> {
+        Ok(SqliteClubRecord {
+            id: Self::to_club_id(id)?,
+            name: Self::to_club_name(name)?,
+            owner: Self::to_club_owner(owner_id)?,
+            members: Self::to_club_members(members)?,
+        })
+    }
+
+    async fn save(
+        &self,
+        club: &Self::ClubData,
+        changes: &PrimitiveMemberChanges,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        // Members who left are removed first, so that a member who becomes the
+        // owner is no longer in club_members when the owner changes.
+        for member in &changes.left {
+            sqlx::query("delete from club_members where club_id = ?1 and user_id = ?2;")
+                .bind(&club.id)
+                .bind(member)
+                .execute(&mut tx)
+                .await?;
+        }
+
+        sqlx::query(
+            "
+insert into club (id, name, owner) values (?1, ?2, ?3)
+on conflict (id)
+do
+update set name = ?2, owner = ?3;
+            ",
+        )
+        .bind(&club.id)
+        .bind(&club.name)
+        .bind(&club.owner)
+        .execute(&mut tx)
+        .await
+        .map_err(map_constraint_violation)?;
+
+        for member in &changes.joined {
+            sqlx::query("insert or ignore into club_members (club_id, user_id) values (?1, ?2);")
+                .bind(&club.id)
+                .bind(member)
+                .execute(&mut tx)
+                .await
+                .map_err(map_constraint_violation)?;
+        }
+
+        SqliteOutboxDatabase::append(&mut tx, events).await?;
+        if let Some(entry) = audit {
+            SqliteAuditDatabase::append(&mut tx, entry).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_by_name(&self, club_name: &Self::ClubName) -> Result<Option<Self::ClubData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let row = sqlx::query_as::<_, (String, String, String)>(
+            "select id, name, owner from club where name = ?1",
+        )
+        .bind(club_name)
+        .fetch_optional(&mut conn)
+        .await?;
+        let (id, name, owner) = match row {
+            Some(row) => row,
+            None => return Ok(None),
+        };
+
+        let members =
+            sqlx::query_as::<_, (String,)>("select user_id from club_members where club_id = ?1")
+                .bind(&id)
+                .fetch_all(&mut conn)
+                .await?
+                .into_iter()
+                .map(|m| m.0)
+                .collect();
+
+        Ok(Some(SqliteClubRecord {
+            id,
+            name,
+            owner,
+            members,
+        }))
+    }
+
+    async fn find_by_id(&self, id: &Self::ClubId) -> Result<Option<Self::ClubData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let row = sqlx::query_as::<_, (String, String, String)>(
+            "select id, name, owner from club where id = ?1",
+        )
+        .bind(id)
+        .fetch_optional(&mut conn)
+        .await?;
+        let (id, name, owner) = match row {
+            Some(row) => row,
+            None => return Ok(None),
+        };
+
+        let members =
+            sqlx::query_as::<_, (String,)>("select user_id from club_members where club_id = ?1")
+                .bind(&id)
+                .fetch_all(&mut conn)
+                .await?
+                .into_iter()
+                .map(|m| m.0)
+                .collect();
+
+        Ok(Some(SqliteClubRecord {
+            id,
+            name,
+            owner,
+            members,
+        }))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Self::ClubData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let clubs =
+            sqlx::query_as::<_, (String, String, String)>("select id, name, owner from club")
+                .fetch_all(&mut conn)
+                .await?;
+
+        let mut members: HashMap<String, Vec<String>> = HashMap::new();
+        sqlx::query_as::<_, (String, String)>("select club_id, user_id from club_members")
+            .fetch_all(&mut conn)
+            .await?
+            .into_iter()
+            .for_each(|(club_id, user_id)| members.entry(club_id).or_default().push(user_id));
+
+        Ok(clubs
+            .into_iter()
+            .map(|(id, name, owner)| SqliteClubRecord {
+                members: members.remove(&id).unwrap_or_default(),
+                id,
+                name,
+                owner,
+            })
+            .collect())
+    }
+
+    async fn find_page(&self, query: &ClubPageQuery<Self::ClubId>) -> Result<Vec<Self::ClubData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let mut conditions = Vec::new();
+        let mut params = 0;
+        if query.name_contains.is_some() {
+            params += 1;
+            conditions.push(format!("instr(lower(name), lower(?{})) > 0", params));
+        }
+        if query.after.is_some() {
+            params += 2;
+            conditions.push(format!("(name, id) > (?{}, ?{})", params, params - 1));
+        }
+        let filter = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" where {}", conditions.join(" and "))
+        };
+        let sql = format!(
+            "select id, name, owner from club{} order by name, id limit ?{}",
+            filter,
+            params + 1
+        );
+
+        let mut page = sqlx::query_as::<_, (String, String, String)>(&sql);
+        if let Some(name) = &query.name_contains {
+            page = page.bind(name);
+        }
+        if let Some((id, name)) = &query.after {
+            page = page.bind(id).bind(name);
+        }
+        let clubs = page.bind(query.limit as i64).fetch_all(&mut conn).await?;
+        Self::with_members(&mut conn, clubs).await
+    }
+
+    async fn find_by_owner(&self, owner: &Self::ClubOwner) -> Result<Vec<Self::ClubData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let clubs = sqlx::query_as::<_, (String, String, String)>(
+            "select id, name, owner from club where owner = ?1 order by name, id",
+        )
+        .bind(owner)
+        .fetch_all(&mut conn)
+        .await?;
+
+        Self::with_members(&mut conn, clubs).await
+    }
+
+    async fn find_by_member(&self, user_id: &Self::ClubOwner) -> Result<Vec<Self::ClubData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let clubs = sqlx::query_as::<_, (String, String, String)>(
+            "
+            select club.id, club.name, club.owner from club
+            inner join club_members
+            on club.id = club_members.club_id
+            where club_members.user_id = ?1
+            order by club.name, club.id
+            ",
+        )
+        .bind(user_id)
+        .fetch_all(&mut conn)
+        .await?;
+
+        Self::with_members(&mut conn, clubs).await
+    }
+}
+
+impl SqliteClubDatabase {
+    pub fn new(pool: Arc<Pool<Sqlite>>) -> anyhow::Result<Self> {
+        Ok(Self { pool })
+    }
+
+    /// Loads the members of all given clubs with a single query.
+    async fn with_members(
+        conn: &mut PoolConnection<Sqlite>,
+        clubs: Vec<(String, String, String)>,
+    ) -> Result<Vec<SqliteClubRecord>> {
+        if clubs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let params = (1..=clubs.len())
+            .map(|i| format!("?{}", i))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let sql = format!(
+            "select club_id, user_id from club_members where club_id in ({})",
+            params
+        );
+        let mut members: HashMap<String, Vec<String>> = HashMap::new();
+        clubs
+            .iter()
+            .fold(sqlx::query_as::<_, (String, String)>(&sql), |q, c| {
+                q.bind(&c.0)
+            })
+            .fetch_all(&mut *conn)
+            .await?
+            .into_iter()
+            .for_each(|(club_id, user_id)| members.entry(club_id).or_default().push(user_id));
+
+        Ok(clubs
+            .into_iter()
+            .map(|(id, name, owner)| SqliteClubRecord {
+                members: members.remove(&id).unwrap_or_default(),
+                id,
+                name,
+                owner,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use crate::domain::model::{
+        club::{
+            entity::{Club, ClubId, ClubName},
+            repository::{ClubListCursor, ClubListQuery, ClubRepositoryTrait},
+        },
+        error::DomainError,
+        user::entity::{User, UserId, UserIsPremium, UserName},
+    };
+    use crate::infrastructure::database::shared::SQLITE_MIGRATOR;
+    use crate::interface::repository::club::ClubRepository;
+
+    use super::SqliteClubDatabase;
+
+    async fn club_repository() -> ClubRepository {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        SQLITE_MIGRATOR.run(&pool).await.unwrap();
+        sqlx::query("insert into user (id, name) values ('owner', 'owner'), ('member', 'member'), ('guest', 'guest')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let club_database = SqliteClubDatabase::new(Arc::new(pool)).unwrap();
+        ClubRepository::new(Box::new(club_database)).await.unwrap()
+    }
+
+    fn club(id: &str, name: &str, members: Vec<&str>) -> Club {
+        Club::new(
+            ClubId::new(id).unwrap(),
+            ClubName::new(name).unwrap(),
+            members
+                .into_iter()
+                .map(|m| UserId::new(m).unwrap())
+                .collect(),
+            UserId::new("owner").unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn can_save_and_find_club() {
+        let repository = club_repository().await;
+        let mut club = club("club", "tennis", vec!["member"]);
+
+        repository.save(&mut club).await.unwrap();
+        repository.save(&mut club).await.unwrap();
+
+        let found = repository.find_by_id(club.get_id()).await.unwrap().unwrap();
+        assert_eq!(found.get_members(), club.get_members());
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+
+        let mut left = found.clone();
+        left.leave(&UserId::new("member").unwrap()).unwrap();
+        repository.save(&mut left).await.unwrap();
+        let found = repository.find_by_id(club.get_id()).await.unwrap().unwrap();
+        assert!(found.get_members().is_empty());
+    }
+
+    #[tokio::test]
+    async fn keeps_the_members_of_concurrent_saves() {
+        let repository = club_repository().await;
+        let mut club = club("club", "tennis", vec![]);
+        repository.save(&mut club).await.unwrap();
+        let loaded = repository.find_by_id(club.get_id()).await.unwrap().unwrap();
+
+        for (id, name) in [("member", "member"), ("guest", "guest")] {
+            let user = User::new(
+                UserId::new(id).unwrap(),
+                UserName::new(name).unwrap(),
+                UserIsPremium::new(false),
+            )
+            .unwrap();
+            let mut stale = loaded.clone();
+            stale.join(user).unwrap();
+            repository.save(&mut stale).await.unwrap();
+        }
+
+        let found = repository.find_by_id(club.get_id()).await.unwrap().unwrap();
+        let mut members = found
+            .get_members()
+            .iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>();
+        members.sort();
+        assert_eq!(members, vec!["guest", "member"]);
+    }
+
+    #[tokio::test]
+    async fn duplicate_name_and_owner_membership_are_conflicts() {
+        let repository = club_repository().await;
+        repository
+            .save(&mut club("first", "tennis", vec![]))
+            .await
+            .unwrap();
+
+        let duplicate = repository.save(&mut club("second", "tennis", vec![])).await;
+        let error = duplicate.unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<DomainError>(),
+            Some(&DomainError::Conflict("Club already exists".to_string()))
+        );
+
+        let owner_as_member = repository
+            .save(&mut club("third", "soccer", vec!["owner"]))
+            .await;
+        let error = owner_as_member.unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<DomainError>(),
+            Some(&DomainError::Conflict(
+                "The owner of a club cannot be a member".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn can_find_page_by_name() {
+        let repository = club_repository().await;
+        repository
+            .save(&mut club("a", "Tennis", vec!["member"]))
+            .await
+            .unwrap();
+        repository
+            .save(&mut club("b", "table tennis", vec![]))
+            .await
+            .unwrap();
+        repository
+            .save(&mut club("c", "soccer", vec![]))
+            .await
+            .unwrap();
+
+        let mut query = ClubListQuery {
+            name_contains: Some("TENNIS".to_string()),
+            after: None,
+            limit: 1,
+        };
+        let first = repository.find_page(&query).await.unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].get_name(), &ClubName::new("Tennis").unwrap());
+        assert_eq!(first[0].get_members().len(), 1);
+
+        query.after = Some(ClubListCursor {
+            id: first[0].get_id().clone(),
+            name: first[0].get_name().clone(),
+        });
+        let second = repository.find_page(&query).await.unwrap();
+        assert_eq!(second.len(), 1);
+        assert_eq!(
+            second[0].get_name(),
+            &ClubName::new("table tennis").unwrap()
+        );
+    }
+}
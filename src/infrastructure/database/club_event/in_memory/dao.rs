@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+
+use crate::domain::model::error::DomainError;
+use crate::infrastructure::database::{
+    audit::InMemoryAuditDatabase, outbox::InMemoryOutboxDatabase, shared::CLUB_CHANGED,
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    club::{
+        ClubEventStoreTrait, ClubStream, ClubViewQuery, PrimitiveClubSnapshot, PrimitiveClubView,
+        StoredClubEvent,
+    },
+    outbox::PrimitiveEvent,
+};
+
+static STATIC_CLUB_EVENT_TABLE: Lazy<Mutex<ClubEventTable>> = Lazy::new(|| {
+    let table = ClubEventTable::default();
+    Mutex::new(table)
+});
+
+#[derive(Default)]
+struct ClubEventTable {
+    streams: HashMap<String, Vec<StoredClubEvent>>,
+    snapshots: HashMap<String, PrimitiveClubSnapshot>,
+    views: HashMap<String, PrimitiveClubView>,
+}
+
+pub struct InMemoryClubEventStore {}
+
+impl InMemoryClubEventStore {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl ClubEventStoreTrait for InMemoryClubEventStore {
+    async fn append(
+        &self,
+        view: &PrimitiveClubView,
+        expected_version: u64,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()> {
+        let mut table = STATIC_CLUB_EVENT_TABLE.lock().await;
+        if table
+            .views
+            .values()
+            .any(|v| v.name == view.name && v.club_id != view.club_id)
+        {
+            return Err(DomainError::Conflict("Club already exists".to_string()).into());
+        }
+        let stream = table.streams.entry(view.club_id.to_string()).or_default();
+        if stream.len() as u64 != expected_version {
+            return Err(DomainError::Conflict(CLUB_CHANGED.to_string()).into());
+        }
+
+        stream.extend(
+            (expected_version + 1..)
+                .zip(events)
+                .map(|(version, event)| StoredClubEvent {
+                    version,
+                    event: event.clone(),
+                }),
+        );
+        table.views.insert(view.club_id.to_string(), view.clone());
+        InMemoryOutboxDatabase::append(events).await;
+        if let Some(entry) = audit {
+            InMemoryAuditDatabase::append(entry).await;
+        }
+
+        Ok(())
+    }
+
+    async fn load(&self, club_id: &str) -> Result<ClubStream> {
+        let table = STATIC_CLUB_EVENT_TABLE.lock().await;
+        let snapshot = table.snapshots.get(club_id).cloned();
+        let after = snapshot.as_ref().map_or(0, |s| s.version);
+        let events = table
+            .streams
+            .get(club_id)
+            .map(|stream| {
+                stream
+                    .iter()
+                    .filter(|e| e.version > after)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(ClubStream { snapshot, events })
+    }
+
+    async fn save_snapshot(&self, snapshot: &PrimitiveClubSnapshot) -> Result<()> {
+        let mut table = STATIC_CLUB_EVENT_TABLE.lock().await;
+        let newer = table
+            .snapshots
+            .get(&snapshot.club_id)
+            .is_none_or(|s| s.version < snapshot.version);
+        if newer {
+            table
+                .snapshots
+                .insert(snapshot.club_id.to_string(), snapshot.clone());
+        }
+
+        Ok(())
+    }
+
+    async fn find_views(&self, query: &ClubViewQuery) -> Result<Vec<PrimitiveClubView>> {
+        let table = STATIC_CLUB_EVENT_TABLE.lock().await;
+        let name_contains = query.name_contains.as_ref().map(|n| n.to_lowercase());
+        let mut views = table
+            .views
+            .values()
+            .filter(|v| query.name.as_ref().is_none_or(|name| v.name == *name))
+            .filter(|v| {
+                name_contains
+                    .as_ref()
+                    .is_none_or(|n| v.name.to_lowercase().contains(n))
+            })
+            .filter(|v| {
+                query
+                    .after
+                    .as_ref()
+                    .is_none_or(|after| (v.name.clone(), v.club_id.clone()) > *after)
+            })
+            .filter(|v| query.owner.as_ref().is_none_or(|owner| v.owner == *owner))
+            .filter(|v| query.member.as_ref().is_none_or(|m| v.members.contains(m)))
+            .cloned()
+            .collect::<Vec<_>>();
+        views.sort_by(|a, b| (&a.name, &a.club_id).cmp(&(&b.name, &b.club_id)));
+        views.truncate(query.limit.unwrap_or(usize::MAX));
+        Ok(views)
+    }
+
+    async fn save_view(&self, view: &PrimitiveClubView) -> Result<()> {
+        let mut table = STATIC_CLUB_EVENT_TABLE.lock().await;
+        table.views.insert(view.club_id.to_string(), view.clone());
+
+        Ok(())
+    }
+
+    async fn club_ids_without_view(&self) -> Result<Vec<String>> {
+        let table = STATIC_CLUB_EVENT_TABLE.lock().await;
+        let mut ids = table
+            .streams
+            .iter()
+            .filter(|(id, stream)| !stream.is_empty() && !table.views.contains_key(*id))
+            .map(|(id, _)| id.to_string())
+            .collect::<Vec<_>>();
+        ids.sort();
+        Ok(ids)
+    }
+}
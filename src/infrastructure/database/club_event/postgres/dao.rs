@@ -0,0 +1,321 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, types::Uuid, PgConnection, Pool, Postgres};
+
+use crate::domain::model::error::DomainError;
+use crate::infrastructure::database::{
+    audit::PostgresAuditDatabase,
+    outbox::PostgresOutboxDatabase,
+    shared::{map_constraint_violation, CLUB_CHANGED},
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    club::{
+        ClubEventStoreTrait, ClubStream, ClubViewQuery, PrimitiveClubSnapshot, PrimitiveClubView,
+        StoredClubEvent,
+    },
+    outbox::PrimitiveEvent,
+};
+
+pub struct PostgresClubEventStore {
+    pool: Arc<Pool<Postgres>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresClubEventRecord {
+    club_id: Uuid,
+    version: i64,
+    id: Uuid,
+    event_type: String,
+    payload: String,
+    occurred_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresClubSnapshotRecord {
+    club_id: Uuid,
+    version: i64,
+    name: String,
+    owner: Uuid,
+    members: Vec<Uuid>,
+    taken_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresClubViewRecord {
+    club_id: Uuid,
+    version: i64,
+    name: String,
+    owner: Uuid,
+    members: Vec<Uuid>,
+}
+
+fn to_uuid(value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value).map_err(|e| {
+        DomainError::invalid_field("id", "uuid", &format!("Invalid id `{}`: {}", value, e)).into()
+    })
+}
+
+#[async_trait]
+impl ClubEventStoreTrait for PostgresClubEventStore {
+    async fn append(
+        &self,
+        view: &PrimitiveClubView,
+        expected_version: u64,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()> {
+        let club_id = to_uuid(&view.club_id)?;
+        let mut tx = self.pool.begin().await?;
+
+        let (version,) = sqlx::query_as::<_, (i64,)>(
+            "select coalesce(max(version), 0) from public.club_event where club_id = $1",
+        )
+        .bind(club_id)
+        .fetch_one(&mut tx)
+        .await?;
+        if version as u64 != expected_version {
+            return Err(DomainError::Conflict(CLUB_CHANGED.to_string()).into());
+        }
+
+        for (version, event) in (expected_version + 1..).zip(events) {
+            sqlx::query(
+                "
+insert into public.club_event (club_id, version, id, event_type, payload, occurred_at)
+values ($1, $2, $3, $4, $5::jsonb, $6);
+                ",
+            )
+            .bind(club_id)
+            .bind(version as i64)
+            .bind(to_uuid(&event.id)?)
+            .bind(&event.event_type)
+            .bind(&event.payload)
+            .bind(event.occurred_at)
+            .execute(&mut tx)
+            .await
+            .map_err(map_constraint_violation)?;
+        }
+
+        Self::write_view(&mut tx, view).await?;
+        PostgresOutboxDatabase::append(&mut tx, events).await?;
+        if let Some(entry) = audit {
+            PostgresAuditDatabase::append(&mut tx, entry).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn load(&self, club_id: &str) -> Result<ClubStream> {
+        let club_id = to_uuid(club_id)?;
+        let mut conn = self.pool.acquire().await?;
+
+        let snapshot = sqlx::query_as::<_, PostgresClubSnapshotRecord>(
+            "
+select club_id, version, name, owner, members, taken_at
+from public.club_snapshot where club_id = $1
+            ",
+        )
+        .bind(club_id)
+        .fetch_optional(&mut conn)
+        .await?;
+
+        let events = sqlx::query_as::<_, PostgresClubEventRecord>(
+            "
+select club_id, version, id, event_type, payload::text as payload, occurred_at
+from public.club_event where club_id = $1 and version > $2
+order by version
+            ",
+        )
+        .bind(club_id)
+        .bind(snapshot.as_ref().map_or(0, |s| s.version))
+        .fetch_all(&mut conn)
+        .await?;
+
+        Ok(ClubStream {
+            snapshot: snapshot.map(|s| PrimitiveClubSnapshot {
+                club_id: s.club_id.to_string(),
+                version: s.version as u64,
+                name: s.name,
+                owner: s.owner.to_string(),
+                members: s.members.iter().map(|m| m.to_string()).collect(),
+                taken_at: s.taken_at,
+            }),
+            events: events
+                .into_iter()
+                .map(|r| StoredClubEvent {
+                    version: r.version as u64,
+                    event: PrimitiveEvent {
+                        id: r.id.to_string(),
+                        event_type: r.event_type,
+                        aggregate_id: r.club_id.to_string(),
+                        payload: r.payload,
+                        occurred_at: r.occurred_at,
+                    },
+                })
+                .collect(),
+        })
+    }
+
+    async fn save_snapshot(&self, snapshot: &PrimitiveClubSnapshot) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+insert into public.club_snapshot (club_id, version, name, owner, members, taken_at)
+values ($1, $2, $3, $4, $5, $6)
+on conflict (club_id)
+do
+update set version = $2, name = $3, owner = $4, members = $5, taken_at = $6
+where club_snapshot.version < $2;
+            ",
+        )
+        .bind(to_uuid(&snapshot.club_id)?)
+        .bind(snapshot.version as i64)
+        .bind(&snapshot.name)
+        .bind(to_uuid(&snapshot.owner)?)
+        .bind(
+            snapshot
+                .members
+                .iter()
+                .map(|m| to_uuid(m))
+                .collect::<Result<Vec<Uuid>>>()?,
+        )
+        .bind(snapshot.taken_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_views(&self, query: &ClubViewQuery) -> Result<Vec<PrimitiveClubView>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let mut conditions = Vec::new();
+        let mut params = 0;
+        if query.name.is_some() {
+            params += 1;
+            conditions.push(format!("name = ${}", params));
+        }
+        if query.name_contains.is_some() {
+            params += 1;
+            conditions.push(format!("strpos(lower(name), lower(${})) > 0", params));
+        }
+        if query.after.is_some() {
+            params += 2;
+            conditions.push(format!("(name, club_id) > (${}, ${})", params - 1, params));
+        }
+        if query.owner.is_some() {
+            params += 1;
+            conditions.push(format!("owner = ${}", params));
+        }
+        if query.member.is_some() {
+            params += 1;
+            conditions.push(format!("${} = any(members)", params));
+        }
+        let filter = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" where {}", conditions.join(" and "))
+        };
+        let limit = if query.limit.is_some() {
+            format!(" limit ${}", params + 1)
+        } else {
+            String::new()
+        };
+        let sql = format!(
+            "select club_id, version, name, owner, members from public.club_view{} order by name, club_id{}",
+            filter, limit
+        );
+
+        let mut views = sqlx::query_as::<_, PostgresClubViewRecord>(&sql);
+        if let Some(name) = &query.name {
+            views = views.bind(name);
+        }
+        if let Some(name) = &query.name_contains {
+            views = views.bind(name);
+        }
+        if let Some((name, id)) = &query.after {
+            views = views.bind(name).bind(to_uuid(id)?);
+        }
+        if let Some(owner) = &query.owner {
+            views = views.bind(to_uuid(owner)?);
+        }
+        if let Some(member) = &query.member {
+            views = views.bind(to_uuid(member)?);
+        }
+        if let Some(limit) = query.limit {
+            views = views.bind(limit as i64);
+        }
+
+        Ok(views
+            .fetch_all(&mut conn)
+            .await?
+            .into_iter()
+            .map(|v| PrimitiveClubView {
+                club_id: v.club_id.to_string(),
+                version: v.version as u64,
+                name: v.name,
+                owner: v.owner.to_string(),
+                members: v.members.iter().map(|m| m.to_string()).collect(),
+            })
+            .collect())
+    }
+
+    async fn save_view(&self, view: &PrimitiveClubView) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+        Self::write_view(&mut conn, view).await
+    }
+
+    async fn club_ids_without_view(&self) -> Result<Vec<String>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let ids = sqlx::query_as::<_, (Uuid,)>(
+            "
+select distinct e.club_id from public.club_event e
+where not exists (select 1 from public.club_view v where v.club_id = e.club_id)
+order by e.club_id
+            ",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        Ok(ids.into_iter().map(|(id,)| id.to_string()).collect())
+    }
+}
+
+impl PostgresClubEventStore {
+    pub fn new(pool: Arc<Pool<Postgres>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+
+    async fn write_view(conn: &mut PgConnection, view: &PrimitiveClubView) -> Result<()> {
+        sqlx::query(
+            "
+insert into public.club_view (club_id, version, name, owner, members)
+values ($1, $2, $3, $4, $5)
+on conflict (club_id)
+do
+update set version = $2, name = $3, owner = $4, members = $5;
+            ",
+        )
+        .bind(to_uuid(&view.club_id)?)
+        .bind(view.version as i64)
+        .bind(&view.name)
+        .bind(to_uuid(&view.owner)?)
+        .bind(
+            view.members
+                .iter()
+                .map(|m| to_uuid(m))
+                .collect::<Result<Vec<Uuid>>>()?,
+        )
+        .execute(conn)
+        .await
+        .map_err(map_constraint_violation)?;
+
+        Ok(())
+    }
+}
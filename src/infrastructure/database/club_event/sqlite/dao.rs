@@ -0,0 +1,429 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, Pool, Sqlite, SqliteConnection};
+
+use crate::domain::model::error::DomainError;
+use crate::infrastructure::database::{
+    audit::SqliteAuditDatabase,
+    outbox::SqliteOutboxDatabase,
+    shared::{map_constraint_violation, CLUB_CHANGED},
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    club::{
+        ClubEventStoreTrait, ClubStream, ClubViewQuery, PrimitiveClubSnapshot, PrimitiveClubView,
+        StoredClubEvent,
+    },
+    outbox::PrimitiveEvent,
+};
+
+pub struct SqliteClubEventStore {
+    pool: Arc<Pool<Sqlite>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteClubEventRecord {
+    club_id: String,
+    version: i64,
+    id: String,
+    event_type: String,
+    payload: String,
+    occurred_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteClubSnapshotRecord {
+    club_id: String,
+    version: i64,
+    name: String,
+    owner: String,
+    members: String,
+    taken_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteClubViewRecord {
+    club_id: String,
+    version: i64,
+    name: String,
+    owner: String,
+    members: String,
+}
+
+#[async_trait]
+impl ClubEventStoreTrait for SqliteClubEventStore {
+    async fn append(
+        &self,
+        view: &PrimitiveClubView,
+        expected_version: u64,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()> {
+        let club_id = &view.club_id;
+        let mut tx = self.pool.begin().await?;
+
+        let (version,) = sqlx::query_as::<_, (i64,)>(
+            "select coalesce(max(version), 0) from club_event where club_id = ?1",
+        )
+        .bind(club_id)
+        .fetch_one(&mut tx)
+        .await?;
+        if version as u64 != expected_version {
+            return Err(DomainError::Conflict(CLUB_CHANGED.to_string()).into());
+        }
+
+        for (version, event) in (expected_version + 1..).zip(events) {
+            sqlx::query(
+                "
+insert into club_event (club_id, version, id, event_type, payload, occurred_at)
+values (?1, ?2, ?3, ?4, ?5, ?6);
+                ",
+            )
+            .bind(club_id)
+            .bind(version as i64)
+            .bind(&event.id)
+            .bind(&event.event_type)
+            .bind(&event.payload)
+            .bind(event.occurred_at)
+            .execute(&mut tx)
+            .await
+            .map_err(map_constraint_violation)?;
+        }
+
+        Self::write_view(&mut tx, view).await?;
+        SqliteOutboxDatabase::append(&mut tx, events).await?;
+        if let Some(entry) = audit {
+            SqliteAuditDatabase::append(&mut tx, entry).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn load(&self, club_id: &str) -> Result<ClubStream> {
+        let mut conn = self.pool.acquire().await?;
+
+        let snapshot = sqlx::query_as::<_, SqliteClubSnapshotRecord>(
+            "
+select club_id, version, name, owner, members, taken_at
+from club_snapshot where club_id = ?1
+            ",
+        )
+        .bind(club_id)
+        .fetch_optional(&mut conn)
+        .await?;
+
+        let events = sqlx::query_as::<_, SqliteClubEventRecord>(
+            "
+select club_id, version, id, event_type, payload, occurred_at
+from club_event where club_id = ?1 and version > ?2
+order by version
+            ",
+        )
+        .bind(club_id)
+        .bind(snapshot.as_ref().map_or(0, |s| s.version))
+        .fetch_all(&mut conn)
+        .await?;
+
+        let snapshot = match snapshot {
+            Some(s) => Some(PrimitiveClubSnapshot {
+                club_id: s.club_id,
+                version: s.version as u64,
+                name: s.name,
+                owner: s.owner,
+                members: serde_json::from_str(&s.members)?,
+                taken_at: s.taken_at,
+            }),
+            None => None,
+        };
+
+        Ok(ClubStream {
+            snapshot,
+            events: events
+                .into_iter()
+                .map(|r| StoredClubEvent {
+                    version: r.version as u64,
+                    event: PrimitiveEvent {
+                        id: r.id,
+                        event_type: r.event_type,
+                        aggregate_id: r.club_id,
+                        payload: r.payload,
+                        occurred_at: r.occurred_at,
+                    },
+                })
+                .collect(),
+        })
+    }
+
+    async fn save_snapshot(&self, snapshot: &PrimitiveClubSnapshot) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+insert into club_snapshot (club_id, version, name, owner, members, taken_at)
+values (?1, ?2, ?3, ?4, ?5, ?6)
+on conflict (club_id)
+do
+update set version = ?2, name = ?3, owner = ?4, members = ?5, taken_at = ?6
+where club_snapshot.version < ?2;
+            ",
+        )
+        .bind(&snapshot.club_id)
+        .bind(snapshot.version as i64)
+        .bind(&snapshot.name)
+        .bind(&snapshot.owner)
+        .bind(serde_json::to_string(&snapshot.members)?)
+        .bind(snapshot.taken_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_views(&self, query: &ClubViewQuery) -> Result<Vec<PrimitiveClubView>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let mut conditions = Vec::new();
+        let mut params = 0;
+        if query.name.is_some() {
+            params += 1;
+            conditions.push(format!("name = ?{}", params));
+        }
+        if query.name_contains.is_some() {
+            params += 1;
+            conditions.push(format!("instr(lower(name), lower(?{})) > 0", params));
+        }
+        if query.after.is_some() {
+            params += 2;
+            conditions.push(format!("(name, club_id) > (?{}, ?{})", params - 1, params));
+        }
+        if query.owner.is_some() {
+            params += 1;
+            conditions.push(format!("owner = ?{}", params));
+        }
+        if query.member.is_some() {
+            params += 1;
+            conditions.push(format!(
+                "exists (select 1 from json_each(club_view.members) where json_each.value = ?{})",
+                params
+            ));
+        }
+        let filter = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" where {}", conditions.join(" and "))
+        };
+        let limit = if query.limit.is_some() {
+            format!(" limit ?{}", params + 1)
+        } else {
+            String::new()
+        };
+        let sql = format!(
+            "select club_id, version, name, owner, members from club_view{} order by name, club_id{}",
+            filter, limit
+        );
+
+        let mut views = sqlx::query_as::<_, SqliteClubViewRecord>(&sql);
+        if let Some(name) = &query.name {
+            views = views.bind(name);
+        }
+        if let Some(name) = &query.name_contains {
+            views = views.bind(name);
+        }
+        if let Some((name, id)) = &query.after {
+            views = views.bind(name).bind(id);
+        }
+        if let Some(owner) = &query.owner {
+            views = views.bind(owner);
+        }
+        if let Some(member) = &query.member {
+            views = views.bind(member);
+        }
+        if let Some(limit) = query.limit {
+            views = views.bind(limit as i64);
+        }
+
+        views
+            .fetch_all(&mut conn)
+            .await?
+            .into_iter()
+            .map(|v| {
+                Ok(PrimitiveClubView {
+                    club_id: v.club_id,
+                    version: v.version as u64,
+                    name: v.name,
+                    owner: v.owner,
+                    members: serde_json::from_str(&v.members)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn save_view(&self, view: &PrimitiveClubView) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+        Self::write_view(&mut conn, view).await
+    }
+
+    async fn club_ids_without_view(&self) -> Result<Vec<String>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let ids = sqlx::query_as::<_, (String,)>(
+            "
+select distinct e.club_id from club_event e
+where not exists (select 1 from club_view v where v.club_id = e.club_id)
+order by e.club_id
+            ",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        Ok(ids.into_iter().map(|(id,)| id).collect())
+    }
+}
+
+impl SqliteClubEventStore {
+    pub fn new(pool: Arc<Pool<Sqlite>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+
+    async fn write_view(conn: &mut SqliteConnection, view: &PrimitiveClubView) -> Result<()> {
+        sqlx::query(
+            "
+insert into club_view (club_id, version, name, owner, members)
+values (?1, ?2, ?3, ?4, ?5)
+on conflict (club_id)
+do
+update set version = ?2, name = ?3, owner = ?4, members = ?5;
+            ",
+        )
+        .bind(&view.club_id)
+        .bind(view.version as i64)
+        .bind(&view.name)
+        .bind(&view.owner)
+        .bind(serde_json::to_string(&view.members)?)
+        .execute(conn)
+        .await
+        .map_err(map_constraint_violation)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use crate::domain::model::{
+        club::{
+            entity::{Club, ClubId, ClubName},
+            repository::{ClubListCursor, ClubListQuery},
+        },
+        error::DomainError,
+        user::entity::{User, UserId, UserIsPremium, UserName},
+    };
+    use crate::infrastructure::database::shared::SQLITE_MIGRATOR;
+    use crate::interface::repository::club::{ClubDatabaseTraitWrapper, EventSourcedClubDatabase};
+
+    use super::SqliteClubEventStore;
+
+    fn names(clubs: &[Club]) -> Vec<String> {
+        clubs.iter().map(|c| c.get_name().to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn lists_clubs_from_views_and_keeps_names_unique() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        SQLITE_MIGRATOR.run(&pool).await.unwrap();
+        let pool = Arc::new(pool);
+        let database = EventSourcedClubDatabase::new(
+            Box::new(SqliteClubEventStore::new(Arc::clone(&pool)).unwrap()),
+            10,
+        );
+        let owner = UserId::new("owner").unwrap();
+        for name in ["alpha", "beta", "gamma"] {
+            let club = Club::new(
+                ClubId::new(name).unwrap(),
+                ClubName::new(name).unwrap(),
+                Vec::new(),
+                owner.clone(),
+            )
+            .unwrap();
+            database.save(&club, None).await.unwrap();
+        }
+        let mut beta = database
+            .find_by_name(&ClubName::new("beta").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        let member = User::new(
+            UserId::new("member").unwrap(),
+            UserName::new("member").unwrap(),
+            UserIsPremium::new(false),
+        )
+        .unwrap();
+        beta.join(member).unwrap();
+        database.save(&beta, None).await.unwrap();
+
+        let taken = Club::new(
+            ClubId::new("other").unwrap(),
+            ClubName::new("alpha").unwrap(),
+            Vec::new(),
+            owner.clone(),
+        )
+        .unwrap();
+        let error = database.save(&taken, None).await.unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<DomainError>(),
+            Some(&DomainError::Conflict("Club already exists".to_string()))
+        );
+        assert!(database
+            .find_by_id(&ClubId::new("other").unwrap())
+            .await
+            .unwrap()
+            .is_none());
+
+        let owned = database.find_by_owner(&owner).await.unwrap();
+        assert_eq!(names(&owned), vec!["alpha", "beta", "gamma"]);
+        let joined = database
+            .find_by_member(&UserId::new("member").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(names(&joined), vec!["beta"]);
+        assert_eq!(joined[0].get_version(), 2);
+        let page = database
+            .find_page(&ClubListQuery {
+                name_contains: Some("A".to_string()),
+                after: Some(ClubListCursor {
+                    id: ClubId::new("alpha").unwrap(),
+                    name: ClubName::new("alpha").unwrap(),
+                }),
+                limit: 1,
+            })
+            .await
+            .unwrap();
+        assert_eq!(names(&page), vec!["beta"]);
+
+        sqlx::query("delete from club_view where club_id = 'gamma'")
+            .execute(&*pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            names(&database.find_all().await.unwrap()),
+            vec!["alpha", "beta"]
+        );
+        assert_eq!(database.fill_missing_views().await.unwrap(), 1);
+        assert_eq!(
+            names(&database.find_all().await.unwrap()),
+            vec!["alpha", "beta", "gamma"]
+        );
+    }
+}
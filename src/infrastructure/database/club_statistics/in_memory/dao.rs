@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+
+use crate::interface::repository::club_statistics::{
+    ClubStatisticsDatabaseTrait, PrimitiveClubStatistics,
+};
+
+static STATIC_CLUB_STATISTICS_TABLE: Lazy<Mutex<HashMap<String, PrimitiveClubStatistics>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub struct InMemoryClubStatisticsDatabase {}
+
+impl InMemoryClubStatisticsDatabase {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl ClubStatisticsDatabaseTrait for InMemoryClubStatisticsDatabase {
+    async fn save(&self, statistics: &PrimitiveClubStatistics) -> Result<()> {
+        let mut table = STATIC_CLUB_STATISTICS_TABLE.lock().await;
+        table.insert(statistics.club_id.to_string(), statistics.clone());
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, club_id: &str) -> Result<Option<PrimitiveClubStatistics>> {
+        let table = STATIC_CLUB_STATISTICS_TABLE.lock().await;
+        Ok(table.get(club_id).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<PrimitiveClubStatistics>> {
+        let table = STATIC_CLUB_STATISTICS_TABLE.lock().await;
+        let mut rows = table.values().cloned().collect::<Vec<_>>();
+        rows.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(rows)
+    }
+
+    async fn delete(&self, club_id: &str) -> Result<()> {
+        let mut table = STATIC_CLUB_STATISTICS_TABLE.lock().await;
+        table.remove(club_id);
+
+        Ok(())
+    }
+
+    async fn delete_all(&self) -> Result<()> {
+        let mut table = STATIC_CLUB_STATISTICS_TABLE.lock().await;
+        table.clear();
+
+        Ok(())
+    }
+}
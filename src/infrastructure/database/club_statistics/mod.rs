@@ -0,0 +1,8 @@
+#[cfg(test)]
+mod in_memory;
+mod postgres;
+mod sqlite;
+
+#[cfg(test)]
+pub use self::in_memory::*;
+pub use self::{postgres::*, sqlite::*};
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, types::Uuid, Pool, Postgres};
+
+use crate::domain::model::error::DomainError;
+use crate::interface::repository::club_statistics::{
+    ClubStatisticsDatabaseTrait, PrimitiveClubStatistics,
+};
+
+pub struct PostgresClubStatisticsDatabase {
+    pool: Arc<Pool<Postgres>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresClubStatisticsRecord {
+    club_id: Uuid,
+    name: String,
+    owner: Uuid,
+    member_count: i32,
+    premium_member_count: i32,
+    last_joined_at: Option<DateTime<Utc>>,
+}
+
+impl From<PostgresClubStatisticsRecord> for PrimitiveClubStatistics {
+    fn from(r: PostgresClubStatisticsRecord) -> Self {
+        Self {
+            club_id: r.club_id.to_string(),
+            name: r.name,
+            owner_id: r.owner.to_string(),
+            member_count: r.member_count as u32,
+            premium_member_count: r.premium_member_count as u32,
+            last_joined_at: r.last_joined_at,
+        }
+    }
+}
+
+const COLUMNS: &str = "club_id, name, owner, member_count, premium_member_count, last_joined_at";
+
+fn to_uuid(value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value).map_err(|e| {
+        DomainError::invalid_field("id", "uuid", &format!("Invalid id `{}`: {}", value, e)).into()
+    })
+}
+
+#[async_trait]
+impl ClubStatisticsDatabaseTrait for PostgresClubStatisticsDatabase {
+    async fn save(&self, statistics: &PrimitiveClubStatistics) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+insert into public.club_statistics (club_id, name, owner, member_count, premium_member_count, last_joined_at, updated_at)
+values ($1, $2, $3, $4, $5, $6, now())
+on conflict (club_id)
+do
+update set name = $2, owner = $3, member_count = $4, premium_member_count = $5,
+    last_joined_at = $6, updated_at = now();
+            ",
+        )
+        .bind(to_uuid(&statistics.club_id)?)
+        .bind(&statistics.name)
+        .bind(to_uuid(&statistics.owner_id)?)
+        .bind(statistics.member_count as i32)
+        .bind(statistics.premium_member_count as i32)
+        .bind(statistics.last_joined_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, club_id: &str) -> Result<Option<PrimitiveClubStatistics>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from public.club_statistics where club_id = $1",
+            COLUMNS
+        );
+        let data = sqlx::query_as::<_, PostgresClubStatisticsRecord>(&query)
+            .bind(to_uuid(club_id)?)
+            .fetch_optional(&mut conn)
+            .await?;
+
+        Ok(data.map(PrimitiveClubStatistics::from))
+    }
+
+    async fn find_all(&self) -> Result<Vec<PrimitiveClubStatistics>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from public.club_statistics order by name",
+            COLUMNS
+        );
+        let data = sqlx::query_as::<_, PostgresClubStatisticsRecord>(&query)
+            .fetch_all(&mut conn)
+            .await?;
+
+        Ok(data
+            .into_iter()
+            .map(PrimitiveClubStatistics::from)
+            .collect())
+    }
+
+    async fn delete(&self, club_id: &str) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query("delete from public.club_statistics where club_id = $1")
+            .bind(to_uuid(club_id)?)
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_all(&self) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query("delete from public.club_statistics")
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresClubStatisticsDatabase {
+    pub fn new(pool: Arc<Pool<Postgres>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, Pool, Sqlite};
+
+use crate::interface::repository::club_statistics::{
+    ClubStatisticsDatabaseTrait, PrimitiveClubStatistics,
+};
+
+pub struct SqliteClubStatisticsDatabase {
+    pool: Arc<Pool<Sqlite>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteClubStatisticsRecord {
+    club_id: String,
+    name: String,
+    owner: String,
+    member_count: i64,
+    premium_member_count: i64,
+    last_joined_at: Option<DateTime<Utc>>,
+}
+
+impl From<SqliteClubStatisticsRecord> for PrimitiveClubStatistics {
+    fn from(r: SqliteClubStatisticsRecord) -> Self {
+        Self {
+            club_id: r.club_id,
+            name: r.name,
+            owner_id: r.owner,
+            member_count: r.member_count as u32,
+            premium_member_count: r.premium_member_count as u32,
+            last_joined_at: r.last_joined_at,
+        }
+    }
+}
+
+const COLUMNS: &str = "club_id, name, owner, member_count, premium_member_count, last_joined_at";
+
+#[async_trait]
+impl ClubStatisticsDatabaseTrait for SqliteClubStatisticsDatabase {
+    async fn save(&self, statistics: &PrimitiveClubStatistics) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+insert into club_statistics (club_id, name, owner, member_count, premium_member_count, last_joined_at, updated_at)
+values (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+on conflict (club_id)
+do
+update set name = ?2, owner = ?3, member_count = ?4, premium_member_count = ?5,
+    last_joined_at = ?6, updated_at = ?7;
+            ",
+        )
+        .bind(&statistics.club_id)
+        .bind(&statistics.name)
+        .bind(&statistics.owner_id)
+        .bind(statistics.member_count as i64)
+        .bind(statistics.premium_member_count as i64)
+        .bind(statistics.last_joined_at)
+        .bind(Utc::now())
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, club_id: &str) -> Result<Option<PrimitiveClubStatistics>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!("select {} from club_statistics where club_id = ?1", COLUMNS);
+        let data = sqlx::query_as::<_, SqliteClubStatisticsRecord>(&query)
+            .bind(club_id)
+            .fetch_optional(&mut conn)
+            .await?;
+
+        Ok(data.map(PrimitiveClubStatistics::from))
+    }
+
+    async fn find_all(&self) -> Result<Vec<PrimitiveClubStatistics>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!("select {} from club_statistics order by name", COLUMNS);
+        let data = sqlx::query_as::<_, SqliteClubStatisticsRecord>(&query)
+            .fetch_all(&mut conn)
+            .await?;
+
+        Ok(data
+            .into_iter()
+            .map(PrimitiveClubStatistics::from)
+            .collect())
+    }
+
+    async fn delete(&self, club_id: &str) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query("delete from club_statistics where club_id = ?1")
+            .bind(club_id)
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_all(&self) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query("delete from club_statistics")
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl SqliteClubStatisticsDatabase {
+    pub fn new(pool: Arc<Pool<Sqlite>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+}
@@ -0,0 +1,4 @@
+mod postgres;
+mod sqlite;
+
+pub use self::{postgres::*, sqlite::*};
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, Pool, Postgres};
+
+use crate::interface::repository::idempotency::{
+    IdempotencyDatabaseTrait, PrimitiveIdempotencyRecord, PrimitiveIdempotentResponse,
+};
+
+pub struct PostgresIdempotencyDatabase {
+    pool: Arc<Pool<Postgres>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresIdempotencyRecord {
+    scope: String,
+    key: String,
+    fingerprint: String,
+    response_status: Option<i32>,
+    response_headers: Option<String>,
+    response_body: Option<Vec<u8>>,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    locked_until: DateTime<Utc>,
+}
+
+impl TryFrom<PostgresIdempotencyRecord> for PrimitiveIdempotencyRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(r: PostgresIdempotencyRecord) -> Result<Self> {
+        let response = match (r.response_status, r.response_headers, r.response_body) {
+            (Some(status), Some(headers), Some(body)) => Some(PrimitiveIdempotentResponse {
+                status: u16::try_from(status)?,
+                headers: serde_json::from_str(&headers)?,
+                body,
+            }),
+            _ => None,
+        };
+        Ok(Self {
+            scope: r.scope,
+            key: r.key,
+            fingerprint: r.fingerprint,
+            response,
+            created_at: r.created_at,
+            expires_at: r.expires_at,
+            locked_until: r.locked_until,
+        })
+    }
+}
+
+#[async_trait]
+impl IdempotencyDatabaseTrait for PostgresIdempotencyDatabase {
+    async fn claim(&self, record: &PrimitiveIdempotencyRecord) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("delete from public.idempotency_key where expires_at <= $1")
+            .bind(record.created_at)
+            .execute(&mut tx)
+            .await?;
+        let claimed = sqlx::query(
+            "
+insert into public.idempotency_key (scope, key, fingerprint, created_at, expires_at, locked_until)
+values ($1, $2, $3, $4, $5, $6)
+on conflict (scope, key)
+do
+update set fingerprint = $3, response_status = null, response_headers = null, response_body = null,
+    created_at = $4, expires_at = $5, locked_until = $6
+where idempotency_key.response_status is null and idempotency_key.locked_until <= $4;
+            ",
+        )
+        .bind(&record.scope)
+        .bind(&record.key)
+        .bind(&record.fingerprint)
+        .bind(record.created_at)
+        .bind(record.expires_at)
+        .bind(record.locked_until)
+        .execute(&mut tx)
+        .await?
+        .rows_affected()
+            > 0;
+        tx.commit().await?;
+
+        Ok(claimed)
+    }
+
+    async fn find(&self, scope: &str, key: &str) -> Result<Option<PrimitiveIdempotencyRecord>> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query_as::<_, PostgresIdempotencyRecord>(
+            "
+select scope, key, fingerprint, response_status, response_headers::text as response_headers,
+    response_body, created_at, expires_at, locked_until
+from public.idempotency_key
+where scope = $1 and key = $2;
+            ",
+        )
+        .bind(scope)
+        .bind(key)
+        .fetch_optional(&mut conn)
+        .await?
+        .map(PrimitiveIdempotencyRecord::try_from)
+        .transpose()
+    }
+
+    async fn complete(
+        &self,
+        scope: &str,
+        key: &str,
+        response: &PrimitiveIdempotentResponse,
+    ) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+update public.idempotency_key
+set response_status = $3, response_headers = $4::jsonb, response_body = $5
+where scope = $1 and key = $2;
+            ",
+        )
+        .bind(scope)
+        .bind(key)
+        .bind(i32::from(response.status))
+        .bind(serde_json::to_string(&response.headers)?)
+        .bind(&response.body)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn release(&self, scope: &str, key: &str) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "delete from public.idempotency_key where scope = $1 and key = $2 and response_status is null",
+        )
+        .bind(scope)
+        .bind(key)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresIdempotencyDatabase {
+    pub fn new(pool: Arc<Pool<Postgres>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+}
@@ -1,3 +1,10 @@
+pub mod api_key;
+pub mod audit;
 pub mod club;
+pub mod club_event;
+pub mod club_statistics;
+pub mod idempotency;
+pub mod outbox;
 pub mod shared;
 pub mod user;
+pub mod webhook;
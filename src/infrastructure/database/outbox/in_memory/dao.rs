@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+
+use crate::interface::repository::outbox::{
+    OutboxDatabaseTrait, OutboxRecord, OutboxStats, PrimitiveEvent,
+};
+
+static STATIC_OUTBOX_TABLE: Lazy<Mutex<Vec<OutboxRow>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+struct OutboxRow {
+    event: PrimitiveEvent,
+    attempts: u32,
+    last_error: Option<String>,
+    next_attempt_at: DateTime<Utc>,
+    published_at: Option<DateTime<Utc>>,
+}
+
+pub struct InMemoryOutboxDatabase {}
+
+impl InMemoryOutboxDatabase {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub async fn append(events: &[PrimitiveEvent]) {
+        let mut table = STATIC_OUTBOX_TABLE.lock().await;
+        table.extend(events.iter().map(|event| OutboxRow {
+            event: event.clone(),
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: event.occurred_at,
+            published_at: None,
+        }));
+    }
+}
+
+#[async_trait]
+impl OutboxDatabaseTrait for InMemoryOutboxDatabase {
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+        max_attempts: u32,
+    ) -> Result<Vec<OutboxRecord>> {
+        let mut table = STATIC_OUTBOX_TABLE.lock().await;
+        Ok(table
+            .iter_mut()
+            .filter(|row| {
+                row.published_at.is_none()
+                    && row.next_attempt_at <= now
+                    && row.attempts < max_attempts
+            })
+            .take(limit)
+            .map(|row| {
+                row.next_attempt_at = lease_until;
+                OutboxRecord {
+                    event: row.event.clone(),
+                    attempts: row.attempts,
+                }
+            })
+            .collect())
+    }
+
+    async fn mark_published(&self, id: &str, published_at: DateTime<Utc>) -> Result<()> {
+        let mut table = STATIC_OUTBOX_TABLE.lock().await;
+        if let Some(row) = table.iter_mut().find(|row| row.event.id == id) {
+            row.published_at = Some(published_at);
+        }
+
+        Ok(())
+    }
+
+    async fn mark_failed(&self, id: &str, error: &str, retry_at: DateTime<Utc>) -> Result<()> {
+        let mut table = STATIC_OUTBOX_TABLE.lock().await;
+        if let Some(row) = table.iter_mut().find(|row| row.event.id == id) {
+            row.attempts += 1;
+            row.last_error = Some(error.to_string());
+            row.next_attempt_at = retry_at;
+        }
+
+        Ok(())
+    }
+
+    async fn stats(&self, max_attempts: u32) -> Result<OutboxStats> {
+        let table = STATIC_OUTBOX_TABLE.lock().await;
+        Ok(table.iter().fold(OutboxStats::default(), |mut stats, row| {
+            match (row.published_at, row.attempts) {
+                (Some(_), _) => stats.published += 1,
+                (None, 0) => stats.pending += 1,
+                (None, attempts) if attempts < max_attempts => stats.retrying += 1,
+                (None, _) => stats.dead += 1,
+            }
+            stats
+        }))
+    }
+
+    async fn last_occurred_at(&self, event_type: &str) -> Result<HashMap<String, DateTime<Utc>>> {
+        let table = STATIC_OUTBOX_TABLE.lock().await;
+        let mut last = HashMap::new();
+        for row in table
+            .iter()
+            .filter(|row| row.event.event_type == event_type)
+        {
+            let occurred_at = last
+                .entry(row.event.aggregate_id.to_string())
+                .or_insert(row.event.occurred_at);
+            *occurred_at = (*occurred_at).max(row.event.occurred_at);
+        }
+        Ok(last)
+    }
+}
@@ -0,0 +1,169 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, types::Uuid, PgConnection, Pool, Postgres};
+
+use crate::interface::repository::outbox::{
+    OutboxDatabaseTrait, OutboxRecord, OutboxStats, PrimitiveEvent,
+};
+
+pub struct PostgresOutboxDatabase {
+    pool: Arc<Pool<Postgres>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresOutboxRecord {
+    position: i64,
+    id: Uuid,
+    aggregate_id: String,
+    event_type: String,
+    payload: String,
+    occurred_at: DateTime<Utc>,
+    attempts: i32,
+}
+
+#[async_trait]
+impl OutboxDatabaseTrait for PostgresOutboxDatabase {
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+        max_attempts: u32,
+    ) -> Result<Vec<OutboxRecord>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let mut records = sqlx::query_as::<_, PostgresOutboxRecord>(
+            "
+update public.outbox set next_attempt_at = $2
+where position in (
+    select position from public.outbox
+    where published_at is null and next_attempt_at <= $1 and attempts < $3
+    order by position
+    limit $4
+    for update skip locked
+)
+returning position, id, aggregate_id, event_type, payload::text as payload, occurred_at, attempts;
+            ",
+        )
+        .bind(now)
+        .bind(lease_until)
+        .bind(max_attempts as i32)
+        .bind(limit as i64)
+        .fetch_all(&mut conn)
+        .await?;
+        records.sort_by_key(|r| r.position);
+
+        Ok(records
+            .into_iter()
+            .map(|r| OutboxRecord {
+                event: PrimitiveEvent {
+                    id: r.id.to_string(),
+                    event_type: r.event_type,
+                    aggregate_id: r.aggregate_id,
+                    payload: r.payload,
+                    occurred_at: r.occurred_at,
+                },
+                attempts: r.attempts as u32,
+            })
+            .collect())
+    }
+
+    async fn mark_published(&self, id: &str, published_at: DateTime<Utc>) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query("update public.outbox set published_at = $2 where id = $1")
+            .bind(Uuid::parse_str(id)?)
+            .bind(published_at)
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(&self, id: &str, error: &str, retry_at: DateTime<Utc>) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+update public.outbox set attempts = attempts + 1, last_error = $2, next_attempt_at = $3
+where id = $1;
+            ",
+        )
+        .bind(Uuid::parse_str(id)?)
+        .bind(error)
+        .bind(retry_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn stats(&self, max_attempts: u32) -> Result<OutboxStats> {
+        let mut conn = self.pool.acquire().await?;
+
+        let (pending, retrying, dead, published) = sqlx::query_as::<_, (i64, i64, i64, i64)>(
+            "
+select
+    count(*) filter (where published_at is null and attempts = 0),
+    count(*) filter (where published_at is null and attempts > 0 and attempts < $1),
+    count(*) filter (where published_at is null and attempts >= $1),
+    count(*) filter (where published_at is not null)
+from public.outbox;
+            ",
+        )
+        .bind(max_attempts as i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+        Ok(OutboxStats {
+            pending: pending as u64,
+            retrying: retrying as u64,
+            dead: dead as u64,
+            published: published as u64,
+        })
+    }
+
+    async fn last_occurred_at(&self, event_type: &str) -> Result<HashMap<String, DateTime<Utc>>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let data = sqlx::query_as::<_, (String, DateTime<Utc>)>(
+            "select aggregate_id, max(occurred_at) from public.outbox where event_type = $1 group by aggregate_id",
+        )
+        .bind(event_type)
+        .fetch_all(&mut conn)
+        .await?;
+
+        Ok(data.into_iter().collect())
+    }
+}
+
+impl PostgresOutboxDatabase {
+    pub fn new(pool: Arc<Pool<Postgres>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+
+    /// Appends events on the connection of the transaction that saves the
+    /// aggregate they belong to.
+    pub async fn append(conn: &mut PgConnection, events: &[PrimitiveEvent]) -> Result<()> {
+        for event in events {
+            sqlx::query(
+                "
+insert into public.outbox (id, aggregate_id, event_type, payload, occurred_at, next_attempt_at)
+values ($1, $2, $3, $4::jsonb, $5, $5);
+                ",
+            )
+            .bind(Uuid::parse_str(&event.id)?)
+            .bind(&event.aggregate_id)
+            .bind(&event.event_type)
+            .bind(&event.payload)
+            .bind(event.occurred_at)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,31 +0,0 @@
-use once_cell::sync::Lazy;
-
-#[derive(Debug)]
-pub struct Config {
-    postgres_host: String,
-    postgres_port: String,
-    postgres_user: String,
-    postgres_password: String,
-    postgres_database: String,
-}
-
-impl Config {
-    pub fn database_url(&self) -> String {
-        format!(
-            "postgres://{}:{}@{}:{}/{}",
-            self.postgres_user,
-            self.postgres_password,
-            self.postgres_host,
-            self.postgres_port,
-            self.postgres_database,
-        )
-    }
-}
-
-pub static DATABASE_CONFIG: Lazy<Config> = Lazy::new(|| Config {
-    postgres_host: std::env::var("POSTGRES_HOSTNAME").unwrap(),
-    postgres_port: std::env::var("POSTGRES_PORT").unwrap(),
-    postgres_user: std::env::var("POSTGRES_USER").unwrap(),
-    postgres_password: std::env::var("POSTGRES_PASSWORD").unwrap(),
-    postgres_database: std::env::var("POSTGRES_DB").unwrap(),
-});
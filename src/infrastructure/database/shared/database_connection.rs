@@ -0,0 +1,208 @@
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use anyhow::Result;
+use sqlx::{
+    postgres::PgPoolOptions,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+    Pool, Postgres, Sqlite,
+};
+
+use crate::{
+    infrastructure::{
+        config::{ClubStore, DatabaseBackend, DatabaseConfig},
+        database::{
+            api_key::{PostgresApiKeyDatabase, SqliteApiKeyDatabase},
+            audit::{PostgresAuditDatabase, SqliteAuditDatabase},
+            club::{PostgresClubDatabase, SqliteClubDatabase},
+            club_event::{PostgresClubEventStore, SqliteClubEventStore},
+            club_statistics::{PostgresClubStatisticsDatabase, SqliteClubStatisticsDatabase},
+            idempotency::{PostgresIdempotencyDatabase, SqliteIdempotencyDatabase},
+            outbox::{PostgresOutboxDatabase, SqliteOutboxDatabase},
+            user::{PostgresUserDatabase, SqliteUserDatabase},
+            webhook::{PostgresWebhookDatabase, SqliteWebhookDatabase},
+        },
+    },
+    interface::repository::{
+        api_key::ApiKeyDatabaseTrait,
+        audit::AuditDatabaseTrait,
+        club::{ClubDatabaseTraitWrapper, ClubEventStoreTrait, EventSourcedClubDatabase},
+        club_statistics::ClubStatisticsDatabaseTrait,
+        idempotency::IdempotencyDatabaseTrait,
+        outbox::OutboxDatabaseTrait,
+        user::UserDatabaseTraitWrapper,
+        webhook::WebhookDatabaseTrait,
+    },
+};
+
+#[derive(Clone)]
+pub enum DatabasePool {
+    Postgres(Arc<Pool<Postgres>>),
+    Sqlite(Arc<Pool<Sqlite>>),
+}
+
+#[derive(Clone)]
+pub struct DatabaseConnection {
+    pool: DatabasePool,
+    club_store: ClubStore,
+    club_snapshot_interval: u64,
+    user_retention: Duration,
+}
+
+impl DatabaseConnection {
+    pub fn new(pool: DatabasePool, config: &DatabaseConfig) -> Self {
+        Self {
+            pool,
+            club_store: config.club_store,
+            club_snapshot_interval: config.club_snapshot_interval,
+            user_retention: config.user_retention(),
+        }
+    }
+
+    pub async fn connect(config: &DatabaseConfig) -> Result<Self> {
+        let pool = match config.backend {
+            DatabaseBackend::Postgres => {
+                let pool = PgPoolOptions::new()
+                    .max_connections(config.max_connections)
+                    .min_connections(config.min_connections)
+                    .connect_timeout(config.connect_timeout())
+                    .idle_timeout(config.idle_timeout())
+                    .connect(&config.database_url())
+                    .await?;
+                DatabasePool::Postgres(Arc::new(pool))
+            }
+            DatabaseBackend::Sqlite => {
+                let options = SqliteConnectOptions::from_str(&config.database_url())?
+                    .create_if_missing(true)
+                    .foreign_keys(true);
+                let pool = SqlitePoolOptions::new()
+                    .max_connections(config.max_connections)
+                    .min_connections(config.min_connections)
+                    .connect_timeout(config.connect_timeout())
+                    .idle_timeout(config.idle_timeout())
+                    .connect_with(options)
+                    .await?;
+                DatabasePool::Sqlite(Arc::new(pool))
+            }
+        };
+
+        Ok(Self::new(pool, config))
+    }
+
+    pub fn pool(&self) -> &DatabasePool {
+        &self.pool
+    }
+
+    /// How long deleted users are kept before they are purged.
+    pub fn user_retention(&self) -> Duration {
+        self.user_retention
+    }
+
+    pub fn user_database(&self) -> Result<Box<dyn UserDatabaseTraitWrapper + Send + Sync>> {
+        match &self.pool {
+            DatabasePool::Postgres(pool) => {
+                Ok(Box::new(PostgresUserDatabase::new(Arc::clone(pool))?))
+            }
+            DatabasePool::Sqlite(pool) => Ok(Box::new(SqliteUserDatabase::new(Arc::clone(pool))?)),
+        }
+    }
+
+    /// The state-based or the event-sourced club store, as configured.
+    pub fn club_database(&self) -> Result<Box<dyn ClubDatabaseTraitWrapper + Send + Sync>> {
+        if self.club_store == ClubStore::Events {
+            return Ok(Box::new(self.event_sourced_club_database()?));
+        }
+
+        match &self.pool {
+            DatabasePool::Postgres(pool) => {
+                Ok(Box::new(PostgresClubDatabase::new(Arc::clone(pool))?))
+            }
+            DatabasePool::Sqlite(pool) => Ok(Box::new(SqliteClubDatabase::new(Arc::clone(pool))?)),
+        }
+    }
+
+    pub(super) fn event_sourced_club_database(&self) -> Result<EventSourcedClubDatabase> {
+        Ok(EventSourcedClubDatabase::new(
+            self.club_event_store()?,
+            self.club_snapshot_interval,
+        ))
+    }
+
+    pub fn club_event_store(&self) -> Result<Box<dyn ClubEventStoreTrait + Send + Sync>> {
+        match &self.pool {
+            DatabasePool::Postgres(pool) => {
+                Ok(Box::new(PostgresClubEventStore::new(Arc::clone(pool))?))
+            }
+            DatabasePool::Sqlite(pool) => {
+                Ok(Box::new(SqliteClubEventStore::new(Arc::clone(pool))?))
+            }
+        }
+    }
+
+    pub fn club_statistics_database(
+        &self,
+    ) -> Result<Box<dyn ClubStatisticsDatabaseTrait + Send + Sync>> {
+        match &self.pool {
+            DatabasePool::Postgres(pool) => Ok(Box::new(PostgresClubStatisticsDatabase::new(
+                Arc::clone(pool),
+            )?)),
+            DatabasePool::Sqlite(pool) => Ok(Box::new(SqliteClubStatisticsDatabase::new(
+                Arc::clone(pool),
+            )?)),
+        }
+    }
+
+    pub fn outbox_database(&self) -> Result<Box<dyn OutboxDatabaseTrait + Send + Sync>> {
+        match &self.pool {
+            DatabasePool::Postgres(pool) => {
+                Ok(Box::new(PostgresOutboxDatabase::new(Arc::clone(pool))?))
+            }
+            DatabasePool::Sqlite(pool) => {
+                Ok(Box::new(SqliteOutboxDatabase::new(Arc::clone(pool))?))
+            }
+        }
+    }
+
+    pub fn api_key_database(&self) -> Result<Box<dyn ApiKeyDatabaseTrait + Send + Sync>> {
+        match &self.pool {
+            DatabasePool::Postgres(pool) => {
+                Ok(Box::new(PostgresApiKeyDatabase::new(Arc::clone(pool))?))
+            }
+            DatabasePool::Sqlite(pool) => {
+                Ok(Box::new(SqliteApiKeyDatabase::new(Arc::clone(pool))?))
+            }
+        }
+    }
+
+    pub fn audit_database(&self) -> Result<Box<dyn AuditDatabaseTrait + Send + Sync>> {
+        match &self.pool {
+            DatabasePool::Postgres(pool) => {
+                Ok(Box::new(PostgresAuditDatabase::new(Arc::clone(pool))?))
+            }
+            DatabasePool::Sqlite(pool) => Ok(Box::new(SqliteAuditDatabase::new(Arc::clone(pool))?)),
+        }
+    }
+
+    /// Where idempotency keys are kept: the `idempotency_key` table of either
+    /// database.
+    pub fn idempotency_database(&self) -> Result<Box<dyn IdempotencyDatabaseTrait + Send + Sync>> {
+        match &self.pool {
+            DatabasePool::Postgres(pool) => Ok(Box::new(PostgresIdempotencyDatabase::new(
+                Arc::clone(pool),
+            )?)),
+            DatabasePool::Sqlite(pool) => {
+                Ok(Box::new(SqliteIdempotencyDatabase::new(Arc::clone(pool))?))
+            }
+        }
+    }
+
+    pub fn webhook_database(&self) -> Result<Box<dyn WebhookDatabaseTrait + Send + Sync>> {
+        match &self.pool {
+            DatabasePool::Postgres(pool) => {
+                Ok(Box::new(PostgresWebhookDatabase::new(Arc::clone(pool))?))
+            }
+            DatabasePool::Sqlite(pool) => {
+                Ok(Box::new(SqliteWebhookDatabase::new(Arc::clone(pool))?))
+            }
+        }
+    }
+}
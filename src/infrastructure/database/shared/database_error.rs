@@ -0,0 +1,45 @@
+use crate::domain::model::error::DomainError;
+
+// Postgres reports the constraint name, SQLite only mentions it in the message.
+// Users and members are looked up before a club is saved, so a foreign key
+// violation can only come from deleting a user who still owns a club.
+const CONSTRAINT_VIOLATIONS: [(&str, &str); 12] = [
+    ("user_name_key", "User already exists"),
+    ("user.name", "User already exists"),
+    ("club_name_key", "Club already exists"),
+    ("club.name", "Club already exists"),
+    ("club_view_name_key", "Club already exists"),
+    ("club_view.name", "Club already exists"),
+    (
+        "club_members_owner_check",
+        "The owner of a club cannot be a member",
+    ),
+    ("club_owner_check", "The owner of a club cannot be a member"),
+    ("club_owner_fkey", "The user still owns a club"),
+    ("club_event_pkey", CLUB_CHANGED),
+    ("club_event.club_id", CLUB_CHANGED),
+    (
+        "FOREIGN KEY constraint failed",
+        "The user still owns a club",
+    ),
+];
+
+/// Reported when a club stream grew after the club was loaded.
+pub const CLUB_CHANGED: &str = "The club was changed by someone else. Reload it and try again.";
+
+pub fn map_constraint_violation(error: sqlx::Error) -> anyhow::Error {
+    if let Some(database_error) = error.as_database_error() {
+        let constraint = database_error
+            .constraint()
+            .unwrap_or_else(|| database_error.message());
+
+        if let Some((_, message)) = CONSTRAINT_VIOLATIONS
+            .iter()
+            .find(|(key, _)| constraint.contains(key))
+        {
+            return DomainError::Conflict(message.to_string()).into();
+        }
+    }
+
+    error.into()
+}
@@ -0,0 +1,391 @@
+use anyhow::{anyhow, Result};
+use sqlx::migrate::{AppliedMigration, Migrate, Migration, Migrator};
+use sqlx::{Executor, Pool, Postgres, Sqlite};
+
+use super::{DatabaseConnection, DatabasePool};
+
+pub static POSTGRES_MIGRATOR: Migrator = sqlx::migrate!("./sql/postgres");
+pub static SQLITE_MIGRATOR: Migrator = sqlx::migrate!("./sql/sqlite");
+
+/// Migrations replaced by the consolidated schema. Databases that recorded them
+/// are upgraded by the bridge scripts and then adopt the baseline version.
+static LEGACY_POSTGRES_MIGRATOR: Migrator = sqlx::migrate!("./sql/legacy/postgres");
+static LEGACY_SQLITE_MIGRATOR: Migrator = sqlx::migrate!("./sql/legacy/sqlite");
+static POSTGRES_BRIDGE: &str = include_str!("../../../../sql/legacy/postgres_bridge.sql");
+static SQLITE_BRIDGE: &str = include_str!("../../../../sql/legacy/sqlite_bridge.sql");
+const BASELINE_VERSION: i64 = 20261019120000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    Applied,
+    Pending,
+    Modified,
+    Missing,
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub state: MigrationState,
+}
+
+impl DatabaseConnection {
+    pub async fn run_migrations(&self) -> Result<()> {
+        match self.pool() {
+            DatabasePool::Postgres(pool) => {
+                adopt_legacy_postgres(pool).await?;
+                POSTGRES_MIGRATOR.run(&**pool).await?
+            }
+            DatabasePool::Sqlite(pool) => {
+                adopt_legacy_sqlite(pool).await?;
+                SQLITE_MIGRATOR.run(&**pool).await?
+            }
+        }
+        // Clubs saved to the event store before it kept their views.
+        self.event_sourced_club_database()?
+            .fill_missing_views()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn migration_status(&self) -> Result<Vec<MigrationStatus>> {
+        let (migrator, legacy, dirty_version, applied) = match self.pool() {
+            DatabasePool::Postgres(pool) => {
+                let mut conn = pool.acquire().await?;
+                conn.ensure_migrations_table().await?;
+                let dirty_version = conn.dirty_version().await?;
+                let applied = conn.list_applied_migrations().await?;
+                (
+                    &POSTGRES_MIGRATOR,
+                    &LEGACY_POSTGRES_MIGRATOR,
+                    dirty_version,
+                    applied,
+                )
+            }
+            DatabasePool::Sqlite(pool) => {
+                let mut conn = pool.acquire().await?;
+                conn.ensure_migrations_table().await?;
+                let dirty_version = conn.dirty_version().await?;
+                let applied = conn.list_applied_migrations().await?;
+                (
+                    &SQLITE_MIGRATOR,
+                    &LEGACY_SQLITE_MIGRATOR,
+                    dirty_version,
+                    applied,
+                )
+            }
+        };
+
+        if let Some(version) = dirty_version {
+            return Err(anyhow!(
+                "Migration {} was partially applied. Fix the database manually.",
+                version
+            ));
+        }
+
+        Ok(compare_migrations(migrator, legacy, applied))
+    }
+
+    pub async fn verify_migrations(&self) -> Result<()> {
+        let not_applied = self
+            .migration_status()
+            .await?
+            .into_iter()
+            .filter(|m| m.state != MigrationState::Applied)
+            .map(|m| format!("{} {} ({:?})", m.version, m.description, m.state))
+            .collect::<Vec<String>>();
+
+        if not_applied.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "The database schema is not up to date. Run `migrate run` first: {}",
+                not_applied.join(", ")
+            ))
+        }
+    }
+}
+
+/// Upgrades a database still on the legacy Postgres history to the baseline and
+/// replaces the legacy version rows by the baseline one, in one transaction.
+async fn adopt_legacy_postgres(pool: &Pool<Postgres>) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    tx.ensure_migrations_table().await?;
+    let applied = tx.list_applied_migrations().await?;
+    let legacy = legacy_versions(&LEGACY_POSTGRES_MIGRATOR, &applied);
+    if legacy.is_empty() {
+        return Ok(());
+    }
+
+    tx.execute(POSTGRES_BRIDGE).await?;
+    let baseline = baseline_migration(&POSTGRES_MIGRATOR)?;
+    sqlx::query("INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) VALUES ($1, $2, TRUE, $3, 0)")
+        .bind(baseline.version)
+        .bind(&*baseline.description)
+        .bind(&*baseline.checksum)
+        .execute(&mut tx)
+        .await?;
+    sqlx::query("DELETE FROM _sqlx_migrations WHERE version = ANY($1)")
+        .bind(&legacy)
+        .execute(&mut tx)
+        .await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// The SQLite counterpart of `adopt_legacy_postgres`.
+async fn adopt_legacy_sqlite(pool: &Pool<Sqlite>) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    tx.ensure_migrations_table().await?;
+    let applied = tx.list_applied_migrations().await?;
+    let legacy = legacy_versions(&LEGACY_SQLITE_MIGRATOR, &applied);
+    if legacy.is_empty() {
+        return Ok(());
+    }
+
+    tx.execute(SQLITE_BRIDGE).await?;
+    let baseline = baseline_migration(&SQLITE_MIGRATOR)?;
+    sqlx::query("INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) VALUES (?, ?, TRUE, ?, 0)")
+        .bind(baseline.version)
+        .bind(&*baseline.description)
+        .bind(&*baseline.checksum)
+        .execute(&mut tx)
+        .await?;
+    for version in legacy {
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = ?")
+            .bind(version)
+            .execute(&mut tx)
+            .await?;
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// The legacy versions recorded in a database that has not adopted the baseline yet.
+fn legacy_versions(legacy: &Migrator, applied: &[AppliedMigration]) -> Vec<i64> {
+    if applied.iter().any(|a| a.version == BASELINE_VERSION) {
+        return Vec::new();
+    }
+
+    applied
+        .iter()
+        .map(|a| a.version)
+        .filter(|version| legacy.iter().any(|m| m.version == *version))
+        .collect()
+}
+
+fn baseline_migration(migrator: &Migrator) -> Result<&Migration> {
+    migrator
+        .iter()
+        .find(|m| m.version == BASELINE_VERSION)
+        .ok_or_else(|| anyhow!("Baseline migration {} is missing", BASELINE_VERSION))
+}
+
+fn compare_migrations(
+    migrator: &Migrator,
+    legacy: &Migrator,
+    applied: Vec<AppliedMigration>,
+) -> Vec<MigrationStatus> {
+    let legacy_versions = legacy_versions(legacy, &applied);
+
+    let mut statuses = migrator
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| {
+            let state = match applied.iter().find(|a| a.version == m.version) {
+                Some(a) if a.checksum == m.checksum => MigrationState::Applied,
+                Some(_) => MigrationState::Modified,
+                None => MigrationState::Pending,
+            };
+            MigrationStatus {
+                version: m.version,
+                description: m.description.to_string(),
+                state,
+            }
+        })
+        .collect::<Vec<MigrationStatus>>();
+
+    applied
+        .iter()
+        .filter(|a| migrator.iter().all(|m| m.version != a.version))
+        // Adopted by `migrate run`, which reports the baseline as pending meanwhile.
+        .filter(|a| !legacy_versions.contains(&a.version))
+        .for_each(|a| {
+            statuses.push(MigrationStatus {
+                version: a.version,
+                description: String::new(),
+                state: MigrationState::Missing,
+            })
+        });
+
+    statuses.sort_by_key(|m| m.version);
+    statuses
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use sqlx::migrate::Migrator;
+    use sqlx::postgres::PgPoolOptions;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use super::{
+        DatabaseConnection, DatabasePool, MigrationState, LEGACY_POSTGRES_MIGRATOR,
+        LEGACY_SQLITE_MIGRATOR, SQLITE_MIGRATOR,
+    };
+    use crate::infrastructure::config::DatabaseConfig;
+
+    #[tokio::test]
+    async fn reports_pending_migrations_until_applied() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let connection = DatabaseConnection::new(
+            DatabasePool::Sqlite(Arc::new(pool)),
+            &DatabaseConfig::default(),
+        );
+
+        let status = connection.migration_status().await.unwrap();
+        assert!(!status.is_empty());
+        assert!(status.iter().all(|m| m.state == MigrationState::Pending));
+        assert!(connection.verify_migrations().await.is_err());
+
+        connection.run_migrations().await.unwrap();
+
+        let status = connection.migration_status().await.unwrap();
+        assert!(status.iter().all(|m| m.state == MigrationState::Applied));
+        assert!(connection.verify_migrations().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn upgrades_a_database_created_from_the_legacy_schema() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        LEGACY_SQLITE_MIGRATOR.run(&pool).await.unwrap();
+        sqlx::query("INSERT INTO user (id, name) VALUES ('u1', 'alice'), ('u2', 'bob')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO club (id, name, owner) VALUES ('c1', 'chess', 'u1')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO club_members (club_id, user_id) VALUES ('c1', 'u1'), ('c1', 'u2')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let connection = DatabaseConnection::new(
+            DatabasePool::Sqlite(Arc::new(pool.clone())),
+            &DatabaseConfig::default(),
+        );
+
+        let status = connection.migration_status().await.unwrap();
+        assert!(status.iter().all(|m| m.state == MigrationState::Pending));
+        assert!(connection.verify_migrations().await.is_err());
+
+        connection.run_migrations().await.unwrap();
+        // Adopting the legacy rows is idempotent.
+        connection.run_migrations().await.unwrap();
+
+        let status = connection.migration_status().await.unwrap();
+        assert!(status.iter().all(|m| m.state == MigrationState::Applied));
+        let members: Vec<(String,)> = sqlx::query_as("SELECT user_id FROM club_members")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(members, vec![("u2".to_string(),)]);
+        assert!(
+            sqlx::query("INSERT INTO user (id, name) VALUES ('u3', 'alice')")
+                .execute(&pool)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn backfills_premium_since_for_premium_users() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let before_profile = Migrator {
+            migrations: SQLITE_MIGRATOR
+                .iter()
+                .filter(|m| m.version < 20261019140000)
+                .cloned()
+                .collect(),
+            ignore_missing: false,
+        };
+        before_profile.run(&pool).await.unwrap();
+        sqlx::query("INSERT INTO user (id, name, is_premium) VALUES ('u1', 'alice', TRUE), ('u2', 'bob', FALSE)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        SQLITE_MIGRATOR.run(&pool).await.unwrap();
+
+        let users: Vec<(String, bool)> = sqlx::query_as(
+            "SELECT id, premium_since IS NOT NULL AND premium_since = created_at FROM user ORDER BY id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            users,
+            vec![("u1".to_string(), true), ("u2".to_string(), false)]
+        );
+    }
+
+    /// Needs an empty Postgres database it may wipe, e.g.
+    /// `TEST_POSTGRES_URL=postgres://postgres:x@localhost/ddd_test cargo test -- --ignored`.
+    #[tokio::test]
+    #[ignore]
+    async fn upgrades_a_postgres_database_created_from_the_legacy_schema() {
+        let url = std::env::var("TEST_POSTGRES_URL").expect("TEST_POSTGRES_URL is not set");
+        let pool = PgPoolOptions::new().connect(&url).await.unwrap();
+        sqlx::query("DROP SCHEMA public CASCADE")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("CREATE SCHEMA public")
+            .execute(&pool)
+            .await
+            .unwrap();
+        LEGACY_POSTGRES_MIGRATOR.run(&pool).await.unwrap();
+        sqlx::query("INSERT INTO public.user (id, name) VALUES ('00000000-0000-0000-0000-000000000001', 'alice')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO public.club (id, name, owner) VALUES ('00000000-0000-0000-0000-000000000002', 'chess', '00000000-0000-0000-0000-000000000001')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let connection = DatabaseConnection::new(
+            DatabasePool::Postgres(Arc::new(pool.clone())),
+            &DatabaseConfig::default(),
+        );
+
+        assert!(connection.verify_migrations().await.is_err());
+        connection.run_migrations().await.unwrap();
+
+        assert!(connection.verify_migrations().await.is_ok());
+        let (is_premium,): (bool,) = sqlx::query_as("SELECT is_premium FROM public.user")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(!is_premium);
+    }
+}
@@ -1,3 +1,5 @@
-mod database_config;
+mod database_connection;
+mod database_error;
+mod database_migrator;
 
-pub use self::database_config::*;
+pub use self::{database_connection::*, database_error::*, database_migrator::*};
@@ -1,9 +1,19 @@
 use std::collections::HashMap;
 
-use crate::interface::repository::user::UserDatabaseTrait;
-
-use anyhow::{anyhow, Ok, Result};
+use crate::domain::model::error::DomainError;
+use crate::domain::model::user::repository::{SortOrder, UserSortKey};
+use crate::infrastructure::database::{
+    audit::InMemoryAuditDatabase, outbox::InMemoryOutboxDatabase,
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    outbox::PrimitiveEvent,
+    user::{PrimitiveUser, UserDatabaseTrait, UserPageQuery},
+};
+
+use anyhow::{Ok, Result};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
 use tokio::sync::Mutex;
 
@@ -12,24 +22,7 @@ static STATIC_USER_TABLE: Lazy<Mutex<UserTable>> = Lazy::new(|| {
     Mutex::new(table)
 });
 
-#[derive(Clone, Debug)]
-struct UserRow {
-    id: String,
-    name: String,
-    is_premium: bool,
-}
-
-impl UserRow {
-    pub fn new(id: &str, name: &str) -> Self {
-        Self {
-            id: id.to_string(),
-            name: name.to_string(),
-            is_premium: false,
-        }
-    }
-}
-
-type UserTable = HashMap<String, UserRow>;
+type UserTable = HashMap<String, PrimitiveUser>;
 
 pub struct InMemoryUserDatabase {}
 
@@ -44,7 +37,7 @@ impl UserDatabaseTrait for InMemoryUserDatabase {
     type UserId = String;
     type UserName = String;
     type UserIsPremium = bool;
-    type UserData = (Self::UserId, Self::UserName, Self::UserIsPremium);
+    type UserData = PrimitiveUser;
 
     fn from_user_id(id: &Self::UserId) -> Result<String> {
         Ok(id.to_string())
@@ -55,8 +48,8 @@ impl UserDatabaseTrait for InMemoryUserDatabase {
     fn from_user_is_premium(is_premium: Self::UserIsPremium) -> Result<bool> {
         Ok(is_premium)
     }
-    fn from_user_data(user: &Self::UserData) -> Result<(String, String, bool)> {
-        Ok((user.0.to_owned(), user.1.to_owned(), user.2))
+    fn from_user_data(user: &Self::UserData) -> Result<PrimitiveUser> {
+        Ok(user.clone())
     }
 
     fn to_user_id(value: &str) -> Result<Self::UserId> {
@@ -68,54 +61,118 @@ impl UserDatabaseTrait for InMemoryUserDatabase {
     fn to_user_is_premium(value: bool) -> Result<bool> {
         Ok(value)
     }
-    fn to_user_data(id: &str, name: &str, is_premium: bool) -> Result<Self::UserData> {
-        Ok((id.to_string(), name.to_string(), is_premium))
+    fn to_user_data(user: &PrimitiveUser) -> Result<Self::UserData> {
+        Ok(user.clone())
     }
 
-    async fn save(&self, user: &Self::UserData) -> Result<()> {
-        let row = UserRow::new(&user.0, &user.1);
+    async fn save(
+        &self,
+        user: &Self::UserData,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()> {
+        let row = user.clone();
         let mut table = STATIC_USER_TABLE.lock().await;
+        if table.values().any(|r| r.name == row.name && r.id != row.id) {
+            return Err(DomainError::Conflict("User already exists".to_string()).into());
+        }
         table.insert(row.clone().id, row);
+        InMemoryOutboxDatabase::append(events).await;
+        if let Some(entry) = audit {
+            InMemoryAuditDatabase::append(entry).await;
+        }
 
         Ok(())
     }
 
-    async fn find(&self, user_name: &Self::UserName) -> Result<Self::UserData> {
+    async fn find(&self, user_name: &Self::UserName) -> Result<Option<Self::UserData>> {
         let table = STATIC_USER_TABLE.lock().await;
-        table
+        Ok(table
             .iter()
-            .find(|row| row.1.name == *user_name)
-            .map(|row| {
-                let row = row.1.clone();
-                (row.id, row.name, row.is_premium)
-            })
-            .ok_or_else(|| anyhow!("User not found"))
+            .find(|row| row.1.name == *user_name && row.1.deleted_at.is_none())
+            .map(|row| row.1.clone()))
+    }
+
+    async fn find_deleted_by_id(&self, id: &Self::UserId) -> Result<Option<Self::UserData>> {
+        let table = STATIC_USER_TABLE.lock().await;
+        Ok(table
+            .get(id)
+            .filter(|row| row.deleted_at.is_some())
+            .cloned())
+    }
+
+    async fn find_deleted_before(
+        &self,
+        deleted_before: DateTime<Utc>,
+    ) -> Result<Vec<Self::UserId>> {
+        let table = STATIC_USER_TABLE.lock().await;
+        let mut rows = table
+            .values()
+            .filter(|row| row.deleted_at.is_some_and(|t| t < deleted_before))
+            .collect::<Vec<_>>();
+        rows.sort_by(|a, b| (a.deleted_at, &a.id).cmp(&(b.deleted_at, &b.id)));
+
+        Ok(rows.iter().map(|row| row.id.to_owned()).collect())
     }
 
-    async fn delete(&self, user_id: &Self::UserId) -> Result<()> {
+    async fn purge(&self, id: &Self::UserId) -> Result<()> {
         let mut table = STATIC_USER_TABLE.lock().await;
-        table.remove(user_id);
+        if table.get(id).is_some_and(|row| row.deleted_at.is_some()) {
+            table.remove(id);
+        }
 
         Ok(())
     }
 
-    async fn find_by_id(&self, id: &Self::UserId) -> Result<Self::UserData> {
+    async fn find_by_id(&self, id: &Self::UserId) -> Result<Option<Self::UserData>> {
         let table = STATIC_USER_TABLE.lock().await;
-        table
+        Ok(table
             .get(id)
-            .map(|row| (row.id.to_owned(), row.name.to_owned(), row.is_premium))
-            .ok_or_else(|| anyhow!("User not found"))
+            .filter(|row| row.deleted_at.is_none())
+            .cloned())
     }
 
     async fn batch_find(&self, users: Vec<Self::UserId>) -> Result<Vec<Self::UserData>> {
         let table = STATIC_USER_TABLE.lock().await;
         Ok(table
             .iter()
-            .filter(|u| users.contains(u.0))
-            .map(|row| {
-                let row = row.1.clone();
-                (row.id, row.name, row.is_premium)
-            })
+            .filter(|u| users.contains(u.0) && u.1.deleted_at.is_none())
+            .map(|row| row.1.clone())
             .collect())
     }
+
+    async fn find_page(&self, query: &UserPageQuery<Self::UserId>) -> Result<Vec<Self::UserData>> {
+        let table = STATIC_USER_TABLE.lock().await;
+        let key = |row: &PrimitiveUser| match query.sort_key {
+            UserSortKey::Id => (String::new(), row.id.to_owned()),
+            UserSortKey::Name => (row.name.to_owned(), row.id.to_owned()),
+        };
+        let after = query.after.as_ref().map(|(id, name)| match query.sort_key {
+            UserSortKey::Id => (String::new(), id.to_owned()),
+            UserSortKey::Name => (name.to_owned(), id.to_owned()),
+        });
+
+        let mut rows = table
+            .values()
+            .filter(|row| row.deleted_at.is_none())
+            .filter(|row| query.is_premium.is_none_or(|p| row.is_premium == p))
+            .filter(|row| {
+                query
+                    .name_prefix
+                    .as_ref()
+                    .is_none_or(|prefix| row.name.starts_with(prefix))
+            })
+            .filter(|row| match (&after, query.sort_order) {
+                (None, _) => true,
+                (Some(after), SortOrder::Asc) => key(row) > *after,
+                (Some(after), SortOrder::Desc) => key(row) < *after,
+            })
+            .collect::<Vec<&PrimitiveUser>>();
+        rows.sort_by_key(|row| key(row));
+        if query.sort_order == SortOrder::Desc {
+            rows.reverse();
+        }
+
+        Ok(rows.into_iter().take(query.limit).cloned().collect())
+    }
 }
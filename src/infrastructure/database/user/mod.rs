@@ -1,7 +1,9 @@
+#[cfg(test)]
 mod in_memory;
+mod page_sql;
 mod postgres;
+mod sqlite;
 
-pub use self::{
-    in_memory::*,
-    postgres::*,
-};
\ No newline at end of file
+#[cfg(test)]
+pub use self::in_memory::*;
+pub use self::{postgres::*, sqlite::*};
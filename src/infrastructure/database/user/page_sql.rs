@@ -0,0 +1,58 @@
+use crate::domain::model::user::repository::{SortOrder, UserSortKey};
+use crate::interface::repository::user::UserPageQuery;
+
+/// Builds the `where`, `order by` and `limit` clauses of the keyset query for
+/// a page. Parameters are written as `sigil` followed by their number, e.g.
+/// `$1` for Postgres or `?1` for SQLite, and numbered in the order
+/// `find_page` binds them: premium flag, name prefix, cursor id, cursor name
+/// and finally the limit.
+pub fn page_sql<T>(query: &UserPageQuery<T>, sigil: char) -> String {
+    let mut conditions = vec!["deleted_at is null".to_string()];
+    let mut params = 0;
+
+    if query.is_premium.is_some() {
+        params += 1;
+        conditions.push(format!("is_premium = {}{}", sigil, params));
+    }
+    if query.name_prefix.is_some() {
+        params += 1;
+        conditions.push(format!(
+            "substr(name, 1, length({s}{p})) = {s}{p}",
+            s = sigil,
+            p = params
+        ));
+    }
+    let (operator, direction) = match query.sort_order {
+        SortOrder::Asc => (">", "asc"),
+        SortOrder::Desc => ("<", "desc"),
+    };
+    if query.after.is_some() {
+        match query.sort_key {
+            UserSortKey::Id => {
+                params += 1;
+                conditions.push(format!("id {} {}{}", operator, sigil, params));
+            }
+            UserSortKey::Name => {
+                params += 2;
+                conditions.push(format!(
+                    "(name, id) {} ({s}{}, {s}{})",
+                    operator,
+                    params,
+                    params - 1,
+                    s = sigil
+                ));
+            }
+        }
+    }
+    let order = match query.sort_key {
+        UserSortKey::Id => format!("id {}", direction),
+        UserSortKey::Name => format!("name {0}, id {0}", direction),
+    };
+    format!(
+        "where {} order by {} limit {}{}",
+        conditions.join(" and "),
+        order,
+        sigil,
+        params + 1
+    )
+}
@@ -1,21 +1,45 @@
 use std::sync::Arc;
 
-use crate::interface::repository::user::UserDatabaseTrait;
-
-use anyhow::{anyhow, Result};
+use crate::domain::model::{error::DomainError, user::repository::UserSortKey};
+use crate::infrastructure::database::{
+    audit::PostgresAuditDatabase, outbox::PostgresOutboxDatabase, shared::map_constraint_violation,
+    user::page_sql::page_sql,
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    outbox::PrimitiveEvent,
+    user::{PrimitiveUser, UserDatabaseTrait, UserPageQuery},
+};
+
+use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::{self, types::Uuid, Pool, Postgres};
 
 pub struct PostgresUserDatabase {
     pool: Arc<Pool<Postgres>>,
 }
 
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PostgresUserRecord {
+    id: Uuid,
+    name: String,
+    is_premium: bool,
+    premium_since: Option<DateTime<Utc>>,
+    premium_expires_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+const USER_COLUMNS: &str =
+    "id, name, is_premium, premium_since, premium_expires_at, created_at, deleted_at";
+
 #[async_trait]
 impl UserDatabaseTrait for PostgresUserDatabase {
     type UserId = Uuid;
     type UserName = String;
     type UserIsPremium = bool;
-    type UserData = (Self::UserId, Self::UserName, Self::UserIsPremium);
+    type UserData = PostgresUserRecord;
 
     fn from_user_id(id: &Self::UserId) -> Result<String> {
         Ok(id.to_string())
@@ -26,12 +50,23 @@ impl UserDatabaseTrait for PostgresUserDatabase {
     fn from_user_is_premium(is_premium: Self::UserIsPremium) -> Result<bool> {
         Ok(is_premium)
     }
-    fn from_user_data(user: &Self::UserData) -> Result<(String, String, bool)> {
-        Ok((user.0.to_string(), user.1.to_owned(), user.2))
+    fn from_user_data(user: &Self::UserData) -> Result<PrimitiveUser> {
+        Ok(PrimitiveUser {
+            id: user.id.to_string(),
+            name: user.name.to_owned(),
+            is_premium: user.is_premium,
+            premium_since: user.premium_since,
+            premium_expires_at: user.premium_expires_at,
+            created_at: user.created_at,
+            deleted_at: user.deleted_at,
+        })
     }
 
     fn to_user_id(value: &str) -> Result<Self::UserId> {
-        Uuid::parse_str(value).map_err(|e| anyhow!(e.to_string()))
+        Uuid::parse_str(value).map_err(|e| {
+            DomainError::invalid_field("user_id", "uuid", &format!("Invalid id `{}`: {}", value, e))
+                .into()
+        })
     }
     fn to_user_name(value: &str) -> Result<Self::UserName> {
         Ok(value.to_string())
@@ -39,66 +74,123 @@ impl UserDatabaseTrait for PostgresUserDatabase {
     fn to_user_is_premium(value: bool) -> Result<Self::UserIsPremium> {
         Ok(value)
     }
-    fn to_user_data(id: &str, name: &str, is_premium: bool) -> Result<Self::UserData> {
-        let id = Uuid::parse_str(id)?;
-        Ok((id, name.to_string(), is_premium))
+    fn to_user_data(user: &PrimitiveUser) -> Result<Self::UserData> {
+        Ok(PostgresUserRecord {
+            id: Uuid::parse_str(&user.id)?,
+            name: user.name.to_owned(),
+            is_premium: user.is_premium,
+            premium_since: user.premium_since,
+            premium_expires_at: user.premium_expires_at,
+            created_at: user.created_at,
+            deleted_at: user.deleted_at,
+        })
     }
 
-    async fn save(&self, user: &Self::UserData) -> Result<()> {
-        let mut conn = self.pool.acquire().await?;
-
-        let user_name = user.1.to_string();
-        let user_id = user.0;
-        let is_premium = user.2;
+    async fn save(
+        &self,
+        user: &Self::UserData,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
 
         sqlx::query(
             "
-insert into public.user (id, name, is_premium) values ($1, $2, $3)
-on conflict on constraint user_id_key
+insert into public.user (id, name, is_premium, premium_since, premium_expires_at, created_at, deleted_at)
+values ($1, $2, $3, $4, $5, $6, $7)
+on conflict (id)
 do
-update set name = $2, is_premium = $3; 
+update set name = $2, is_premium = $3, premium_since = $4, premium_expires_at = $5, deleted_at = $7;
             ",
         )
-        .bind(user_id)
-        .bind(user_name)
-        .bind(is_premium)
-        .execute(&mut conn)
-        .await?;
+        .bind(user.id)
+        .bind(&user.name)
+        .bind(user.is_premium)
+        .bind(user.premium_since)
+        .bind(user.premium_expires_at)
+        .bind(user.created_at)
+        .bind(user.deleted_at)
+        .execute(&mut tx)
+        .await
+        .map_err(map_constraint_violation)?;
+
+        PostgresOutboxDatabase::append(&mut tx, events).await?;
+        if let Some(entry) = audit {
+            PostgresAuditDatabase::append(&mut tx, entry).await?;
+        }
+        tx.commit().await?;
 
         Ok(())
     }
 
-    async fn find(&self, user_name: &Self::UserName) -> Result<Self::UserData> {
+    async fn find(&self, user_name: &Self::UserName) -> Result<Option<Self::UserData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from public.user where name = $1 and deleted_at is null;",
+            USER_COLUMNS
+        );
+        let data = sqlx::query_as::<_, Self::UserData>(&query)
+            .bind(user_name)
+            .fetch_optional(&mut conn)
+            .await?;
+
+        Ok(data)
+    }
+
+    async fn find_deleted_by_id(&self, id: &Self::UserId) -> Result<Option<Self::UserData>> {
         let mut conn = self.pool.acquire().await?;
 
-        let data =
-            sqlx::query_as::<_, Self::UserData>("select * from public.user where name = $1;")
-                .bind(user_name)
-                .fetch_one(&mut conn)
-                .await?;
+        let query = format!(
+            "select {} from public.user where id = $1 and deleted_at is not null;",
+            USER_COLUMNS
+        );
+        let data = sqlx::query_as::<_, Self::UserData>(&query)
+            .bind(id)
+            .fetch_optional(&mut conn)
+            .await?;
 
         Ok(data)
     }
 
-    async fn delete(&self, user_id: &Self::UserId) -> Result<()> {
+    async fn find_deleted_before(
+        &self,
+        deleted_before: DateTime<Utc>,
+    ) -> Result<Vec<Self::UserId>> {
         let mut conn = self.pool.acquire().await?;
 
-        sqlx::query("delete from public.user where id = $1")
-            .bind(user_id)
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            "select id from public.user where deleted_at < $1 order by deleted_at, id;",
+        )
+        .bind(deleted_before)
+        .fetch_all(&mut conn)
+        .await?;
+
+        Ok(ids)
+    }
+
+    async fn purge(&self, id: &Self::UserId) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query("delete from public.user where id = $1 and deleted_at is not null;")
+            .bind(id)
             .execute(&mut conn)
             .await?;
 
         Ok(())
     }
 
-    async fn find_by_id(&self, id: &Self::UserId) -> Result<Self::UserData> {
+    async fn find_by_id(&self, id: &Self::UserId) -> Result<Option<Self::UserData>> {
         let mut conn = self.pool.acquire().await?;
 
-        let data =
-            sqlx::query_as::<_, Self::UserData>("select * from public.user where id::text = $1;")
-                .bind(id.to_string())
-                .fetch_one(&mut conn)
-                .await?;
+        let query = format!(
+            "select {} from public.user where id = $1 and deleted_at is null;",
+            USER_COLUMNS
+        );
+        let data = sqlx::query_as::<_, Self::UserData>(&query)
+            .bind(id)
+            .fetch_optional(&mut conn)
+            .await?;
 
         Ok(data)
     }
@@ -110,18 +202,43 @@ update set name = $2, is_premium = $3;
             return Ok(Vec::new());
         }
 
-        let params = users
-            .iter()
-            .map(|u| format!("'{}'", u))
-            .collect::<Vec<String>>()
-            .join(", ");
-        let query = format!("select * from public.user where id in ({})", params);
+        let query = format!(
+            "select {} from public.user where id = any($1) and deleted_at is null",
+            USER_COLUMNS
+        );
         let data = sqlx::query_as::<_, Self::UserData>(&query)
+            .bind(&users)
             .fetch_all(&mut conn)
             .await?;
 
         Ok(data)
     }
+
+    async fn find_page(&self, query: &UserPageQuery<Self::UserId>) -> Result<Vec<Self::UserData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let sql = format!(
+            "select {} from public.user {}",
+            USER_COLUMNS,
+            page_sql(query, '$')
+        );
+        let mut page = sqlx::query_as::<_, Self::UserData>(&sql);
+        if let Some(is_premium) = query.is_premium {
+            page = page.bind(is_premium);
+        }
+        if let Some(prefix) = &query.name_prefix {
+            page = page.bind(prefix);
+        }
+        if let Some((id, name)) = &query.after {
+            page = page.bind(id);
+            if query.sort_key == UserSortKey::Name {
+                page = page.bind(name);
+            }
+        }
+        let data = page.bind(query.limit as i64).fetch_all(&mut conn).await?;
+
+        Ok(data)
+    }
 }
 
 impl PostgresUserDatabase {
@@ -1,4 +1,4 @@
 mod dao;
 mod model;
 
-pub use self::{dao::*, model::*};
+pub use self::dao::*;
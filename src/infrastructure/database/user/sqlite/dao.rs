@@ -0,0 +1,422 @@
+use std::sync::Arc;
+
+use crate::domain::model::user::repository::UserSortKey;
+use crate::infrastructure::database::{
+    audit::SqliteAuditDatabase, outbox::SqliteOutboxDatabase, shared::map_constraint_violation,
+    user::page_sql::page_sql,
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    outbox::PrimitiveEvent,
+    user::{PrimitiveUser, UserDatabaseTrait, UserPageQuery},
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, Pool, Sqlite};
+
+pub struct SqliteUserDatabase {
+    pool: Arc<Pool<Sqlite>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SqliteUserRecord {
+    id: String,
+    name: String,
+    is_premium: bool,
+    premium_since: Option<DateTime<Utc>>,
+    premium_expires_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+const USER_COLUMNS: &str =
+    "id, name, is_premium, premium_since, premium_expires_at, created_at, deleted_at";
+
+#[async_trait]
+impl UserDatabaseTrait for SqliteUserDatabase {
+    type UserId = String;
+    type UserName = String;
+    type UserIsPremium = bool;
+    type UserData = SqliteUserRecord;
+
+    fn from_user_id(id: &Self::UserId) -> Result<String> {
+        Ok(id.to_owned())
+    }
+    fn from_user_name(name: &Self::UserName) -> Result<String> {
+        Ok(name.to_owned())
+    }
+    fn from_user_is_premium(is_premium: Self::UserIsPremium) -> Result<bool> {
+        Ok(is_premium)
+    }
+    fn from_user_data(user: &Self::UserData) -> Result<PrimitiveUser> {
+        Ok(PrimitiveUser {
+            id: user.id.to_owned(),
+            name: user.name.to_owned(),
+            is_premium: user.is_premium,
+            premium_since: user.premium_since,
+            premium_expires_at: user.premium_expires_at,
+            created_at: user.created_at,
+            deleted_at: user.deleted_at,
+        })
+    }
+
+    fn to_user_id(value: &str) -> Result<Self::UserId> {
+        Ok(value.to_string())
+    }
+    fn to_user_name(value: &str) -> Result<Self::UserName> {
+        Ok(value.to_string())
+    }
+    fn to_user_is_premium(value: bool) -> Result<Self::UserIsPremium> {
+        Ok(value)
+    }
+    fn to_user_data(user: &PrimitiveUser) -> Result<Self::UserData> {
+        Ok(SqliteUserRecord {
+            id: user.id.to_owned(),
+            name: user.name.to_owned(),
+            is_premium: user.is_premium,
+            premium_since: user.premium_since,
+            premium_expires_at: user.premium_expires_at,
+            created_at: user.created_at,
+            deleted_at: user.deleted_at,
+        })
+    }
+
+    async fn save(
+        &self,
+        user: &Self::UserData,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            "
+insert into user (id, name, is_premium, premium_since, premium_expires_at, created_at, deleted_at)
+values (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+on conflict (id)
+do
+update set name = ?2, is_premium = ?3, premium_since = ?4, premium_expires_at = ?5, deleted_at = ?7;
+            ",
+        )
+        .bind(&user.id)
+        .bind(&user.name)
+        .bind(user.is_premium)
+        .bind(user.premium_since)
+        .bind(user.premium_expires_at)
+        .bind(user.created_at)
+        .bind(user.deleted_at)
+        .execute(&mut tx)
+        .await
+        .map_err(map_constraint_violation)?;
+
+        SqliteOutboxDatabase::append(&mut tx, events).await?;
+        if let Some(entry) = audit {
+            SqliteAuditDatabase::append(&mut tx, entry).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find(&self, user_name: &Self::UserName) -> Result<Option<Self::UserData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from user where name = ?1 and deleted_at is null;",
+            USER_COLUMNS
+        );
+        let data = sqlx::query_as::<_, Self::UserData>(&query)
+            .bind(user_name)
+            .fetch_optional(&mut conn)
+            .await?;
+
+        Ok(data)
+    }
+
+    async fn find_deleted_by_id(&self, id: &Self::UserId) -> Result<Option<Self::UserData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from user where id = ?1 and deleted_at is not null;",
+            USER_COLUMNS
+        );
+        let data = sqlx::query_as::<_, Self::UserData>(&query)
+            .bind(id)
+            .fetch_optional(&mut conn)
+            .await?;
+
+        Ok(data)
+    }
+
+    async fn find_deleted_before(
+        &self,
+        deleted_before: DateTime<Utc>,
+    ) -> Result<Vec<Self::UserId>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let ids = sqlx::query_scalar::<_, String>(
+            "select id from user where deleted_at < ?1 order by deleted_at, id;",
+        )
+        .bind(deleted_before)
+        .fetch_all(&mut conn)
+        .await?;
+
+        Ok(ids)
+    }
+
+    async fn purge(&self, id: &Self::UserId) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query("delete from user where id = ?1 and deleted_at is not null;")
+            .bind(id)
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &Self::UserId) -> Result<Option<Self::UserData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from user where id = ?1 and deleted_at is null;",
+            USER_COLUMNS
+        );
+        let data = sqlx::query_as::<_, Self::UserData>(&query)
+            .bind(id)
+            .fetch_optional(&mut conn)
+            .await?;
+
+        Ok(data)
+    }
+
+    async fn batch_find(&self, users: Vec<Self::UserId>) -> Result<Vec<Self::UserData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        if users.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let params = (1..=users.len())
+            .map(|i| format!("?{}", i))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let query = format!(
+            "select {} from user where id in ({}) and deleted_at is null",
+            USER_COLUMNS, params
+        );
+        let data = users
+            .iter()
+            .fold(sqlx::query_as::<_, Self::UserData>(&query), |q, id| {
+                q.bind(id)
+            })
+            .fetch_all(&mut conn)
+            .await?;
+
+        Ok(data)
+    }
+
+    async fn find_page(&self, query: &UserPageQuery<Self::UserId>) -> Result<Vec<Self::UserData>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let sql = format!("select {} from user {}", USER_COLUMNS, page_sql(query, '?'));
+        let mut page = sqlx::query_as::<_, Self::UserData>(&sql);
+        if let Some(is_premium) = query.is_premium {
+            page = page.bind(is_premium);
+        }
+        if let Some(prefix) = &query.name_prefix {
+            page = page.bind(prefix);
+        }
+        if let Some((id, name)) = &query.after {
+            page = page.bind(id);
+            if query.sort_key == UserSortKey::Name {
+                page = page.bind(name);
+            }
+        }
+        let data = page.bind(query.limit as i64).fetch_all(&mut conn).await?;
+
+        Ok(data)
+    }
+}
+
+impl SqliteUserDatabase {
+    pub fn new(pool: Arc<Pool<Sqlite>>) -> anyhow::Result<SqliteUserDatabase> {
+        Ok(SqliteUserDatabase { pool })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use sqlx::{sqlite::SqlitePoolOptions, Pool, Sqlite};
+
+    use crate::application::audit::audit_entry;
+    use crate::domain::model::{
+        audit::entity::Actor,
+        user::{
+            entity::{User, UserId, UserIsPremium, UserName},
+            repository::{
+                SortOrder, UserListCursor, UserListQuery, UserRepositoryTrait, UserSortKey,
+            },
+        },
+    };
+    use crate::infrastructure::database::shared::SQLITE_MIGRATOR;
+    use crate::interface::repository::user::UserRepository;
+
+    use super::SqliteUserDatabase;
+
+    async fn pool() -> Pool<Sqlite> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        SQLITE_MIGRATOR.run(&pool).await.unwrap();
+        pool
+    }
+
+    async fn user_repository() -> UserRepository {
+        let user_database = SqliteUserDatabase::new(Arc::new(pool().await)).unwrap();
+        UserRepository::new(Box::new(user_database)).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn can_save_and_find_user() {
+        let repository = user_repository().await;
+        let id = UserId::new("00000000-0000-0000-0000-000000000001").unwrap();
+        let name = UserName::new("sqlite").unwrap();
+        let mut user = User::new(id.clone(), name.clone(), UserIsPremium::new(true)).unwrap();
+
+        repository.save(&mut user).await.unwrap();
+
+        let by_id = repository.find_by_id(&id).await.unwrap().unwrap();
+        let by_name = repository.find_by_name(&name).await.unwrap().unwrap();
+        assert_eq!(by_id.get_name(), &name);
+        assert_eq!(by_name.get_id(), &id);
+        assert!(by_id.get_is_premium().to_inner());
+        assert_eq!(by_id.get_created_at(), user.get_created_at());
+        assert_eq!(by_id.get_premium_since(), user.get_premium_since());
+
+        let mut downgraded = by_id.clone();
+        downgraded.downgrade().unwrap();
+        repository.save(&mut downgraded).await.unwrap();
+        let found = repository.find_by_id(&id).await.unwrap().unwrap();
+        assert_eq!(found.get_premium_since(), user.get_premium_since());
+        assert_eq!(
+            found.get_premium_expires_at(),
+            downgraded.get_premium_expires_at()
+        );
+        assert_eq!(found.get_created_at(), user.get_created_at());
+
+        let found = repository.batch_find(vec![id.clone()]).await.unwrap();
+        assert_eq!(found.len(), 1);
+
+        let mut deleted = downgraded.clone();
+        deleted.delete().unwrap();
+        repository.save(&mut deleted).await.unwrap();
+        assert!(repository.find_by_id(&id).await.unwrap().is_none());
+        assert!(repository.find_by_name(&name).await.unwrap().is_none());
+        assert!(repository.find_deleted_by_id(&id).await.unwrap().is_some());
+
+        let deleted = repository.find_deleted_before(Utc::now()).await.unwrap();
+        assert_eq!(deleted, vec![id.clone()]);
+        repository.purge(&id).await.unwrap();
+        assert!(matches!(repository.find_by_id(&id).await, Ok(None)));
+        assert!(repository.find_deleted_by_id(&id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn saves_nothing_when_the_audit_entry_cannot_be_written() {
+        let pool = Arc::new(pool().await);
+        let user_database = SqliteUserDatabase::new(Arc::clone(&pool)).unwrap();
+        let repository = UserRepository::new(Box::new(user_database)).await.unwrap();
+        let id = UserId::new("00000000-0000-0000-0000-000000000002").unwrap();
+        let mut user = User::new(
+            id.clone(),
+            UserName::new("audited").unwrap(),
+            UserIsPremium::new(false),
+        )
+        .unwrap();
+        let actor = Actor::System("test".to_string());
+
+        sqlx::query("drop table audit_log;")
+            .execute(&*pool)
+            .await
+            .unwrap();
+        let entry = audit_entry(&actor, "user.register", None, Some(&user));
+        assert!(repository.save_audited(&mut user, &entry).await.is_err());
+
+        assert!(repository.find_by_id(&id).await.unwrap().is_none());
+        let outbox: i64 = sqlx::query_scalar("select count(*) from outbox;")
+            .fetch_one(&*pool)
+            .await
+            .unwrap();
+        assert_eq!(outbox, 0);
+    }
+
+    #[tokio::test]
+    async fn appends_each_event_to_the_outbox_once() {
+        let pool = Arc::new(pool().await);
+        let user_database = SqliteUserDatabase::new(Arc::clone(&pool)).unwrap();
+        let repository = UserRepository::new(Box::new(user_database)).await.unwrap();
+        let mut user = User::new(
+            UserId::new("00000000-0000-0000-0000-000000000003").unwrap(),
+            UserName::new("outboxed").unwrap(),
+            UserIsPremium::new(false),
+        )
+        .unwrap();
+        let outbox = || async {
+            sqlx::query_scalar::<_, i64>("select count(*) from outbox;")
+                .fetch_one(&*pool)
+                .await
+                .unwrap()
+        };
+
+        repository.save(&mut user).await.unwrap();
+        assert!(user.get_events().is_empty());
+        repository.save(&mut user).await.unwrap();
+        assert_eq!(outbox().await, 1);
+
+        user.upgrade().unwrap();
+        repository.save(&mut user).await.unwrap();
+        assert_eq!(outbox().await, 2);
+    }
+
+    #[tokio::test]
+    async fn can_find_page_after_cursor() {
+        let repository = user_repository().await;
+        for (id, name, is_premium) in [
+            ("1", "alice", true),
+            ("2", "bob", false),
+            ("3", "alan", true),
+        ] {
+            let mut user = User::new(
+                UserId::new(id).unwrap(),
+                UserName::new(name).unwrap(),
+                UserIsPremium::new(is_premium),
+            )
+            .unwrap();
+            repository.save(&mut user).await.unwrap();
+        }
+
+        let query = UserListQuery {
+            is_premium: Some(true),
+            name_prefix: Some("al".to_string()),
+            sort_key: UserSortKey::Name,
+            sort_order: SortOrder::Desc,
+            after: Some(UserListCursor {
+                id: UserId::new("1").unwrap(),
+                name: UserName::new("alice").unwrap(),
+            }),
+            limit: 10,
+        };
+        let page = repository.find_page(&query).await.unwrap();
+
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].get_name(), &UserName::new("alan").unwrap());
+    }
+}
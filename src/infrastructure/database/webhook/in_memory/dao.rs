@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::interface::repository::{
+    outbox::PrimitiveEvent,
+    webhook::{PendingDelivery, PrimitiveDelivery, PrimitiveWebhook, WebhookDatabaseTrait},
+};
+
+static STATIC_WEBHOOK_TABLE: Lazy<Mutex<WebhookTable>> = Lazy::new(|| {
+    let table = WebhookTable::default();
+    Mutex::new(table)
+});
+
+#[derive(Default)]
+struct WebhookTable {
+    webhooks: HashMap<String, PrimitiveWebhook>,
+    deliveries: Vec<DeliveryRow>,
+}
+
+struct DeliveryRow {
+    delivery: PrimitiveDelivery,
+    body: String,
+    next_attempt_at: DateTime<Utc>,
+}
+
+pub struct InMemoryWebhookDatabase {}
+
+impl InMemoryWebhookDatabase {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl WebhookDatabaseTrait for InMemoryWebhookDatabase {
+    async fn save(&self, webhook: &PrimitiveWebhook) -> Result<()> {
+        let mut table = STATIC_WEBHOOK_TABLE.lock().await;
+        table
+            .webhooks
+            .insert(webhook.id.to_string(), webhook.clone());
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<PrimitiveWebhook>> {
+        let table = STATIC_WEBHOOK_TABLE.lock().await;
+        Ok(table.webhooks.get(id).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<PrimitiveWebhook>> {
+        let table = STATIC_WEBHOOK_TABLE.lock().await;
+        let mut webhooks = table.webhooks.values().cloned().collect::<Vec<_>>();
+        webhooks.sort_by(|a, b| (a.created_at, &a.id).cmp(&(b.created_at, &b.id)));
+        Ok(webhooks)
+    }
+
+    async fn delete(&self, id: &str) -> Result<()> {
+        let mut table = STATIC_WEBHOOK_TABLE.lock().await;
+        table.webhooks.remove(id);
+        table.deliveries.retain(|row| row.delivery.webhook_id != id);
+
+        Ok(())
+    }
+
+    async fn find_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: usize,
+    ) -> Result<Vec<PrimitiveDelivery>> {
+        let table = STATIC_WEBHOOK_TABLE.lock().await;
+        Ok(table
+            .deliveries
+            .iter()
+            .rev()
+            .filter(|row| row.delivery.webhook_id == webhook_id)
+            .take(limit)
+            .map(|row| row.delivery.clone())
+            .collect())
+    }
+
+    async fn enqueue(&self, webhook_id: &str, event: &PrimitiveEvent, body: &str) -> Result<()> {
+        let mut table = STATIC_WEBHOOK_TABLE.lock().await;
+        if table
+            .deliveries
+            .iter()
+            .any(|row| row.delivery.webhook_id == webhook_id && row.delivery.event_id == event.id)
+        {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        table.deliveries.push(DeliveryRow {
+            delivery: PrimitiveDelivery {
+                id: Uuid::new_v4().to_string(),
+                webhook_id: webhook_id.to_string(),
+                event_id: event.id.to_string(),
+                event_type: event.event_type.to_string(),
+                status: "pending".to_string(),
+                attempts: 0,
+                response_status: None,
+                last_error: None,
+                created_at: now,
+                delivered_at: None,
+            },
+            body: body.to_string(),
+            next_attempt_at: now,
+        });
+
+        Ok(())
+    }
+
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<PendingDelivery>> {
+        let mut table = STATIC_WEBHOOK_TABLE.lock().await;
+        let WebhookTable {
+            webhooks,
+            deliveries,
+        } = &mut *table;
+
+        Ok(deliveries
+            .iter_mut()
+            .filter(|row| row.delivery.status == "pending" && row.next_attempt_at <= now)
+            .take(limit)
+            .filter_map(|row| {
+                let webhook = webhooks.get(&row.delivery.webhook_id)?;
+                row.next_attempt_at = lease_until;
+                Some(PendingDelivery {
+                    id: row.delivery.id.to_string(),
+                    webhook_id: webhook.id.to_string(),
+                    url: webhook.url.to_string(),
+                    secret: webhook.secret.to_string(),
+                    event_id: row.delivery.event_id.to_string(),
+                    event_type: row.delivery.event_type.to_string(),
+                    body: row.body.to_string(),
+                    attempts: row.delivery.attempts,
+                })
+            })
+            .collect())
+    }
+
+    async fn mark_delivered(
+        &self,
+        id: &str,
+        response_status: u16,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let mut table = STATIC_WEBHOOK_TABLE.lock().await;
+        if let Some(row) = table
+            .deliveries
+            .iter_mut()
+            .find(|row| row.delivery.id == id)
+        {
+            row.delivery.status = "delivered".to_string();
+            row.delivery.attempts += 1;
+            row.delivery.response_status = Some(response_status);
+            row.delivery.last_error = None;
+            row.delivery.delivered_at = Some(delivered_at);
+        }
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: &str,
+        response_status: Option<u16>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let mut table = STATIC_WEBHOOK_TABLE.lock().await;
+        if let Some(row) = table
+            .deliveries
+            .iter_mut()
+            .find(|row| row.delivery.id == id)
+        {
+            row.delivery.attempts += 1;
+            row.delivery.response_status = response_status;
+            row.delivery.last_error = Some(error.to_string());
+            match retry_at {
+                Some(retry_at) => row.next_attempt_at = retry_at,
+                None => row.delivery.status = "failed".to_string(),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, types::Uuid, Pool, Postgres};
+
+use crate::domain::model::error::DomainError;
+use crate::interface::repository::{
+    outbox::PrimitiveEvent,
+    webhook::{PendingDelivery, PrimitiveDelivery, PrimitiveWebhook, WebhookDatabaseTrait},
+};
+
+pub struct PostgresWebhookDatabase {
+    pool: Arc<Pool<Postgres>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresWebhookRecord {
+    id: Uuid,
+    url: String,
+    events: Vec<String>,
+    secret: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresDeliveryRecord {
+    id: Uuid,
+    webhook_id: Uuid,
+    event_id: Uuid,
+    event_type: String,
+    status: String,
+    attempts: i32,
+    response_status: Option<i32>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct PostgresPendingRecord {
+    id: Uuid,
+    webhook_id: Uuid,
+    url: String,
+    secret: String,
+    event_id: Uuid,
+    event_type: String,
+    body: String,
+    attempts: i32,
+}
+
+impl From<PostgresWebhookRecord> for PrimitiveWebhook {
+    fn from(r: PostgresWebhookRecord) -> Self {
+        Self {
+            id: r.id.to_string(),
+            url: r.url,
+            events: r.events,
+            secret: r.secret,
+            created_at: r.created_at,
+        }
+    }
+}
+
+const WEBHOOK_COLUMNS: &str = "id, url, events, secret, created_at";
+const DELIVERY_COLUMNS: &str = "id, webhook_id, event_id, event_type, status, attempts, response_status, last_error, created_at, delivered_at";
+
+fn to_uuid(value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value).map_err(|e| {
+        DomainError::invalid_field("id", "uuid", &format!("Invalid id `{}`: {}", value, e)).into()
+    })
+}
+
+#[async_trait]
+impl WebhookDatabaseTrait for PostgresWebhookDatabase {
+    async fn save(&self, webhook: &PrimitiveWebhook) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+insert into public.webhook (id, url, events, secret, created_at)
+values ($1, $2, $3, $4, $5)
+on conflict (id)
+do
+update set url = $2, events = $3, secret = $4;
+            ",
+        )
+        .bind(to_uuid(&webhook.id)?)
+        .bind(&webhook.url)
+        .bind(&webhook.events)
+        .bind(&webhook.secret)
+        .bind(webhook.created_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<PrimitiveWebhook>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from public.webhook where id = $1",
+            WEBHOOK_COLUMNS
+        );
+        let data = sqlx::query_as::<_, PostgresWebhookRecord>(&query)
+            .bind(to_uuid(id)?)
+            .fetch_optional(&mut conn)
+            .await?;
+
+        Ok(data.map(PrimitiveWebhook::from))
+    }
+
+    async fn find_all(&self) -> Result<Vec<PrimitiveWebhook>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from public.webhook order by created_at, id",
+            WEBHOOK_COLUMNS
+        );
+        let data = sqlx::query_as::<_, PostgresWebhookRecord>(&query)
+            .fetch_all(&mut conn)
+            .await?;
+
+        Ok(data.into_iter().map(PrimitiveWebhook::from).collect())
+    }
+
+    async fn delete(&self, id: &str) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query("delete from public.webhook where id = $1")
+            .bind(to_uuid(id)?)
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: usize,
+    ) -> Result<Vec<PrimitiveDelivery>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from public.webhook_delivery where webhook_id = $1 order by created_at desc, id limit $2",
+            DELIVERY_COLUMNS
+        );
+        let data = sqlx::query_as::<_, PostgresDeliveryRecord>(&query)
+            .bind(to_uuid(webhook_id)?)
+            .bind(limit as i64)
+            .fetch_all(&mut conn)
+            .await?;
+
+        Ok(data
+            .into_iter()
+            .map(|r| PrimitiveDelivery {
+                id: r.id.to_string(),
+                webhook_id: r.webhook_id.to_string(),
+                event_id: r.event_id.to_string(),
+                event_type: r.event_type,
+                status: r.status,
+                attempts: r.attempts as u32,
+                response_status: r.response_status.map(|s| s as u16),
+                last_error: r.last_error,
+                created_at: r.created_at,
+                delivered_at: r.delivered_at,
+            })
+            .collect())
+    }
+
+    async fn enqueue(&self, webhook_id: &str, event: &PrimitiveEvent, body: &str) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        let now = Utc::now();
+        sqlx::query(
+            "
+insert into public.webhook_delivery (id, webhook_id, event_id, event_type, body, next_attempt_at, created_at)
+values ($1, $2, $3, $4, $5, $6, $6)
+on conflict (webhook_id, event_id) do nothing;
+            ",
+        )
+        .bind(to_uuid(&uuid::Uuid::new_v4().to_string())?)
+        .bind(to_uuid(webhook_id)?)
+        .bind(to_uuid(&event.id)?)
+        .bind(&event.event_type)
+        .bind(body)
+        .bind(now)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<PendingDelivery>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let data = sqlx::query_as::<_, PostgresPendingRecord>(
+            "
+with claimed as (
+    update public.webhook_delivery set next_attempt_at = $2
+    where id in (
+        select id from public.webhook_delivery
+        where status = 'pending' and next_attempt_at <= $1
+        order by next_attempt_at
+        limit $3
+        for update skip locked
+    )
+    returning id, webhook_id, event_id, event_type, body, attempts, created_at
+)
+select claimed.id, claimed.webhook_id, webhook.url, webhook.secret, claimed.event_id,
+    claimed.event_type, claimed.body, claimed.attempts
+from claimed
+inner join public.webhook on webhook.id = claimed.webhook_id
+order by claimed.created_at;
+            ",
+        )
+        .bind(now)
+        .bind(lease_until)
+        .bind(limit as i64)
+        .fetch_all(&mut conn)
+        .await?;
+
+        Ok(data
+            .into_iter()
+            .map(|r| PendingDelivery {
+                id: r.id.to_string(),
+                webhook_id: r.webhook_id.to_string(),
+                url: r.url,
+                secret: r.secret,
+                event_id: r.event_id.to_string(),
+                event_type: r.event_type,
+                body: r.body,
+                attempts: r.attempts as u32,
+            })
+            .collect())
+    }
+
+    async fn mark_delivered(
+        &self,
+        id: &str,
+        response_status: u16,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+update public.webhook_delivery
+set status = 'delivered', attempts = attempts + 1, response_status = $2, last_error = null,
+    delivered_at = $3
+where id = $1;
+            ",
+        )
+        .bind(to_uuid(id)?)
+        .bind(response_status as i32)
+        .bind(delivered_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: &str,
+        response_status: Option<u16>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+update public.webhook_delivery
+set status = case when $4::timestamptz is null then 'failed' else 'pending' end,
+    attempts = attempts + 1, response_status = $2, last_error = $3,
+    next_attempt_at = coalesce($4, next_attempt_at)
+where id = $1;
+            ",
+        )
+        .bind(to_uuid(id)?)
+        .bind(response_status.map(i32::from))
+        .bind(error)
+        .bind(retry_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresWebhookDatabase {
+    pub fn new(pool: Arc<Pool<Postgres>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+}
@@ -0,0 +1,306 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, Pool, Sqlite};
+use uuid::Uuid;
+
+use crate::interface::repository::{
+    outbox::PrimitiveEvent,
+    webhook::{PendingDelivery, PrimitiveDelivery, PrimitiveWebhook, WebhookDatabaseTrait},
+};
+
+pub struct SqliteWebhookDatabase {
+    pool: Arc<Pool<Sqlite>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteWebhookRecord {
+    id: String,
+    url: String,
+    events: String,
+    secret: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteDeliveryRecord {
+    id: String,
+    webhook_id: String,
+    event_id: String,
+    event_type: String,
+    status: String,
+    attempts: i32,
+    response_status: Option<i32>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteClaimedRecord {
+    id: String,
+    webhook_id: String,
+    event_id: String,
+    event_type: String,
+    body: String,
+    attempts: i32,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<SqliteWebhookRecord> for PrimitiveWebhook {
+    type Error = anyhow::Error;
+
+    fn try_from(r: SqliteWebhookRecord) -> Result<Self> {
+        Ok(Self {
+            id: r.id,
+            url: r.url,
+            events: serde_json::from_str(&r.events)?,
+            secret: r.secret,
+            created_at: r.created_at,
+        })
+    }
+}
+
+const WEBHOOK_COLUMNS: &str = "id, url, events, secret, created_at";
+const DELIVERY_COLUMNS: &str = "id, webhook_id, event_id, event_type, status, attempts, response_status, last_error, created_at, delivered_at";
+
+#[async_trait]
+impl WebhookDatabaseTrait for SqliteWebhookDatabase {
+    async fn save(&self, webhook: &PrimitiveWebhook) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+insert into webhook (id, url, events, secret, created_at)
+values (?1, ?2, ?3, ?4, ?5)
+on conflict (id)
+do
+update set url = ?2, events = ?3, secret = ?4;
+            ",
+        )
+        .bind(&webhook.id)
+        .bind(&webhook.url)
+        .bind(serde_json::to_string(&webhook.events)?)
+        .bind(&webhook.secret)
+        .bind(webhook.created_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<PrimitiveWebhook>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!("select {} from webhook where id = ?1", WEBHOOK_COLUMNS);
+        sqlx::query_as::<_, SqliteWebhookRecord>(&query)
+            .bind(id)
+            .fetch_optional(&mut conn)
+            .await?
+            .map(PrimitiveWebhook::try_from)
+            .transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<PrimitiveWebhook>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from webhook order by created_at, id",
+            WEBHOOK_COLUMNS
+        );
+        sqlx::query_as::<_, SqliteWebhookRecord>(&query)
+            .fetch_all(&mut conn)
+            .await?
+            .into_iter()
+            .map(PrimitiveWebhook::try_from)
+            .collect()
+    }
+
+    async fn delete(&self, id: &str) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query("delete from webhook where id = ?1")
+            .bind(id)
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: usize,
+    ) -> Result<Vec<PrimitiveDelivery>> {
+        let mut conn = self.pool.acquire().await?;
+
+        let query = format!(
+            "select {} from webhook_delivery where webhook_id = ?1 order by created_at desc, id limit ?2",
+            DELIVERY_COLUMNS
+        );
+        let data = sqlx::query_as::<_, SqliteDeliveryRecord>(&query)
+            .bind(webhook_id)
+            .bind(limit as i64)
+            .fetch_all(&mut conn)
+            .await?;
+
+        Ok(data
+            .into_iter()
+            .map(|r| PrimitiveDelivery {
+                id: r.id,
+                webhook_id: r.webhook_id,
+                event_id: r.event_id,
+                event_type: r.event_type,
+                status: r.status,
+                attempts: r.attempts as u32,
+                response_status: r.response_status.map(|s| s as u16),
+                last_error: r.last_error,
+                created_at: r.created_at,
+                delivered_at: r.delivered_at,
+            })
+            .collect())
+    }
+
+    async fn enqueue(&self, webhook_id: &str, event: &PrimitiveEvent, body: &str) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        let now = Utc::now();
+        sqlx::query(
+            "
+insert into webhook_delivery (id, webhook_id, event_id, event_type, body, next_attempt_at, created_at)
+values (?1, ?2, ?3, ?4, ?5, ?6, ?6)
+on conflict (webhook_id, event_id) do nothing;
+            ",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(webhook_id)
+        .bind(&event.id)
+        .bind(&event.event_type)
+        .bind(body)
+        .bind(now)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<PendingDelivery>> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut claimed = sqlx::query_as::<_, SqliteClaimedRecord>(
+            "
+update webhook_delivery set next_attempt_at = ?2
+where id in (
+    select id from webhook_delivery
+    where status = 'pending' and next_attempt_at <= ?1
+    order by next_attempt_at
+    limit ?3
+)
+returning id, webhook_id, event_id, event_type, body, attempts, created_at;
+            ",
+        )
+        .bind(now)
+        .bind(lease_until)
+        .bind(limit as i64)
+        .fetch_all(&mut tx)
+        .await?;
+        claimed.sort_by_key(|r| r.created_at);
+
+        let mut webhooks: HashMap<String, (String, String)> = HashMap::new();
+        for record in &claimed {
+            if !webhooks.contains_key(&record.webhook_id) {
+                let webhook = sqlx::query_as::<_, (String, String)>(
+                    "select url, secret from webhook where id = ?1",
+                )
+                .bind(&record.webhook_id)
+                .fetch_one(&mut tx)
+                .await?;
+                webhooks.insert(record.webhook_id.to_string(), webhook);
+            }
+        }
+        tx.commit().await?;
+
+        Ok(claimed
+            .into_iter()
+            .map(|r| {
+                let (url, secret) = webhooks[&r.webhook_id].clone();
+                PendingDelivery {
+                    id: r.id,
+                    webhook_id: r.webhook_id,
+                    url,
+                    secret,
+                    event_id: r.event_id,
+                    event_type: r.event_type,
+                    body: r.body,
+                    attempts: r.attempts as u32,
+                }
+            })
+            .collect())
+    }
+
+    async fn mark_delivered(
+        &self,
+        id: &str,
+        response_status: u16,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+update webhook_delivery
+set status = 'delivered', attempts = attempts + 1, response_status = ?2, last_error = null,
+    delivered_at = ?3
+where id = ?1;
+            ",
+        )
+        .bind(id)
+        .bind(response_status as i32)
+        .bind(delivered_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: &str,
+        response_status: Option<u16>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+
+        sqlx::query(
+            "
+update webhook_delivery
+set status = case when ?4 is null then 'failed' else 'pending' end,
+    attempts = attempts + 1, response_status = ?2, last_error = ?3,
+    next_attempt_at = coalesce(?4, next_attempt_at)
+where id = ?1;
+            ",
+        )
+        .bind(id)
+        .bind(response_status.map(i32::from))
+        .bind(error)
+        .bind(retry_at)
+        .execute(&mut conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl SqliteWebhookDatabase {
+    pub fn new(pool: Arc<Pool<Sqlite>>) -> Result<Self> {
+        Ok(Self { pool })
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use super::EventSinkTrait;
+use crate::application::club::ClubStatisticsProjector;
+use crate::domain::model::event::DomainEvent;
+use crate::infrastructure::database::shared::DatabaseConnection;
+use crate::interface::repository::{
+    club::ClubRepository, club_statistics::ClubStatisticsRepository, outbox::PrimitiveEvent,
+    user::UserRepository,
+};
+
+/// Keeps the club statistics projection up to date from the events of the
+/// clubs and of their members.
+pub struct ClubStatisticsSink {
+    projector: ClubStatisticsProjector,
+}
+
+impl ClubStatisticsSink {
+    pub fn new(projector: ClubStatisticsProjector) -> Self {
+        Self { projector }
+    }
+
+    /// A sink whose projector reads and writes through `connection`.
+    pub async fn from_connection(connection: &DatabaseConnection) -> Result<Self> {
+        let club_repository = ClubRepository::new(connection.club_database()?).await?;
+        let user_repository = UserRepository::new(connection.user_database()?).await?;
+        let club_statistics_repository =
+            ClubStatisticsRepository::new(connection.club_statistics_database()?).await?;
+
+        Ok(Self::new(ClubStatisticsProjector::new(
+            Arc::new(Mutex::new(club_repository)),
+            Arc::new(user_repository),
+            Arc::new(club_statistics_repository),
+        )))
+    }
+}
+
+#[async_trait]
+impl EventSinkTrait for ClubStatisticsSink {
+    fn name(&self) -> &'static str {
+        "club_statistics"
+    }
+
+    async fn publish(&self, event: &PrimitiveEvent) -> Result<()> {
+        match event.to_event()? {
+            DomainEvent::MemberJoined { club_id, .. } => {
+                self.projector
+                    .refresh(&club_id, Some(event.occurred_at))
+                    .await
+            }
+            DomainEvent::ClubCreated { club_id, .. }
+            | DomainEvent::ClubRenamed { club_id, .. }
+            | DomainEvent::MemberLeft { club_id, .. }
+            | DomainEvent::OwnershipTransferred { club_id, .. } => {
+                self.projector.refresh(&club_id, None).await
+            }
+            DomainEvent::UserUpgraded { user_id }
+            | DomainEvent::UserDowngraded { user_id }
+            | DomainEvent::UserDeleted { user_id }
+            | DomainEvent::UserRestored { user_id } => {
+                self.projector.refresh_member(&user_id).await
+            }
+            DomainEvent::UserRegistered { .. } | DomainEvent::UserRenamed { .. } => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::{Arc, RwLock};
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::model::event::{
+    DomainEvent, DomainEventDispatcherTrait, DomainEventHandlerTrait,
+};
+
+type Handler = Arc<dyn DomainEventHandlerTrait + Send + Sync>;
+
+/// Hands every event to the subscribed handlers in the same process. Clones
+/// share their subscriptions.
+#[derive(Clone, Default)]
+pub struct EventDispatcher {
+    handlers: Arc<RwLock<Vec<Handler>>>,
+}
+
+impl EventDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn subscribe(&self, handler: Handler) {
+        self.handlers
+            .write()
+            .expect("event handlers lock poisoned")
+            .push(handler);
+    }
+}
+
+#[async_trait]
+impl DomainEventDispatcherTrait for EventDispatcher {
+    /// Every handler sees every event even when another one fails. The first
+    /// failure is returned so that the relay delivers the events again.
+    async fn dispatch(&self, events: &[DomainEvent]) -> Result<()> {
+        let handlers = self
+            .handlers
+            .read()
+            .expect("event handlers lock poisoned")
+            .clone();
+
+        let mut result = Ok(());
+        for event in events {
+            for handler in &handlers {
+                if let Err(e) = handler.handle(event).await {
+                    if result.is_ok() {
+                        result = Err(e.context(format!("Failed to handle {}", event.name())));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,102 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::EventDispatcher;
+use crate::domain::model::event::DomainEventDispatcherTrait;
+use crate::infrastructure::http::HttpClient;
+use crate::interface::repository::outbox::PrimitiveEvent;
+
+/// Somewhere the outbox relay publishes events to.
+#[async_trait]
+pub trait EventSinkTrait {
+    fn name(&self) -> &'static str;
+    async fn publish(&self, event: &PrimitiveEvent) -> Result<()>;
+}
+
+/// The JSON form of an event outside the process.
+#[derive(Serialize)]
+struct EventEnvelope<'a> {
+    id: &'a str,
+    #[serde(rename = "type")]
+    event_type: &'a str,
+    aggregate_id: &'a str,
+    occurred_at: String,
+    data: Value,
+}
+
+pub fn event_json(event: &PrimitiveEvent) -> Result<String> {
+    let envelope = EventEnvelope {
+        id: &event.id,
+        event_type: &event.event_type,
+        aggregate_id: &event.aggregate_id,
+        occurred_at: event.occurred_at.to_rfc3339(),
+        data: serde_json::from_str(&event.payload)?,
+    };
+    Ok(serde_json::to_string(&envelope)?)
+}
+
+/// Prints every event as a line of JSON.
+pub struct LogSink;
+
+#[async_trait]
+impl EventSinkTrait for LogSink {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    async fn publish(&self, event: &PrimitiveEvent) -> Result<()> {
+        println!("event {}", event_json(event)?);
+        Ok(())
+    }
+}
+
+/// Posts every event to a single URL. Any status other than 2xx is a failure.
+pub struct WebhookSink {
+    url: String,
+    client: HttpClient,
+}
+
+impl WebhookSink {
+    pub fn new(url: &str, client: HttpClient) -> Self {
+        Self {
+            url: url.to_string(),
+            client,
+        }
+    }
+}
+
+#[async_trait]
+impl EventSinkTrait for WebhookSink {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn publish(&self, event: &PrimitiveEvent) -> Result<()> {
+        let headers = [
+            ("X-Event-Id", event.id.to_string()),
+            ("X-Event-Type", event.event_type.to_string()),
+        ];
+        let status = self
+            .client
+            .post_json(&self.url, &headers, &event_json(event)?)
+            .await?;
+        if !(200..300).contains(&status) {
+            bail!("{} answered with status {}", self.url, status);
+        }
+        Ok(())
+    }
+}
+
+/// Hands events to the handlers subscribed in this process.
+#[async_trait]
+impl EventSinkTrait for EventDispatcher {
+    fn name(&self) -> &'static str {
+        "subscribers"
+    }
+
+    async fn publish(&self, event: &PrimitiveEvent) -> Result<()> {
+        self.dispatch(&[event.to_event()?]).await
+    }
+}
@@ -0,0 +1,10 @@
+mod club_statistics_sink;
+mod event_dispatcher;
+mod event_sink;
+mod outbox_relay;
+mod webhook_delivery;
+
+pub use self::{
+    club_statistics_sink::*, event_dispatcher::*, event_sink::*, outbox_relay::*,
+    webhook_delivery::*,
+};
@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chrono::{Duration, Utc};
+
+use super::{
+    ClubStatisticsSink, EventDispatcher, EventSinkTrait, LogSink, WebhookSink,
+    WebhookSubscriptionSink,
+};
+use crate::infrastructure::{
+    config::OutboxConfig, database::shared::DatabaseConnection, http::HttpClient,
+};
+use crate::interface::repository::outbox::{OutboxDatabaseTrait, PrimitiveEvent};
+
+type Sink = Arc<dyn EventSinkTrait + Send + Sync>;
+
+/// Publishes the events written to the outbox. An event is marked published
+/// once every sink accepted it. Until then it is retried with backoff, so a
+/// sink may receive the same event more than once.
+pub struct OutboxRelay {
+    outbox: Box<dyn OutboxDatabaseTrait + Send + Sync>,
+    sinks: Vec<Sink>,
+    config: OutboxConfig,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RelayReport {
+    pub published: usize,
+    pub failed: usize,
+}
+
+impl OutboxRelay {
+    pub fn new(
+        outbox: Box<dyn OutboxDatabaseTrait + Send + Sync>,
+        sinks: Vec<Sink>,
+        config: OutboxConfig,
+    ) -> Self {
+        Self {
+            outbox,
+            sinks,
+            config,
+        }
+    }
+
+    /// Publishes to the subscribers of `events`, to the club statistics
+    /// projection, to the registered webhooks and to the sinks enabled in
+    /// `config`.
+    pub async fn from_config(
+        connection: &DatabaseConnection,
+        config: &OutboxConfig,
+        events: &EventDispatcher,
+    ) -> Result<Self> {
+        let mut sinks: Vec<Sink> = vec![
+            Arc::new(events.clone()),
+            Arc::new(ClubStatisticsSink::from_connection(connection).await?),
+            Arc::new(WebhookSubscriptionSink::new(connection.webhook_database()?)),
+        ];
+        if config.log {
+            sinks.push(Arc::new(LogSink));
+        }
+        if let Some(url) = &config.webhook_url {
+            let client = HttpClient::new(config.webhook_timeout());
+            sinks.push(Arc::new(WebhookSink::new(url, client)));
+        }
+
+        Ok(Self::new(
+            connection.outbox_database()?,
+            sinks,
+            config.clone(),
+        ))
+    }
+
+    /// Publishes one batch of due events.
+    pub async fn run_once(&self) -> Result<RelayReport> {
+        let now = Utc::now();
+        let lease_until = now + Duration::from_std(self.config.lease())?;
+        let records = self
+            .outbox
+            .claim(
+                now,
+                lease_until,
+                self.config.batch_size,
+                self.config.max_attempts,
+            )
+            .await?;
+
+        let mut report = RelayReport::default();
+        for record in records {
+            let event = &record.event;
+            match self.publish(event).await {
+                Ok(()) => {
+                    self.outbox.mark_published(&event.id, Utc::now()).await?;
+                    report.published += 1;
+                }
+                Err(e) => {
+                    let attempts = record.attempts + 1;
+                    let retry_at =
+                        Utc::now() + Duration::from_std(self.config.retry_delay(attempts))?;
+                    eprintln!(
+                        "Failed to publish {} {} (attempt {} of {}): {:#}",
+                        event.event_type, event.id, attempts, self.config.max_attempts, e
+                    );
+                    self.outbox
+                        .mark_failed(&event.id, &format!("{:#}", e), retry_at)
+                        .await?;
+                    report.failed += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Publishes batches until no event is due.
+    pub async fn drain(&self) -> Result<RelayReport> {
+        let mut total = RelayReport::default();
+        loop {
+            let report = self.run_once().await?;
+            total.published += report.published;
+            total.failed += report.failed;
+            if report.published + report.failed < self.config.batch_size {
+                return Ok(total);
+            }
+        }
+    }
+
+    /// Keeps publishing, waiting `poll_interval` whenever the outbox has
+    /// nothing due.
+    pub async fn run(self) {
+        loop {
+            if let Err(e) = self.drain().await {
+                eprintln!("Outbox relay failed: {:#}", e);
+            }
+            tokio::time::sleep(self.config.poll_interval()).await;
+        }
+    }
+
+    async fn publish(&self, event: &PrimitiveEvent) -> Result<()> {
+        for sink in &self.sinks {
+            sink.publish(event)
+                .await
+                .with_context(|| format!("{} sink", sink.name()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::{bail, Result};
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    use crate::domain::model::user::{
+        entity::{User, UserId, UserIsPremium, UserName},
+        repository::UserRepositoryTrait,
+    };
+    use crate::infrastructure::{
+        config::OutboxConfig,
+        database::{outbox::InMemoryOutboxDatabase, user::InMemoryUserDatabase},
+    };
+    use crate::interface::repository::{outbox::PrimitiveEvent, user::UserRepository};
+
+    use super::{EventSinkTrait, OutboxRelay};
+
+    /// Records the events of one aggregate and fails the first delivery.
+    struct FlakySink {
+        aggregate_id: String,
+        received: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl EventSinkTrait for FlakySink {
+        fn name(&self) -> &'static str {
+            "flaky"
+        }
+
+        async fn publish(&self, event: &PrimitiveEvent) -> Result<()> {
+            if event.aggregate_id != self.aggregate_id {
+                return Ok(());
+            }
+            let mut received = self.received.lock().unwrap();
+            received.push(event.event_type.to_string());
+            if received.len() == 1 {
+                bail!("unavailable");
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_events_until_every_sink_accepts_them() {
+        let repository = UserRepository::new(Box::new(InMemoryUserDatabase::new()))
+            .await
+            .unwrap();
+        let id = UserId::new("outbox-user").unwrap();
+        let name = UserName::new("outbox-user").unwrap();
+        let mut user = User::new(id.clone(), name, UserIsPremium::new(false)).unwrap();
+        user.upgrade().unwrap();
+        repository.save(&mut user).await.unwrap();
+
+        let sink = Arc::new(FlakySink {
+            aggregate_id: id.to_string(),
+            received: Mutex::new(Vec::new()),
+        });
+        let config = OutboxConfig {
+            retry_base_secs: 0,
+            ..OutboxConfig::default()
+        };
+        let relay = OutboxRelay::new(
+            Box::new(InMemoryOutboxDatabase::new()),
+            vec![sink.clone()],
+            config,
+        );
+
+        relay.drain().await.unwrap();
+        relay.drain().await.unwrap();
+        relay.drain().await.unwrap();
+
+        assert_eq!(
+            *sink.received.lock().unwrap(),
+            vec!["UserRegistered", "UserUpgraded", "UserRegistered"]
+        );
+    }
+}
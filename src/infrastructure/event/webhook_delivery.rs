@@ -0,0 +1,318 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::{event_json, EventSinkTrait};
+use crate::infrastructure::{config::WebhookConfig, http::HttpClient};
+use crate::interface::repository::{
+    outbox::PrimitiveEvent,
+    webhook::{to_webhook, PendingDelivery, WebhookDatabaseTrait},
+};
+
+/// The value of the `X-Webhook-Signature` header: the HMAC-SHA256 of
+/// `{timestamp}.{body}` keyed with the webhook's secret. Receivers recompute
+/// it to check that a delivery is genuine and reject stale timestamps.
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Queues a delivery for every registered webhook that subscribes to the
+/// event. The deliveries are sent by the `WebhookDeliveryWorker`, so one slow
+/// webhook does not hold up the outbox or the other webhooks.
+pub struct WebhookSubscriptionSink {
+    database: Box<dyn WebhookDatabaseTrait + Send + Sync>,
+}
+
+impl WebhookSubscriptionSink {
+    pub fn new(database: Box<dyn WebhookDatabaseTrait + Send + Sync>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait]
+impl EventSinkTrait for WebhookSubscriptionSink {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn publish(&self, event: &PrimitiveEvent) -> Result<()> {
+        let body = event_json(event)?;
+        for webhook in self.database.find_all().await? {
+            if to_webhook(&webhook)?.matches(&event.event_type) {
+                self.database.enqueue(&webhook.id, event, &body).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sends the queued deliveries. A delivery succeeds on any 2xx status and is
+/// otherwise retried with backoff until `max_attempts`, after which it is
+/// marked failed. Deliveries to private hosts fail unless
+/// `allow_private_hosts` is set.
+pub struct WebhookDeliveryWorker {
+    database: Box<dyn WebhookDatabaseTrait + Send + Sync>,
+    client: HttpClient,
+    config: WebhookConfig,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeliveryReport {
+    pub delivered: usize,
+    pub failed: usize,
+}
+
+impl WebhookDeliveryWorker {
+    pub fn new(
+        database: Box<dyn WebhookDatabaseTrait + Send + Sync>,
+        client: HttpClient,
+        config: WebhookConfig,
+    ) -> Self {
+        let client = if config.allow_private_hosts {
+            client
+        } else {
+            client.public_only()
+        };
+        Self {
+            database,
+            client,
+            config,
+        }
+    }
+
+    /// Sends one batch of due deliveries.
+    pub async fn run_once(&self) -> Result<DeliveryReport> {
+        let now = Utc::now();
+        let lease_until = now + Duration::from_std(self.config.lease())?;
+        let deliveries = self
+            .database
+            .claim(now, lease_until, self.config.batch_size)
+            .await?;
+
+        let mut report = DeliveryReport::default();
+        for delivery in deliveries {
+            match self.send(&delivery).await {
+                Ok(status) if (200..300).contains(&status) => {
+                    self.database
+                        .mark_delivered(&delivery.id, status, Utc::now())
+                        .await?;
+                    report.delivered += 1;
+                }
+                Ok(status) => {
+                    let error = format!("{} answered with status {}", delivery.url, status);
+                    self.fail(&delivery, Some(status), &error).await?;
+                    report.failed += 1;
+                }
+                Err(e) => {
+                    self.fail(&delivery, None, &format!("{:#}", e)).await?;
+                    report.failed += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Sends batches until no delivery is due.
+    pub async fn drain(&self) -> Result<DeliveryReport> {
+        let mut total = DeliveryReport::default();
+        loop {
+            let report = self.run_once().await?;
+            total.delivered += report.delivered;
+            total.failed += report.failed;
+            if report.delivered + report.failed < self.config.batch_size {
+                return Ok(total);
+            }
+        }
+    }
+
+    /// Keeps sending, waiting `poll_interval` whenever nothing is due.
+    pub async fn run(self) {
+        loop {
+            if let Err(e) = self.drain().await {
+                eprintln!("Webhook delivery failed: {:#}", e);
+            }
+            tokio::time::sleep(self.config.poll_interval()).await;
+        }
+    }
+
+    async fn send(&self, delivery: &PendingDelivery) -> Result<u16> {
+        let timestamp = Utc::now().timestamp();
+        let headers = [
+            ("X-Webhook-Id", delivery.webhook_id.to_string()),
+            ("X-Event-Id", delivery.event_id.to_string()),
+            ("X-Event-Type", delivery.event_type.to_string()),
+            ("X-Webhook-Timestamp", timestamp.to_string()),
+            (
+                "X-Webhook-Signature",
+                sign_payload(&delivery.secret, timestamp, &delivery.body),
+            ),
+        ];
+        self.client
+            .post_json(&delivery.url, &headers, &delivery.body)
+            .await
+    }
+
+    async fn fail(
+        &self,
+        delivery: &PendingDelivery,
+        status: Option<u16>,
+        error: &str,
+    ) -> Result<()> {
+        let attempts = delivery.attempts + 1;
+        let retry_at = if attempts < self.config.max_attempts {
+            Some(Utc::now() + Duration::from_std(self.config.retry_delay(attempts))?)
+        } else {
+            None
+        };
+        eprintln!(
+            "Failed to deliver {} {} to webhook {} (attempt {} of {}): {}",
+            delivery.event_type,
+            delivery.event_id,
+            delivery.webhook_id,
+            attempts,
+            self.config.max_attempts,
+            error
+        );
+        self.database
+            .mark_failed(&delivery.id, status, error, retry_at)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, sync::Arc, time::Duration};
+
+    use chrono::Utc;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        sync::Mutex,
+    };
+
+    use crate::infrastructure::{
+        config::WebhookConfig, database::webhook::InMemoryWebhookDatabase, http::HttpClient,
+    };
+    use crate::interface::repository::{
+        outbox::PrimitiveEvent,
+        webhook::{PrimitiveWebhook, WebhookDatabaseTrait},
+    };
+
+    use super::{sign_payload, EventSinkTrait, WebhookDeliveryWorker, WebhookSubscriptionSink};
+
+    struct Received {
+        headers: HashMap<String, String>,
+        body: String,
+    }
+
+    /// A local webhook receiver that fails the first request and accepts the
+    /// rest.
+    async fn stand_in() -> (String, Arc<Mutex<Vec<Received>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let requests = received.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0; 4096];
+                let (head, body) = loop {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length = head
+                            .lines()
+                            .find_map(|l| l.strip_prefix("Content-Length: "))
+                            .map(|l| l.parse::<usize>().unwrap())
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            break (head.to_string(), body.to_string());
+                        }
+                    }
+                };
+                let headers = head
+                    .lines()
+                    .skip(1)
+                    .filter_map(|l| l.split_once(": "))
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect();
+
+                let mut requests = requests.lock().await;
+                requests.push(Received { headers, body });
+                let status = if requests.len() == 1 {
+                    "500 Internal Server Error"
+                } else {
+                    "200 OK"
+                };
+                let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (url, received)
+    }
+
+    #[tokio::test]
+    async fn retries_signed_deliveries_until_the_webhook_accepts_them() {
+        let (url, received) = stand_in().await;
+        let webhook = PrimitiveWebhook {
+            id: "0d6c7a51-64b5-4d1f-8a9e-6f2b0b6c1f0e".to_string(),
+            url,
+            events: vec!["UserRegistered".to_string()],
+            secret: "a-secret-of-some-length".to_string(),
+            created_at: Utc::now(),
+        };
+        let database = InMemoryWebhookDatabase::new();
+        database.save(&webhook).await.unwrap();
+
+        let sink = WebhookSubscriptionSink::new(Box::new(InMemoryWebhookDatabase::new()));
+        for event_type in ["UserRegistered", "UserUpgraded"] {
+            let event = PrimitiveEvent {
+                id: uuid::Uuid::new_v4().to_string(),
+                event_type: event_type.to_string(),
+                aggregate_id: "webhook-user".to_string(),
+                payload: "{}".to_string(),
+                occurred_at: Utc::now(),
+            };
+            sink.publish(&event).await.unwrap();
+        }
+
+        let config = WebhookConfig {
+            retry_base_secs: 0,
+            allow_private_hosts: true,
+            ..WebhookConfig::default()
+        };
+        let worker = WebhookDeliveryWorker::new(
+            Box::new(InMemoryWebhookDatabase::new()),
+            HttpClient::new(Duration::from_secs(5)),
+            config,
+        );
+        assert_eq!(worker.drain().await.unwrap().failed, 1);
+        assert_eq!(worker.drain().await.unwrap().delivered, 1);
+
+        let received = received.lock().await;
+        assert_eq!(received.len(), 2);
+        let last = &received[1];
+        assert_eq!(last.headers["X-Event-Type"], "UserRegistered");
+        let timestamp = last.headers["X-Webhook-Timestamp"].parse().unwrap();
+        assert_eq!(
+            last.headers["X-Webhook-Signature"],
+            sign_payload(&webhook.secret, timestamp, &last.body)
+        );
+
+        let deliveries = database.find_deliveries(&webhook.id, 10).await.unwrap();
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].status, "delivered");
+        assert_eq!(deliveries[0].attempts, 2);
+        assert_eq!(deliveries[0].response_status, Some(200));
+    }
+}
@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::{lookup_host, TcpStream},
+};
+use tokio_native_tls::{native_tls, TlsConnector};
+use url::Url;
+
+use crate::domain::model::webhook::entity::is_public_address;
+
+/// A minimal HTTP/1.1 client for delivering events to webhooks. It sends one
+/// request per connection and only reads the status of the response.
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    timeout: Duration,
+    public_only: bool,
+}
+
+impl HttpClient {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            public_only: false,
+        }
+    }
+
+    /// Refuses to connect to hosts that resolve to a loopback, link-local or
+    /// private address. The connection is made to the addresses that were
+    /// checked, so the host cannot resolve to another one in between.
+    pub fn public_only(self) -> Self {
+        Self {
+            public_only: true,
+            ..self
+        }
+    }
+
+    /// Posts `body` as JSON and returns the status code of the response.
+    pub async fn post_json(
+        &self,
+        url: &str,
+        headers: &[(&str, String)],
+        body: &str,
+    ) -> Result<u16> {
+        let url = Url::parse(url).with_context(|| format!("Invalid URL `{}`", url))?;
+        // IPv6 literals keep their brackets in the URL but not in a lookup,
+        // e.g. `[2001:db8::1]` is looked up as `2001:db8::1`.
+        let host = url
+            .host_str()
+            .ok_or_else(|| anyhow!("URL `{}` has no host", url))?
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_string();
+        let port = url
+            .port_or_known_default()
+            .ok_or_else(|| anyhow!("URL `{}` has no port", url))?;
+
+        let mut request = format!(
+            "POST {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: ddd-in-rust\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+            &url[url::Position::BeforePath..url::Position::AfterQuery],
+            &url[url::Position::BeforeHost..url::Position::AfterPort],
+            body.len()
+        );
+        for (name, value) in headers {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        request.push_str("\r\n");
+        request.push_str(body);
+
+        let exchange = async {
+            let addresses = lookup_host((host.as_str(), port))
+                .await?
+                .collect::<Vec<_>>();
+            if self.public_only {
+                if let Some(address) = addresses.iter().find(|a| !is_public_address(&a.ip())) {
+                    bail!(
+                        "`{}` resolves to the private address {}",
+                        host,
+                        address.ip()
+                    );
+                }
+            }
+            let stream = TcpStream::connect(&addresses[..]).await?;
+            match url.scheme() {
+                "http" => exchange(stream, request.as_bytes()).await,
+                "https" => {
+                    let connector = TlsConnector::from(native_tls::TlsConnector::new()?);
+                    let stream = connector.connect(&host, stream).await?;
+                    exchange(stream, request.as_bytes()).await
+                }
+                scheme => bail!("Unsupported URL scheme `{}`", scheme),
+            }
+        };
+
+        tokio::time::timeout(self.timeout, exchange)
+            .await
+            .map_err(|_| anyhow!("No response from {} within {:?}", url, self.timeout))?
+            .with_context(|| format!("Request to {} failed", url))
+    }
+}
+
+async fn exchange<S: AsyncRead + AsyncWrite + Unpin>(mut stream: S, request: &[u8]) -> Result<u16> {
+    stream.write_all(request).await?;
+    stream.flush().await?;
+
+    let mut response = Vec::new();
+    let mut buffer = [0; 1024];
+    while !response.windows(2).any(|w| w == b"\r\n") {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        response.extend_from_slice(&buffer[..read]);
+    }
+
+    let status_line = String::from_utf8_lossy(&response);
+    status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| anyhow!("Invalid HTTP response: {:?}", status_line.lines().next()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::net::TcpListener;
+
+    use super::HttpClient;
+
+    #[tokio::test]
+    async fn refuses_private_hosts_when_public_only() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+
+        let client = HttpClient::new(Duration::from_secs(5)).public_only();
+        let error = client.post_json(&url, &[], "{}").await.unwrap_err();
+        assert!(format!("{:#}", error).contains("private address"));
+        let localhost = url.replace("127.0.0.1", "localhost");
+        assert!(client.post_json(&localhost, &[], "{}").await.is_err());
+
+        for url in ["http://[::1]:9/hook", "http://[fd00::1]/hook"] {
+            let error = client.post_json(url, &[], "{}").await.unwrap_err();
+            assert!(
+                format!("{:#}", error).contains("private address"),
+                "{}",
+                url
+            );
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod http_client;
+
+pub use self::http_client::*;
@@ -0,0 +1,3 @@
+mod user_purge_job;
+
+pub use self::user_purge_job::*;
@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::infrastructure::database::shared::DatabaseConnection;
+use crate::interface::controller::user_controller::UserController;
+
+/// How often `serve` looks for deleted users past the retention window.
+const PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Purges the users deleted longer than the retention window ago, once an
+/// hour while the web server runs.
+pub struct UserPurgeJob {
+    connection: DatabaseConnection,
+}
+
+impl UserPurgeJob {
+    pub fn new(connection: DatabaseConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Purges what is due and returns the ids of the purged users.
+    pub async fn run_once(&self) -> Result<Vec<String>> {
+        UserController::new(&self.connection).await?.purge().await
+    }
+
+    pub async fn run(self) {
+        loop {
+            match self.run_once().await {
+                Ok(ids) if !ids.is_empty() => println!("Purged {} deleted users.", ids.len()),
+                Ok(_) => {}
+                Err(e) => eprintln!("User purge failed: {:#}", e),
+            }
+            tokio::time::sleep(PURGE_INTERVAL).await;
+        }
+    }
+}
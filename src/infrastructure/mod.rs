@@ -1,3 +1,9 @@
-pub mod database;
+pub mod auth;
 pub mod command_line;
-pub mod web_server;
\ No newline at end of file
+pub mod config;
+pub mod database;
+pub mod event;
+pub mod http;
+pub mod job;
+pub mod rate_limit;
+pub mod web_server;
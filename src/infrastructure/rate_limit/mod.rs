@@ -0,0 +1,3 @@
+mod rate_limiter;
+
+pub use self::rate_limiter::*;
@@ -0,0 +1,155 @@
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::domain::model::audit::entity::Actor;
+use crate::infrastructure::config::RateLimitConfig;
+
+/// How many buckets are kept before the full ones are dropped. A full bucket
+/// holds nothing a new one would not.
+const SWEEP_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// Token buckets of the same size, one per key, kept in memory.
+struct TokenBuckets {
+    capacity: f64,
+    refill_per_sec: f64,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl TokenBuckets {
+    fn new(burst: u32, per_minute: u32) -> Self {
+        Self {
+            capacity: f64::from(burst),
+            refill_per_sec: f64::from(per_minute) / 60.0,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn refilled(&self, bucket: TokenBucket, now: Instant) -> f64 {
+        let elapsed = now
+            .saturating_duration_since(bucket.updated_at)
+            .as_secs_f64();
+        (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity)
+    }
+
+    /// Takes a token from the bucket of `key`, or tells how long until one is
+    /// available.
+    fn take(&self, key: &str, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if buckets.len() >= SWEEP_THRESHOLD {
+            buckets.retain(|_, bucket| self.refilled(*bucket, now) < self.capacity);
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert(TokenBucket {
+            tokens: self.capacity,
+            updated_at: now,
+        });
+        let tokens = self.refilled(*bucket, now);
+        if tokens >= 1.0 {
+            *bucket = TokenBucket {
+                tokens: tokens - 1.0,
+                updated_at: now,
+            };
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - tokens) / self.refill_per_sec,
+            ))
+        }
+    }
+}
+
+/// Limits how often a client IP and a signed-in user may call the web server.
+/// The buckets live in memory, so each server process counts on its own.
+#[derive(Clone)]
+pub struct RateLimiter {
+    ip: Arc<TokenBuckets>,
+    user: Arc<TokenBuckets>,
+    trust_forwarded: bool,
+}
+
+impl RateLimiter {
+    /// The limiter for `config`, or `None` when rate limiting is disabled.
+    pub fn from_config(config: &RateLimitConfig) -> Option<Self> {
+        config.enabled.then(|| Self {
+            ip: Arc::new(TokenBuckets::new(config.ip_burst, config.ip_per_minute)),
+            user: Arc::new(TokenBuckets::new(config.user_burst, config.user_per_minute)),
+            trust_forwarded: config.trust_forwarded,
+        })
+    }
+
+    /// Whether the client IP is read from the forwarding headers.
+    pub fn trusts_forwarded(&self) -> bool {
+        self.trust_forwarded
+    }
+
+    /// Counts a request from `ip` made by `actor`, which is `None` when the
+    /// caller could not be authenticated. Fails with the time to wait before
+    /// retrying when a limit is exceeded. API keys and the system are never
+    /// limited.
+    pub fn check(&self, ip: Option<IpAddr>, actor: Option<&Actor>) -> Result<(), Duration> {
+        self.check_at(ip, actor, Instant::now())
+    }
+
+    fn check_at(
+        &self,
+        ip: Option<IpAddr>,
+        actor: Option<&Actor>,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        if matches!(actor, Some(Actor::ApiKey { .. } | Actor::System(_))) {
+            return Ok(());
+        }
+        if let Some(ip) = ip {
+            self.ip.take(&ip.to_string(), now)?;
+        }
+        if let Some(Actor::User(id)) = actor {
+            self.user.take(&id.to_string(), now)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::model::{api_key::entity::ApiKeyId, user::entity::UserId};
+
+    #[test]
+    fn limits_each_ip_until_its_bucket_refills() {
+        let limiter = RateLimiter::from_config(&RateLimitConfig {
+            ip_burst: 2,
+            ip_per_minute: 60,
+            ..RateLimitConfig::default()
+        })
+        .unwrap();
+        let ip = Some("10.0.0.1".parse().unwrap());
+        let other = Some("10.0.0.2".parse().unwrap());
+        let user = Actor::User(UserId::new("alice").unwrap());
+        let api_key = Actor::ApiKey {
+            id: ApiKeyId::new("billing").unwrap(),
+            scopes: Vec::new(),
+        };
+        let now = Instant::now();
+
+        assert!(limiter.check_at(ip, None, now).is_ok());
+        assert!(limiter.check_at(ip, Some(&user), now).is_ok());
+        let retry_after = limiter.check_at(ip, None, now).unwrap_err();
+        assert!(retry_after <= Duration::from_secs(1));
+
+        assert!(limiter.check_at(other, None, now).is_ok());
+        assert!(limiter.check_at(ip, Some(&api_key), now).is_ok());
+        assert!(limiter
+            .check_at(ip, None, now + Duration::from_secs(1))
+            .is_ok());
+    }
+}
@@ -0,0 +1,209 @@
+use actix_web::{
+    body::{self, BoxBody, MessageBody},
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    error::ErrorInternalServerError,
+    http::{Method, StatusCode},
+    middleware::Next,
+    web, HttpMessage, HttpResponse,
+};
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::domain::model::audit::entity::Actor;
+use crate::infrastructure::{config::ServerConfig, database::shared::DatabaseConnection};
+use crate::interface::repository::idempotency::{
+    IdempotencyDatabaseTrait, PrimitiveIdempotencyRecord, PrimitiveIdempotentResponse,
+};
+
+/// The header a client names a request with to make retrying it safe.
+const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+/// Set on a response that is sent again for a retried request.
+const REPLAYED_HEADER: &str = "Idempotency-Replayed";
+const MAX_KEY_LENGTH: usize = 255;
+/// How long a key stays locked while its first request is handled. Should
+/// the server stop while handling it, the key is free again after this.
+const LOCK_TIMEOUT_SECS: i64 = 60;
+
+/// Handles a changing request with an `Idempotency-Key` header once per key
+/// and caller. The response is kept for `server.idempotency_ttl_secs` and
+/// sent again, with an `Idempotency-Replayed` header, when the request is
+/// retried. Reusing a key for another request is answered with `422`, and
+/// retrying while the first request is still handled with `409`. Server
+/// errors are not kept, so that the request can be retried. Anonymous callers
+/// cannot be told apart, so their keys are scoped to the request as well: only
+/// the same request with the same key is answered again.
+pub async fn idempotency<B: MessageBody + 'static>(
+    mut request: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<BoxBody>, actix_web::Error> {
+    let key = match idempotency_key(&request) {
+        Ok(Some(key)) => key,
+        Ok(None) => return Ok(next.call(request).await?.map_into_boxed_body()),
+        Err(message) => return Ok(request.into_response(HttpResponse::BadRequest().body(message))),
+    };
+    let connection = match request.app_data::<web::Data<DatabaseConnection>>() {
+        Some(connection) => connection.clone(),
+        None => return Err(ErrorInternalServerError("No database connection")),
+    };
+    let ttl = request
+        .app_data::<web::Data<ServerConfig>>()
+        .map(|config| config.idempotency_ttl())
+        .unwrap_or_default();
+    let database = connection
+        .idempotency_database()
+        .map_err(ErrorInternalServerError)?;
+
+    let body = request.extract::<web::Bytes>().await?;
+    let fingerprint = fingerprint(&request, &body);
+    request.set_payload(Payload::from(body));
+    let scope = match request.extensions().get::<Actor>() {
+        Some(Actor::Anonymous) | None => format!("{}:{}", Actor::Anonymous, fingerprint),
+        Some(actor) => actor.to_string(),
+    };
+
+    let now = Utc::now();
+    let record = PrimitiveIdempotencyRecord {
+        scope,
+        key,
+        fingerprint,
+        response: None,
+        created_at: now,
+        expires_at: now + Duration::from_std(ttl).map_err(ErrorInternalServerError)?,
+        locked_until: now + Duration::seconds(LOCK_TIMEOUT_SECS),
+    };
+    if !database
+        .claim(&record)
+        .await
+        .map_err(ErrorInternalServerError)?
+    {
+        let response = previous_response(database.as_ref(), &record).await?;
+        return Ok(request.into_response(response));
+    }
+
+    let response = match next.call(request).await {
+        Ok(response) if !response.status().is_server_error() => response,
+        result => {
+            release(database.as_ref(), &record).await;
+            return result.map(ServiceResponse::map_into_boxed_body);
+        }
+    };
+
+    let (http_request, http_response) = response.into_parts();
+    let (head, body) = http_response.into_parts();
+    let body = match body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(e) => {
+            release(database.as_ref(), &record).await;
+            return Err(ErrorInternalServerError(e.into()));
+        }
+    };
+    let stored = PrimitiveIdempotentResponse {
+        status: head.status().as_u16(),
+        headers: head
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect(),
+        body: body.to_vec(),
+    };
+    // The change is made, so the response is sent even if it cannot be kept.
+    if let Err(e) = database.complete(&record.scope, &record.key, &stored).await {
+        eprintln!(
+            "Failed to keep the response for idempotency key {}: {:#}",
+            record.key, e
+        );
+    }
+
+    Ok(ServiceResponse::new(
+        http_request,
+        head.set_body(body).map_into_boxed_body(),
+    ))
+}
+
+/// The key of a changing request, `None` for requests without one and an
+/// error message for a key that cannot be used.
+fn idempotency_key(request: &ServiceRequest) -> Result<Option<String>, String> {
+    if !matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    ) {
+        return Ok(None);
+    }
+    let value = match request.headers().get(IDEMPOTENCY_KEY_HEADER) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    match value.to_str() {
+        Ok(key) if !key.is_empty() && key.len() <= MAX_KEY_LENGTH => Ok(Some(key.to_string())),
+        _ => Err(format!(
+            "The {} header must hold 1 to {} visible ASCII characters.",
+            IDEMPOTENCY_KEY_HEADER, MAX_KEY_LENGTH
+        )),
+    }
+}
+
+/// Tells requests apart that reuse a key: the method, path, query, content
+/// type and body.
+fn fingerprint(request: &ServiceRequest, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        request.method().as_str(),
+        request.path(),
+        request.query_string(),
+        request.content_type(),
+    ] {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    hasher.update(body);
+    hex::encode(hasher.finalize())
+}
+
+/// The answer to a request whose key is already taken.
+async fn previous_response(
+    database: &(dyn IdempotencyDatabaseTrait + Send + Sync),
+    record: &PrimitiveIdempotencyRecord,
+) -> Result<HttpResponse, actix_web::Error> {
+    let previous = database
+        .find(&record.scope, &record.key)
+        .await
+        .map_err(ErrorInternalServerError)?;
+
+    let response = match previous {
+        Some(previous) if previous.fingerprint != record.fingerprint => {
+            HttpResponse::UnprocessableEntity().body(format!(
+                "The {} was already used for another request.",
+                IDEMPOTENCY_KEY_HEADER
+            ))
+        }
+        Some(PrimitiveIdempotencyRecord {
+            response: Some(response),
+            ..
+        }) => {
+            let status = StatusCode::from_u16(response.status).map_err(ErrorInternalServerError)?;
+            let mut builder = HttpResponse::build(status);
+            for header in response.headers {
+                builder.append_header(header);
+            }
+            builder
+                .insert_header((REPLAYED_HEADER, "true"))
+                .body(response.body)
+        }
+        _ => HttpResponse::Conflict().body(format!(
+            "A request with this {} is still being handled. Retry later.",
+            IDEMPOTENCY_KEY_HEADER
+        )),
+    };
+    Ok(response)
+}
+
+/// Frees the key after a failed request, so that a retry handles it again.
+async fn release(
+    database: &(dyn IdempotencyDatabaseTrait + Send + Sync),
+    record: &PrimitiveIdempotencyRecord,
+) {
+    if let Err(e) = database.release(&record.scope, &record.key).await {
+        eprintln!("Failed to release idempotency key {}: {:#}", record.key, e);
+    }
+}
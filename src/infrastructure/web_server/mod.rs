@@ -1,39 +1,332 @@
-use actix_web::{delete, get, post, put, web, App, HttpResponse, HttpServer, Responder};
+mod idempotency;
+
+use actix_web::{
+    delete,
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    error::{InternalError, JsonPayloadError, QueryPayloadError},
+    get,
+    http::header,
+    middleware::{from_fn, Next},
+    post, put, web, App, FromRequest, HttpMessage, HttpRequest, HttpResponse, HttpResponseBuilder,
+    HttpServer, Responder,
+};
 use serde::{Deserialize, Serialize};
-use std::io;
+use serde_json::Value;
+use std::{
+    future::{ready, Ready},
+    io,
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
 
+use self::idempotency::idempotency;
+use crate::application::webhook::{WebhookData, WebhookDeliveryData};
+use crate::domain::model::{
+    audit::entity::Actor,
+    error::{DomainError, FieldError},
+};
+use crate::infrastructure::{
+    auth::TokenAuthenticator,
+    config::{ServerConfig, WebhookConfig},
+    database::shared::DatabaseConnection,
+    rate_limit::RateLimiter,
+};
 use crate::interface::controller::{
-    club_controller::{ClubController, PostClubArgs, PostMemberArgs},
+    api_key_controller::{ApiKeyController, AuthenticateArgs},
+    audit_controller::{AuditController, GetAuditArgs},
+    bulk_format::BulkFormat,
+    club_controller::{
+        self, ClubController, ClubSummaryData, ClubUserData, ExportClubsArgs, GetClubArgs,
+        GetRecommendationArgs, ListClubsArgs, PostClubArgs, PostMemberArgs, PutOwnerArgs,
+    },
     user_controller::{
-        DeleteArgs, DeletePremiumArgs, GetArgs, PostArgs, PostPremiumArgs, PutArgs, UserController,
+        DeleteArgs, DeletePremiumArgs, ExportArgs, GetArgs, GetClubsArgs, GetProfileResult,
+        ImportArgs, ListArgs, PostArgs, PostPremiumArgs, PutArgs, RestoreArgs, UserController,
+    },
+    webhook_controller::{
+        DeleteWebhookArgs, GetDeliveriesArgs, GetWebhookArgs, ListWebhooksArgs, PostWebhookArgs,
+        WebhookController,
     },
 };
 
-pub struct WebServer;
+pub struct WebServer {
+    config: ServerConfig,
+    webhook_config: WebhookConfig,
+    connection: DatabaseConnection,
+    authenticator: Option<TokenAuthenticator>,
+    rate_limiter: Option<RateLimiter>,
+}
 
 impl WebServer {
     pub async fn run(&self) -> io::Result<()> {
-        HttpServer::new(move || {
+        let connection = web::Data::new(self.connection.clone());
+        let authenticator = web::Data::new(self.authenticator.clone());
+        // Shared by every worker so that a client cannot spread its requests
+        // over them.
+        let rate_limiter = web::Data::new(self.rate_limiter.clone());
+        let config = web::Data::new(self.config.clone());
+        let webhook_config = web::Data::new(self.webhook_config.clone());
+        let server = HttpServer::new(move || {
             App::new()
-                .service(get_user)
-                .service(post_user)
-                .service(post_user)
-                .service(delete_user)
-                .service(put_user)
-                .service(post_club)
-                .service(post_member)
-                .service(post_premium)
-                .service(delete_premium)
-                .service(get_recommendation)
-            // .route("/club/{id}/members", web::post().to(post_member))
+                .app_data(web::Data::clone(&connection))
+                .app_data(web::Data::clone(&authenticator))
+                .app_data(web::Data::clone(&rate_limiter))
+                .app_data(web::Data::clone(&config))
+                .app_data(web::Data::clone(&webhook_config))
+                .app_data(web::JsonConfig::default().error_handler(json_error))
+                .app_data(web::QueryConfig::default().error_handler(query_error))
+                // Runs after admit, which knows who the caller is.
+                .wrap(from_fn(idempotency))
+                .wrap(from_fn(admit))
+                .configure(routes)
         })
-        .bind(("127.0.0.1", 8080))?
-        .run()
-        .await
+        .client_request_timeout(self.config.request_timeout());
+
+        let server = match self.config.workers {
+            Some(workers) => server.workers(workers),
+            None => server,
+        };
+
+        server.bind(&self.config.bind_address)?.run().await
     }
 
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(
+        config: ServerConfig,
+        webhook_config: WebhookConfig,
+        connection: DatabaseConnection,
+        authenticator: Option<TokenAuthenticator>,
+        rate_limiter: Option<RateLimiter>,
+    ) -> Self {
+        Self {
+            config,
+            webhook_config,
+            connection,
+            authenticator,
+            rate_limiter,
+        }
+    }
+}
+
+/// Registers every route, on the server and on the apps of the tests.
+fn routes(config: &mut web::ServiceConfig) {
+    config
+        // Registered before get_user and get_club so that the export
+        // paths are not taken for an id.
+        .service(export_users)
+        .service(export_clubs)
+        .service(import_users)
+        .service(get_user)
+        .service(get_users)
+        .service(get_user_clubs)
+        .service(post_user)
+        .service(delete_user)
+        .service(restore_user)
+        .service(put_user)
+        .service(post_club)
+        .service(post_member)
+        .service(put_owner)
+        .service(post_premium)
+        .service(delete_premium)
+        // Registered before get_club so that /club/recommend is not
+        // taken for a club id.
+        .service(get_recommendation)
+        .service(get_club)
+        .service(get_clubs)
+        .service(post_webhook)
+        .service(get_webhooks)
+        .service(get_webhook)
+        .service(delete_webhook)
+        .service(get_webhook_deliveries)
+        .service(get_audit);
+}
+
+fn error_response(e: anyhow::Error, mut fallback: HttpResponseBuilder) -> HttpResponse {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Validation(errors)) => unprocessable(errors),
+        Some(DomainError::NotFound(_)) => HttpResponse::NotFound().body(e.to_string()),
+        Some(DomainError::Conflict(_)) => HttpResponse::Conflict().body(e.to_string()),
+        Some(DomainError::Unauthorized(_)) => unauthorized(&e.to_string()),
+        Some(DomainError::Forbidden(_)) => HttpResponse::Forbidden().body(e.to_string()),
+        None => fallback.body(e.to_string()),
+    }
+}
+
+fn unauthorized(message: &str) -> HttpResponse {
+    HttpResponse::Unauthorized()
+        .insert_header((header::WWW_AUTHENTICATE, "Bearer"))
+        .body(message.to_string())
+}
+
+#[derive(Serialize)]
+struct FieldErrorResult {
+    field: String,
+    rule: String,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct ValidationErrorResult {
+    errors: Vec<FieldErrorResult>,
+}
+
+/// Answers requests whose fields break the domain's rules, or that cannot be
+/// read at all, with `422` and every broken rule.
+fn unprocessable(errors: &[FieldError]) -> HttpResponse {
+    let errors = errors
+        .iter()
+        .map(|e| FieldErrorResult {
+            field: e.field.to_string(),
+            rule: e.rule.to_string(),
+            message: e.message.to_string(),
+        })
+        .collect();
+    HttpResponse::UnprocessableEntity().json(ValidationErrorResult { errors })
+}
+
+/// Reports a JSON body that is not valid JSON or does not have the fields a
+/// handler expects. Other errors, e.g. a missing content type, keep the
+/// default response.
+fn json_error(error: JsonPayloadError, _: &HttpRequest) -> actix_web::Error {
+    match &error {
+        JsonPayloadError::Deserialize(e) => {
+            let rule = if e.is_data() { "schema" } else { "json" };
+            let response = unprocessable(&[FieldError::new("body", rule, &e.to_string())]);
+            InternalError::from_response(error, response).into()
+        }
+        _ => error.into(),
+    }
+}
+
+/// Reports a query string whose parameters cannot be read, e.g. a `limit`
+/// that is not a number.
+fn query_error(error: QueryPayloadError, _: &HttpRequest) -> actix_web::Error {
+    let message = match &error {
+        QueryPayloadError::Deserialize(e) => e.to_string(),
+        _ => error.to_string(),
+    };
+    let response = unprocessable(&[FieldError::new("query", "format", &message)]);
+    InternalError::from_response(error, response).into()
+}
+
+/// The header other services send their API key in.
+const API_KEY_HEADER: &str = "X-Api-Key";
+
+/// Works out who sent each request, applies the rate limits and keeps the
+/// caller in the request extensions for `RequestActor`. A request over its
+/// limit is answered with `429`, one with a credential that cannot be
+/// verified with `401`, both before it reaches a handler. Failed attempts
+/// count against the client IP too.
+async fn admit<B>(
+    request: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<B>, actix_web::Error> {
+    let actor = request_actor(request.request()).await;
+
+    let limiter = request
+        .app_data::<web::Data<Option<RateLimiter>>>()
+        .and_then(|limiter| limiter.as_ref().clone());
+    if let Some(limiter) = limiter {
+        let ip = client_ip(&request, &limiter);
+        if let Err(retry_after) = limiter.check(ip, actor.as_ref().ok()) {
+            let response = too_many_requests(retry_after);
+            return Err(InternalError::from_response("Too many requests", response).into());
+        }
+    }
+
+    let actor = match actor {
+        Ok(actor) => actor,
+        Err(e) => {
+            let message = e.to_string();
+            let response = error_response(e, HttpResponse::InternalServerError());
+            return Err(InternalError::from_response(message, response).into());
+        }
+    };
+    request.extensions_mut().insert(actor);
+    next.call(request).await
+}
+
+/// The address of the client, read from the forwarding headers when the
+/// limiter is configured to trust them.
+fn client_ip(request: &ServiceRequest, limiter: &RateLimiter) -> Option<IpAddr> {
+    if limiter.trusts_forwarded() {
+        let info = request.connection_info();
+        let address = info.realip_remote_addr()?;
+        address
+            .parse::<SocketAddr>()
+            .map(|a| a.ip())
+            .or_else(|_| address.parse::<IpAddr>())
+            .ok()
+    } else {
+        request.peer_addr().map(|a| a.ip())
+    }
+}
+
+fn too_many_requests(retry_after: Duration) -> HttpResponse {
+    let secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+    HttpResponse::TooManyRequests()
+        .insert_header((header::RETRY_AFTER, secs.to_string()))
+        .body(format!("Too many requests. Retry in {} seconds.", secs))
+}
+
+/// The caller of a request: the API key in the `X-Api-Key` header, the user of
+/// the bearer token in the `Authorization` header, or an anonymous caller when
+/// there is neither.
+async fn request_actor(request: &HttpRequest) -> anyhow::Result<Actor> {
+    let api_key = request.headers().get(API_KEY_HEADER);
+    let authorization = request.headers().get(header::AUTHORIZATION);
+
+    match (api_key, authorization) {
+        (None, None) => Ok(Actor::Anonymous),
+        (Some(_), Some(_)) => Err(DomainError::Unauthorized(format!(
+            "Send either an {} header or an Authorization header, not both.",
+            API_KEY_HEADER
+        ))
+        .into()),
+        (Some(value), None) => {
+            let key = value
+                .to_str()
+                .map_err(|_| DomainError::Unauthorized("The API key is not valid.".to_string()))?;
+            let connection = request
+                .app_data::<web::Data<DatabaseConnection>>()
+                .ok_or_else(|| anyhow::anyhow!("The database connection is not configured."))?;
+            let controller = ApiKeyController::new(connection).await?;
+            let args = AuthenticateArgs {
+                key: key.trim().to_string(),
+            };
+            controller.authenticate(args).await
+        }
+        (None, Some(value)) => {
+            let token = value
+                .to_str()
+                .ok()
+                .and_then(|v| v.strip_prefix("Bearer "))
+                .ok_or_else(|| {
+                    DomainError::Unauthorized(
+                        "The Authorization header must hold a bearer token.".to_string(),
+                    )
+                })?;
+            let authenticator = request
+                .app_data::<web::Data<Option<TokenAuthenticator>>>()
+                .and_then(|authenticator| authenticator.as_ref().as_ref())
+                .ok_or_else(|| {
+                    DomainError::Unauthorized("Access tokens are not enabled.".to_string())
+                })?;
+
+            Ok(Actor::User(authenticator.authenticate(token.trim())?))
+        }
+    }
+}
+
+/// Who sent the request, as worked out by the `admit` middleware.
+struct RequestActor(Actor);
+
+impl FromRequest for RequestActor {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let actor = request.extensions().get::<Actor>().cloned();
+        ready(Ok(Self(actor.unwrap_or(Actor::Anonymous))))
     }
 }
 
@@ -43,24 +336,210 @@ struct GetUserResult {
     name: String,
 }
 
+#[derive(Serialize)]
+struct GetUserProfileResult {
+    id: String,
+    name: String,
+    is_premium: bool,
+    premium_since: Option<String>,
+    premium_expires_at: Option<String>,
+    clubs_count: usize,
+    created_at: String,
+}
+
+impl From<GetProfileResult> for GetUserProfileResult {
+    fn from(user: GetProfileResult) -> Self {
+        Self {
+            id: user.id,
+            name: user.name,
+            is_premium: user.is_premium,
+            premium_since: user.premium_since,
+            premium_expires_at: user.premium_expires_at,
+            clubs_count: user.clubs_count,
+            created_at: user.created_at,
+        }
+    }
+}
+
 #[get("/user/{id}")]
-async fn get_user(path: web::Path<(String,)>) -> impl Responder {
+async fn get_user(
+    connection: web::Data<DatabaseConnection>,
+    path: web::Path<(String,)>,
+) -> impl Responder {
     let id = path.into_inner().0;
 
     let args = GetArgs { id };
-    if let Ok(controller) = UserController::new().await {
+    if let Ok(controller) = UserController::new(&connection).await {
         match controller.get(args).await {
             Ok(u) => match u {
-                Some(u) => {
-                    let result = GetUserResult {
-                        id: u.id,
-                        name: u.name,
-                    };
-                    HttpResponse::Ok().body(serde_json::to_string(&result).unwrap())
-                }
+                Some(u) => HttpResponse::Ok().json(GetUserProfileResult::from(u)),
                 None => HttpResponse::NotFound().body("Not Found"),
             },
-            Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Serialize)]
+struct GetUserClubsResult {
+    owned: Vec<ClubSummaryResult>,
+    member_of: Vec<ClubSummaryResult>,
+}
+
+#[get("/user/{id}/clubs")]
+async fn get_user_clubs(
+    connection: web::Data<DatabaseConnection>,
+    path: web::Path<(String,)>,
+) -> impl Responder {
+    let id = path.into_inner().0;
+
+    let args = GetClubsArgs { id };
+    if let Ok(controller) = UserController::new(&connection).await {
+        match controller.get_clubs(args).await {
+            Ok(Some(c)) => HttpResponse::Ok().json(GetUserClubsResult {
+                owned: c.owned.into_iter().map(ClubSummaryResult::from).collect(),
+                member_of: c
+                    .member_of
+                    .into_iter()
+                    .map(ClubSummaryResult::from)
+                    .collect(),
+            }),
+            Ok(None) => HttpResponse::NotFound().body("Not Found"),
+            Err(e) => error_response(e, HttpResponse::NotFound()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Deserialize)]
+struct GetUsersQuery {
+    cursor: Option<String>,
+    limit: Option<usize>,
+    premium: Option<bool>,
+    name_prefix: Option<String>,
+    sort: Option<String>,
+}
+
+#[derive(Serialize)]
+struct GetUsersResult {
+    users: Vec<GetUserResult>,
+    next_cursor: Option<String>,
+}
+
+#[get("/user")]
+async fn get_users(
+    connection: web::Data<DatabaseConnection>,
+    query: web::Query<GetUsersQuery>,
+) -> impl Responder {
+    let query = query.into_inner();
+    let args = ListArgs {
+        cursor: query.cursor,
+        limit: query.limit,
+        premium: query.premium,
+        name_prefix: query.name_prefix,
+        sort: query.sort,
+    };
+    if let Ok(controller) = UserController::new(&connection).await {
+        match controller.list(args).await {
+            Ok(page) => {
+                let result = GetUsersResult {
+                    users: page
+                        .users
+                        .into_iter()
+                        .map(|u| GetUserResult {
+                            id: u.id,
+                            name: u.name,
+                        })
+                        .collect(),
+                    next_cursor: page.next_cursor,
+                };
+                HttpResponse::Ok().json(result)
+            }
+            Err(e) => error_response(e, HttpResponse::BadRequest()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Deserialize)]
+struct ImportUsersQuery {
+    format: Option<String>,
+    #[serde(default)]
+    dry_run: bool,
+}
+
+/// Reads the users from the request body, as CSV when the format is `csv` or
+/// the body is sent as `text/csv`, and as JSON lines otherwise.
+#[post("/user/import")]
+async fn import_users(
+    connection: web::Data<DatabaseConnection>,
+    request: HttpRequest,
+    actor: RequestActor,
+    query: web::Query<ImportUsersQuery>,
+    body: String,
+) -> impl Responder {
+    let query = query.into_inner();
+    let format = match query.format.as_deref() {
+        Some(format) => BulkFormat::parse(format),
+        None if request.content_type() == "text/csv" => Ok(BulkFormat::Csv),
+        None => Ok(BulkFormat::JsonLines),
+    };
+    let format = match format {
+        Ok(format) => format,
+        Err(e) => return error_response(e, HttpResponse::BadRequest()),
+    };
+
+    let args = ImportArgs {
+        actor: actor.0,
+        format,
+        data: body,
+        dry_run: query.dry_run,
+    };
+    if let Ok(controller) = UserController::new(&connection).await {
+        match controller.import(args).await {
+            Ok(report) => HttpResponse::Ok().json(report),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Deserialize)]
+struct ExportQuery {
+    format: Option<String>,
+}
+
+impl ExportQuery {
+    fn format(&self) -> anyhow::Result<BulkFormat> {
+        BulkFormat::parse(self.format.as_deref().unwrap_or("jsonl"))
+    }
+}
+
+#[get("/user/export")]
+async fn export_users(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    query: web::Query<ExportQuery>,
+) -> impl Responder {
+    let format = match query.format() {
+        Ok(format) => format,
+        Err(e) => return error_response(e, HttpResponse::BadRequest()),
+    };
+    if let Ok(controller) = UserController::new(&connection).await {
+        let args = ExportArgs {
+            actor: actor.0,
+            format,
+        };
+        match controller.export(args).await {
+            Ok(data) => HttpResponse::Ok()
+                .content_type(format.content_type())
+                .body(data),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
         }
     } else {
         HttpResponse::InternalServerError().body("Internal Server Error")
@@ -73,29 +552,40 @@ struct PostUserPayload {
 }
 
 #[post("/user")]
-async fn post_user(body: web::Json<PostUserPayload>) -> impl Responder {
-    if let Ok(controller) = UserController::new().await {
+async fn post_user(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    body: web::Json<PostUserPayload>,
+) -> impl Responder {
+    if let Ok(controller) = UserController::new(&connection).await {
         let args = PostArgs {
+            actor: actor.0,
             name: body.name.to_owned(),
         };
         match controller.post(args).await {
-            Ok(_) => HttpResponse::Ok().body("OK"),
-            Err(e) => HttpResponse::NotAcceptable().body(e.to_string()),
+            Ok(user) => HttpResponse::Created()
+                .insert_header((header::LOCATION, format!("/user/{}", user.id)))
+                .json(GetUserProfileResult::from(user)),
+            Err(e) => error_response(e, HttpResponse::NotAcceptable()),
         }
     } else {
-        HttpResponse::InternalServerError().body("Internal Sever Error")
+        HttpResponse::InternalServerError().body("Internal Server Error")
     }
 }
 
 #[delete("/user/{id}")]
-async fn delete_user(path: web::Path<(String,)>) -> impl Responder {
+async fn delete_user(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    path: web::Path<(String,)>,
+) -> impl Responder {
     let id = path.into_inner().0;
 
-    let args = DeleteArgs { id };
-    if let Ok(controller) = UserController::new().await {
+    let args = DeleteArgs { actor: actor.0, id };
+    if let Ok(controller) = UserController::new(&connection).await {
         match controller.delete(args).await {
             Ok(_) => HttpResponse::Ok().body("OK"),
-            Err(e) => HttpResponse::NotFound().body(e.to_string()),
+            Err(e) => error_response(e, HttpResponse::NotFound()),
         }
     } else {
         HttpResponse::InternalServerError().body("Internal Server Error")
@@ -108,16 +598,40 @@ struct PutUserPayload {
     name: String,
 }
 
+#[post("/user/{id}/restore")]
+async fn restore_user(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    path: web::Path<(String,)>,
+) -> impl Responder {
+    let id = path.into_inner().0;
+
+    let args = RestoreArgs { actor: actor.0, id };
+    if let Ok(controller) = UserController::new(&connection).await {
+        match controller.restore(args).await {
+            Ok(_) => HttpResponse::Ok().body("OK"),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
 #[put("/user")]
-async fn put_user(body: web::Json<PutUserPayload>) -> impl Responder {
-    if let Ok(controller) = UserController::new().await {
+async fn put_user(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    body: web::Json<PutUserPayload>,
+) -> impl Responder {
+    if let Ok(controller) = UserController::new(&connection).await {
         let args = PutArgs {
+            actor: actor.0,
             id: body.id.to_owned(),
             name: body.name.to_owned(),
         };
         match controller.put(args).await {
             Ok(_) => HttpResponse::Ok().body("OK"),
-            Err(e) => HttpResponse::NotFound().body(e.to_string()),
+            Err(e) => error_response(e, HttpResponse::NotFound()),
         }
     } else {
         HttpResponse::InternalServerError().body("Internal Server Error")
@@ -131,15 +645,161 @@ struct PostClubPayload {
 }
 
 #[post("/club")]
-async fn post_club(body: web::Json<PostClubPayload>) -> impl Responder {
-    if let Ok(controller) = ClubController::new().await {
+async fn post_club(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    body: web::Json<PostClubPayload>,
+) -> impl Responder {
+    if let Ok(controller) = ClubController::new(&connection).await {
         let args = PostClubArgs {
+            actor: actor.0,
             user_id: body.user_id.to_string(),
             name: body.name.to_string(),
         };
         match controller.post_club(args).await {
-            Ok(_) => HttpResponse::Ok().body("OK"),
-            Err(e) => HttpResponse::NotFound().body(e.to_string()),
+            Ok(club) => HttpResponse::Created()
+                .insert_header((header::LOCATION, format!("/club/{}", club.id)))
+                .json(GetClubResult::from(club)),
+            Err(e) => error_response(e, HttpResponse::NotFound()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[get("/club/export")]
+async fn export_clubs(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    query: web::Query<ExportQuery>,
+) -> impl Responder {
+    let format = match query.format() {
+        Ok(format) => format,
+        Err(e) => return error_response(e, HttpResponse::BadRequest()),
+    };
+    if let Ok(controller) = ClubController::new(&connection).await {
+        let args = ExportClubsArgs {
+            actor: actor.0,
+            format,
+        };
+        match controller.export_clubs(args).await {
+            Ok(data) => HttpResponse::Ok()
+                .content_type(format.content_type())
+                .body(data),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Serialize)]
+struct ClubUserResult {
+    id: String,
+    name: String,
+}
+
+impl From<ClubUserData> for ClubUserResult {
+    fn from(user: ClubUserData) -> Self {
+        Self {
+            id: user.id,
+            name: user.name,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct GetClubResult {
+    id: String,
+    name: String,
+    owner: Option<ClubUserResult>,
+    members: Vec<ClubUserResult>,
+}
+
+impl From<club_controller::GetClubResult> for GetClubResult {
+    fn from(club: club_controller::GetClubResult) -> Self {
+        Self {
+            id: club.id,
+            name: club.name,
+            owner: club.owner.map(ClubUserResult::from),
+            members: club.members.into_iter().map(ClubUserResult::from).collect(),
+        }
+    }
+}
+
+#[get("/club/{id}")]
+async fn get_club(
+    connection: web::Data<DatabaseConnection>,
+    path: web::Path<(String,)>,
+) -> impl Responder {
+    let id = path.into_inner().0;
+
+    let args = GetClubArgs { id };
+    if let Ok(controller) = ClubController::new(&connection).await {
+        match controller.get_club(args).await {
+            Ok(Some(c)) => HttpResponse::Ok().json(GetClubResult::from(c)),
+            Ok(None) => HttpResponse::NotFound().body("Not Found"),
+            Err(e) => error_response(e, HttpResponse::NotFound()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Deserialize)]
+struct GetClubsQuery {
+    cursor: Option<String>,
+    limit: Option<usize>,
+    name: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ClubSummaryResult {
+    id: String,
+    name: String,
+    owner: String,
+    member_count: usize,
+}
+
+impl From<ClubSummaryData> for ClubSummaryResult {
+    fn from(club: ClubSummaryData) -> Self {
+        Self {
+            id: club.id,
+            name: club.name,
+            owner: club.owner,
+            member_count: club.member_count,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct GetClubsResult {
+    clubs: Vec<ClubSummaryResult>,
+    next_cursor: Option<String>,
+}
+
+#[get("/club")]
+async fn get_clubs(
+    connection: web::Data<DatabaseConnection>,
+    query: web::Query<GetClubsQuery>,
+) -> impl Responder {
+    let query = query.into_inner();
+    let args = ListClubsArgs {
+        cursor: query.cursor,
+        limit: query.limit,
+        name: query.name,
+    };
+    if let Ok(controller) = ClubController::new(&connection).await {
+        match controller.list_clubs(args).await {
+            Ok(page) => HttpResponse::Ok().json(GetClubsResult {
+                clubs: page
+                    .clubs
+                    .into_iter()
+                    .map(ClubSummaryResult::from)
+                    .collect(),
+                next_cursor: page.next_cursor,
+            }),
+            Err(e) => error_response(e, HttpResponse::BadRequest()),
         }
     } else {
         HttpResponse::InternalServerError().body("Internal Server Error")
@@ -153,18 +813,49 @@ struct PostMemberPayload {
 
 #[post("/club/{id}/members")]
 async fn post_member(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
     path: web::Path<(String,)>,
     body: web::Json<PostMemberPayload>,
 ) -> impl Responder {
     let club_id = path.into_inner().0;
-    if let Ok(controller) = ClubController::new().await {
+    if let Ok(controller) = ClubController::new(&connection).await {
         let args = PostMemberArgs {
+            actor: actor.0,
             club_id,
             user_id: body.user_id.to_string(),
         };
         match controller.post_member(args).await {
             Ok(_) => HttpResponse::Ok().body("OK"),
-            Err(e) => HttpResponse::BadRequest().body(e.to_string()),
+            Err(e) => error_response(e, HttpResponse::BadRequest()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Deserialize)]
+struct PutOwnerPayload {
+    user_id: String,
+}
+
+#[put("/club/{id}/owner")]
+async fn put_owner(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    path: web::Path<(String,)>,
+    body: web::Json<PutOwnerPayload>,
+) -> impl Responder {
+    let club_id = path.into_inner().0;
+    if let Ok(controller) = ClubController::new(&connection).await {
+        let args = PutOwnerArgs {
+            actor: actor.0,
+            club_id,
+            user_id: body.user_id.to_string(),
+        };
+        match controller.put_owner(args).await {
+            Ok(_) => HttpResponse::Ok().body("OK"),
+            Err(e) => error_response(e, HttpResponse::BadRequest()),
         }
     } else {
         HttpResponse::InternalServerError().body("Internal Server Error")
@@ -172,13 +863,20 @@ async fn post_member(
 }
 
 #[post("/user/{id}/membership")]
-async fn post_premium(path: web::Path<(String,)>) -> impl Responder {
+async fn post_premium(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    path: web::Path<(String,)>,
+) -> impl Responder {
     let user_id = path.into_inner().0;
-    if let Ok(controller) = UserController::new().await {
-        let args = PostPremiumArgs { id: user_id };
+    if let Ok(controller) = UserController::new(&connection).await {
+        let args = PostPremiumArgs {
+            actor: actor.0,
+            id: user_id,
+        };
         match controller.post_premium(args).await {
             Ok(_) => HttpResponse::Ok().body("OK"),
-            Err(e) => HttpResponse::BadRequest().body(e.to_string()),
+            Err(e) => error_response(e, HttpResponse::BadRequest()),
         }
     } else {
         HttpResponse::InternalServerError().body("Internal Server Error")
@@ -186,33 +884,527 @@ async fn post_premium(path: web::Path<(String,)>) -> impl Responder {
 }
 
 #[delete("/user/{id}/membership")]
-async fn delete_premium(path: web::Path<(String,)>) -> impl Responder {
+async fn delete_premium(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    path: web::Path<(String,)>,
+) -> impl Responder {
     let user_id = path.into_inner().0;
-    if let Ok(controller) = UserController::new().await {
-        let args = DeletePremiumArgs { id: user_id };
+    if let Ok(controller) = UserController::new(&connection).await {
+        let args = DeletePremiumArgs {
+            actor: actor.0,
+            id: user_id,
+        };
         match controller.delete_premium(args).await {
             Ok(_) => HttpResponse::Ok().body("OK"),
-            Err(e) => HttpResponse::BadRequest().body(e.to_string()),
+            Err(e) => error_response(e, HttpResponse::BadRequest()),
         }
     } else {
         HttpResponse::InternalServerError().body("Internal Server Error")
     }
 }
 
+#[derive(Deserialize)]
+struct GetRecommendationQuery {
+    limit: Option<usize>,
+    sort: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ClubRecommendationResult {
+    club_id: String,
+    club_name: String,
+    owner: String,
+    member_count: usize,
+}
+
 #[get("/club/recommend")]
-async fn get_recommendation() -> impl Responder {
-    if let Ok(controller) = ClubController::new().await {
-        match controller.get_recommendation().await {
-            Ok(data) => HttpResponse::Ok().body(format!(
-                "[{}]",
-                data.iter()
-                    .map(|d| d.to_string())
-                    .collect::<Vec<String>>()
-                    .join(", ")
-            )),
-            Err(e) => HttpResponse::BadRequest().body(e.to_string()),
+async fn get_recommendation(
+    connection: web::Data<DatabaseConnection>,
+    query: web::Query<GetRecommendationQuery>,
+) -> impl Responder {
+    let query = query.into_inner();
+    let args = GetRecommendationArgs {
+        limit: query.limit,
+        sort: query.sort,
+    };
+    if let Ok(controller) = ClubController::new(&connection).await {
+        match controller.get_recommendation(args).await {
+            Ok(data) => HttpResponse::Ok().json(
+                data.into_iter()
+                    .map(|d| ClubRecommendationResult {
+                        club_id: d.club_id,
+                        club_name: d.club_name,
+                        owner: d.owner,
+                        member_count: d.member_count,
+                    })
+                    .collect::<Vec<ClubRecommendationResult>>(),
+            ),
+            Err(e) => error_response(e, HttpResponse::BadRequest()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Deserialize)]
+struct PostWebhookPayload {
+    url: String,
+    #[serde(default)]
+    events: Vec<String>,
+    secret: Option<String>,
+}
+
+/// A registered webhook. The secret is left out except in the response to
+/// its creation.
+#[derive(Serialize)]
+struct WebhookResult {
+    id: String,
+    url: String,
+    events: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret: Option<String>,
+    created_at: String,
+}
+
+impl WebhookResult {
+    fn new(webhook: WebhookData, with_secret: bool) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url,
+            events: webhook.events,
+            secret: with_secret.then_some(webhook.secret),
+            created_at: webhook.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[post("/webhook")]
+async fn post_webhook(
+    connection: web::Data<DatabaseConnection>,
+    webhook_config: web::Data<WebhookConfig>,
+    actor: RequestActor,
+    body: web::Json<PostWebhookPayload>,
+) -> impl Responder {
+    let body = body.into_inner();
+    if let Ok(controller) = WebhookController::new(&connection, &webhook_config).await {
+        let args = PostWebhookArgs {
+            actor: actor.0,
+            url: body.url,
+            events: body.events,
+            secret: body.secret,
+        };
+        match controller.post_webhook(args).await {
+            Ok(webhook) => HttpResponse::Ok().json(WebhookResult::new(webhook, true)),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[get("/webhook")]
+async fn get_webhooks(
+    connection: web::Data<DatabaseConnection>,
+    webhook_config: web::Data<WebhookConfig>,
+    actor: RequestActor,
+) -> impl Responder {
+    let args = ListWebhooksArgs { actor: actor.0 };
+    if let Ok(controller) = WebhookController::new(&connection, &webhook_config).await {
+        match controller.list_webhooks(args).await {
+            Ok(webhooks) => HttpResponse::Ok().json(
+                webhooks
+                    .into_iter()
+                    .map(|w| WebhookResult::new(w, false))
+                    .collect::<Vec<WebhookResult>>(),
+            ),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[get("/webhook/{id}")]
+async fn get_webhook(
+    connection: web::Data<DatabaseConnection>,
+    webhook_config: web::Data<WebhookConfig>,
+    actor: RequestActor,
+    path: web::Path<(String,)>,
+) -> impl Responder {
+    let id = path.into_inner().0;
+
+    let args = GetWebhookArgs { actor: actor.0, id };
+    if let Ok(controller) = WebhookController::new(&connection, &webhook_config).await {
+        match controller.get_webhook(args).await {
+            Ok(Some(webhook)) => HttpResponse::Ok().json(WebhookResult::new(webhook, false)),
+            Ok(None) => HttpResponse::NotFound().body("Not Found"),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[delete("/webhook/{id}")]
+async fn delete_webhook(
+    connection: web::Data<DatabaseConnection>,
+    webhook_config: web::Data<WebhookConfig>,
+    actor: RequestActor,
+    path: web::Path<(String,)>,
+) -> impl Responder {
+    let id = path.into_inner().0;
+
+    let args = DeleteWebhookArgs { actor: actor.0, id };
+    if let Ok(controller) = WebhookController::new(&connection, &webhook_config).await {
+        match controller.delete_webhook(args).await {
+            Ok(_) => HttpResponse::Ok().body("OK"),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Deserialize)]
+struct GetDeliveriesQuery {
+    limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct WebhookDeliveryResult {
+    id: String,
+    event_id: String,
+    event_type: String,
+    status: &'static str,
+    attempts: u32,
+    response_status: Option<u16>,
+    last_error: Option<String>,
+    created_at: String,
+    delivered_at: Option<String>,
+}
+
+impl From<WebhookDeliveryData> for WebhookDeliveryResult {
+    fn from(delivery: WebhookDeliveryData) -> Self {
+        Self {
+            id: delivery.id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at.to_rfc3339(),
+            delivered_at: delivery.delivered_at.map(|d| d.to_rfc3339()),
+        }
+    }
+}
+
+#[get("/webhook/{id}/deliveries")]
+async fn get_webhook_deliveries(
+    connection: web::Data<DatabaseConnection>,
+    webhook_config: web::Data<WebhookConfig>,
+    actor: RequestActor,
+    path: web::Path<(String,)>,
+    query: web::Query<GetDeliveriesQuery>,
+) -> impl Responder {
+    let id = path.into_inner().0;
+
+    let args = GetDeliveriesArgs {
+        actor: actor.0,
+        id,
+        limit: query.limit,
+    };
+    if let Ok(controller) = WebhookController::new(&connection, &webhook_config).await {
+        match controller.get_deliveries(args).await {
+            Ok(deliveries) => HttpResponse::Ok().json(
+                deliveries
+                    .into_iter()
+                    .map(WebhookDeliveryResult::from)
+                    .collect::<Vec<WebhookDeliveryResult>>(),
+            ),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
+        }
+    } else {
+        HttpResponse::InternalServerError().body("Internal Server Error")
+    }
+}
+
+#[derive(Deserialize)]
+struct GetAuditQuery {
+    target: Option<String>,
+    limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct AuditEntryResult {
+    id: String,
+    actor: String,
+    action: String,
+    target_type: String,
+    target_id: String,
+    before: Option<Value>,
+    after: Option<Value>,
+    occurred_at: String,
+}
+
+#[get("/audit")]
+async fn get_audit(
+    connection: web::Data<DatabaseConnection>,
+    actor: RequestActor,
+    query: web::Query<GetAuditQuery>,
+) -> impl Responder {
+    let query = query.into_inner();
+    let args = GetAuditArgs {
+        actor: actor.0,
+        target: query.target,
+        limit: query.limit,
+    };
+    if let Ok(controller) = AuditController::new(&connection).await {
+        match controller.get_audit(args).await {
+            Ok(entries) => HttpResponse::Ok().json(
+                entries
+                    .into_iter()
+                    .map(|e| AuditEntryResult {
+                        id: e.id,
+                        actor: e.actor,
+                        action: e.action,
+                        target_type: e.target_type,
+                        target_id: e.target_id,
+                        before: e.before,
+                        after: e.after,
+                        occurred_at: e.occurred_at.to_rfc3339(),
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            Err(e) => error_response(e, HttpResponse::InternalServerError()),
         }
     } else {
         HttpResponse::InternalServerError().body("Internal Server Error")
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use actix_web::{
+        body::MessageBody,
+        dev::{ServiceFactory, ServiceRequest, ServiceResponse},
+        http::StatusCode,
+        middleware::from_fn,
+        test::{self, TestRequest},
+        web, App,
+    };
+    use serde_json::{json, Value};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use super::{admit, idempotency, json_error, query_error, routes};
+    use crate::domain::model::user::entity::UserId;
+    use crate::infrastructure::{
+        auth::TokenAuthenticator,
+        config::{AuthConfig, DatabaseConfig, ServerConfig, WebhookConfig},
+        database::shared::{DatabaseConnection, DatabasePool, SQLITE_MIGRATOR},
+        rate_limit::RateLimiter,
+    };
+
+    async fn connection() -> DatabaseConnection {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        SQLITE_MIGRATOR.run(&pool).await.unwrap();
+        DatabaseConnection::new(
+            DatabasePool::Sqlite(Arc::new(pool)),
+            &DatabaseConfig::default(),
+        )
+    }
+
+    fn authenticator() -> TokenAuthenticator {
+        let config = AuthConfig {
+            secret: Some("a secret of at least 32 characters".to_string()),
+            ..AuthConfig::default()
+        };
+        TokenAuthenticator::from_config(&config).unwrap().unwrap()
+    }
+
+    /// The app `WebServer::run` serves, without rate limits.
+    fn app(
+        connection: DatabaseConnection,
+        authenticator: Option<TokenAuthenticator>,
+    ) -> App<
+        impl ServiceFactory<
+            ServiceRequest,
+            Config = (),
+            Response = ServiceResponse<impl MessageBody>,
+            Error = actix_web::Error,
+            InitError = (),
+        >,
+    > {
+        App::new()
+            .app_data(web::Data::new(connection))
+            .app_data(web::Data::new(authenticator))
+            .app_data(web::Data::new(None::<RateLimiter>))
+            .app_data(web::Data::new(ServerConfig::default()))
+            .app_data(web::Data::new(WebhookConfig::default()))
+            .app_data(web::JsonConfig::default().error_handler(json_error))
+            .app_data(web::QueryConfig::default().error_handler(query_error))
+            .wrap(from_fn(idempotency))
+            .wrap(from_fn(admit))
+            .configure(routes)
+    }
+
+    #[actix_web::test]
+    async fn rejects_anonymous_callers_of_webhook_audit_and_export_routes() {
+        let app = test::init_service(app(connection().await, None)).await;
+
+        let requests = vec![
+            TestRequest::post().uri("/webhook").set_json(json!({
+                "url": "https://example.com/hook",
+                "events": ["UserRegistered"],
+            })),
+            TestRequest::get().uri("/webhook"),
+            TestRequest::get().uri("/webhook/1"),
+            TestRequest::delete().uri("/webhook/1"),
+            TestRequest::get().uri("/webhook/1/deliveries"),
+            TestRequest::get().uri("/audit"),
+            TestRequest::get().uri("/user/export"),
+            TestRequest::get().uri("/club/export"),
+        ];
+        for request in requests {
+            let request = request.to_request();
+            let path = request.path().to_string();
+            let response = test::call_service(&app, request).await;
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{}", path);
+        }
+    }
+
+    #[actix_web::test]
+    async fn answers_unprocessable_with_the_broken_list_parameter() {
+        let app = test::init_service(app(connection().await, None)).await;
+
+        for (uri, field) in [
+            ("/user?limit=0", "limit"),
+            ("/user?sort=age", "sort"),
+            ("/user?cursor=nonsense", "cursor"),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = test::call_service(&app, request).await;
+            assert_eq!(
+                response.status(),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "{}",
+                uri
+            );
+            let body: Value = test::read_body_json(response).await;
+            assert_eq!(body["errors"][0]["field"], field, "{}", uri);
+        }
+    }
+
+    #[actix_web::test]
+    async fn replays_the_response_to_a_retried_request() {
+        let authenticator = authenticator();
+        let app = test::init_service(app(connection().await, Some(authenticator.clone()))).await;
+
+        let request = TestRequest::post()
+            .uri("/user")
+            .set_json(json!({ "name": "idempotent-owner" }))
+            .to_request();
+        let user: Value = test::call_and_read_body_json(&app, request).await;
+        let user_id = user["id"].as_str().unwrap();
+        let token = authenticator
+            .issue(&UserId::new(user_id).unwrap())
+            .unwrap()
+            .token;
+
+        let anonymous = || {
+            TestRequest::post()
+                .uri("/user")
+                .insert_header(("Idempotency-Key", "anonymous"))
+                .set_json(json!({ "name": "idempotent-anonymous" }))
+                .to_request()
+        };
+        let first = test::call_service(&app, anonymous()).await;
+        assert_eq!(first.status(), StatusCode::CREATED);
+        let first_body = test::read_body(first).await;
+        let retried = test::call_service(&app, anonymous()).await;
+        assert_eq!(retried.status(), StatusCode::CREATED);
+        assert_eq!(
+            retried.headers().get("Idempotency-Replayed").unwrap(),
+            "true"
+        );
+        assert_eq!(test::read_body(retried).await, first_body);
+
+        let post_club = |name: &str| {
+            TestRequest::post()
+                .uri("/club")
+                .insert_header(("Authorization", format!("Bearer {}", token)))
+                .insert_header(("Idempotency-Key", "create-club"))
+                .set_json(json!({ "name": name, "user_id": user_id }))
+                .to_request()
+        };
+        let first = test::call_service(&app, post_club("idempotent club")).await;
+        assert_eq!(first.status(), StatusCode::CREATED);
+        assert!(first.headers().get("Idempotency-Replayed").is_none());
+        let location = first.headers().get("Location").cloned();
+        let first_body = test::read_body(first).await;
+
+        let retried = test::call_service(&app, post_club("idempotent club")).await;
+        assert_eq!(retried.status(), StatusCode::CREATED);
+        assert_eq!(
+            retried.headers().get("Idempotency-Replayed").unwrap(),
+            "true"
+        );
+        assert_eq!(retried.headers().get("Location").cloned(), location);
+        assert_eq!(test::read_body(retried).await, first_body);
+
+        let reused = test::call_service(&app, post_club("another club")).await;
+        assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[actix_web::test]
+    async fn answers_created_with_the_location_of_a_new_user_or_club() {
+        let authenticator = authenticator();
+        let app = test::init_service(app(connection().await, Some(authenticator.clone()))).await;
+
+        let request = TestRequest::post()
+            .uri("/user")
+            .set_json(json!({ "name": "created-owner" }))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let location = response.headers().get("Location").unwrap().clone();
+        let user: Value = test::read_body_json(response).await;
+        let user_id = user["id"].as_str().unwrap();
+        assert_eq!(location, format!("/user/{}", user_id).as_str());
+
+        let request = TestRequest::get()
+            .uri(location.to_str().unwrap())
+            .to_request();
+        let found: Value = test::call_and_read_body_json(&app, request).await;
+        assert_eq!(found["name"], "created-owner");
+
+        let token = authenticator
+            .issue(&UserId::new(user_id).unwrap())
+            .unwrap()
+            .token;
+        let request = TestRequest::post()
+            .uri("/club")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "name": "created club", "user_id": user_id }))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let location = response.headers().get("Location").unwrap().clone();
+        let club: Value = test::read_body_json(response).await;
+        let club_id = club["id"].as_str().unwrap();
+        assert_eq!(location, format!("/club/{}", club_id).as_str());
+
+        let request = TestRequest::get()
+            .uri(location.to_str().unwrap())
+            .to_request();
+        let found: Value = test::call_and_read_body_json(&app, request).await;
+        assert_eq!(found["name"], "created club");
+    }
+}
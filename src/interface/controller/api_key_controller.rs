@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tokio::sync::Mutex;
+
+use crate::{
+    application::api_key::{
+        ApiKeyAuthenticateService, ApiKeyCreateCommand, ApiKeyCreateService, ApiKeyData,
+        ApiKeyListService, ApiKeyRevokeService,
+    },
+    domain::model::{api_key::factory::ApiKeyFactory, audit::entity::Actor},
+    infrastructure::database::shared::DatabaseConnection,
+    interface::repository::api_key::ApiKeyRepository,
+};
+
+pub struct ApiKeyController {
+    api_key_create_service: ApiKeyCreateService,
+    api_key_list_service: ApiKeyListService,
+    api_key_revoke_service: ApiKeyRevokeService,
+    api_key_authenticate_service: ApiKeyAuthenticateService,
+}
+
+pub struct PostApiKeyArgs {
+    pub name: String,
+    pub scopes: Vec<String>,
+}
+
+pub struct RevokeApiKeyArgs {
+    pub id: String,
+}
+
+pub struct AuthenticateArgs {
+    pub key: String,
+}
+
+impl ApiKeyController {
+    pub async fn new(connection: &DatabaseConnection) -> Result<Self> {
+        // repository
+        let api_key_database = connection.api_key_database()?;
+        let api_key_repository = ApiKeyRepository::new(api_key_database).await?;
+        let api_key_repository = Arc::new(Mutex::new(api_key_repository));
+
+        // factory
+        let api_key_factory = Arc::new(ApiKeyFactory::new());
+
+        let api_key_repo = Arc::clone(&api_key_repository);
+        let api_key_create_service = ApiKeyCreateService::new(api_key_repo, api_key_factory);
+
+        let api_key_repo = Arc::clone(&api_key_repository);
+        let api_key_list_service = ApiKeyListService::new(api_key_repo);
+
+        let api_key_repo = Arc::clone(&api_key_repository);
+        let api_key_revoke_service = ApiKeyRevokeService::new(api_key_repo);
+
+        let api_key_repo = Arc::clone(&api_key_repository);
+        let api_key_authenticate_service = ApiKeyAuthenticateService::new(api_key_repo);
+
+        Ok(Self {
+            api_key_create_service,
+            api_key_list_service,
+            api_key_revoke_service,
+            api_key_authenticate_service,
+        })
+    }
+
+    pub async fn post_api_key(&self, args: PostApiKeyArgs) -> Result<ApiKeyData> {
+        let command = ApiKeyCreateCommand::new(&args.name, &args.scopes);
+        self.api_key_create_service.handle(command).await
+    }
+
+    pub async fn list_api_keys(&self) -> Result<Vec<ApiKeyData>> {
+        self.api_key_list_service.handle().await
+    }
+
+    pub async fn revoke_api_key(&self, args: RevokeApiKeyArgs) -> Result<ApiKeyData> {
+        self.api_key_revoke_service.handle(&args.id).await
+    }
+
+    pub async fn authenticate(&self, args: AuthenticateArgs) -> Result<Actor> {
+        self.api_key_authenticate_service.handle(&args.key).await
+    }
+}
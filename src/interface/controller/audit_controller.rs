@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::audit::{AuditEntryData, AuditListService},
+    domain::model::audit::entity::Actor,
+    infrastructure::database::shared::DatabaseConnection,
+    interface::repository::audit::AuditRepository,
+};
+
+pub struct AuditController {
+    audit_list_service: AuditListService,
+}
+
+pub struct GetAuditArgs {
+    pub actor: Actor,
+    pub target: Option<String>,
+    pub limit: Option<usize>,
+}
+
+impl AuditController {
+    pub async fn new(connection: &DatabaseConnection) -> Result<Self> {
+        let audit_database = connection.audit_database()?;
+        let audit_repository = AuditRepository::new(audit_database).await?;
+        let audit_repository = Arc::new(audit_repository);
+
+        let audit_list_service = AuditListService::new(audit_repository);
+
+        Ok(Self { audit_list_service })
+    }
+
+    pub async fn get_audit(&self, args: GetAuditArgs) -> Result<Vec<AuditEntryData>> {
+        self.audit_list_service
+            .handle(&args.actor, args.target.as_deref(), args.limit)
+            .await
+    }
+}
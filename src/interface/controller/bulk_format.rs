@@ -0,0 +1,97 @@
+use anyhow::Result;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::domain::model::error::DomainError;
+
+/// The file formats accepted by bulk import and produced by export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkFormat {
+    /// Comma separated values with a header row.
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+}
+
+impl BulkFormat {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "csv" => Ok(Self::Csv),
+            "jsonl" | "ndjson" => Ok(Self::JsonLines),
+            _ => Err(DomainError::invalid_field(
+                "format",
+                "one_of",
+                &format!("Unknown format `{}`. Expected csv or jsonl", value),
+            )
+            .into()),
+        }
+    }
+
+    /// Guesses the format from a file name, e.g. `users.csv`.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let extension = path.rsplit_once('.')?.1;
+        Self::parse(&extension.to_lowercase()).ok()
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::JsonLines => "application/x-ndjson",
+        }
+    }
+}
+
+/// Reads every record of `input`, keeping the line number of each one so
+/// that a malformed record can be reported without failing the others.
+pub fn read_records<T: DeserializeOwned>(
+    format: BulkFormat,
+    input: &str,
+) -> Vec<(usize, Result<T, String>)> {
+    match format {
+        BulkFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(input.as_bytes());
+            let headers = match reader.headers() {
+                Ok(headers) => headers.clone(),
+                Err(e) => return vec![(1, Err(e.to_string()))],
+            };
+            reader
+                .records()
+                .enumerate()
+                .map(|(i, record)| {
+                    let line = record
+                        .as_ref()
+                        .ok()
+                        .and_then(|r| r.position())
+                        .map_or(i + 2, |p| p.line() as usize);
+                    let record = record
+                        .and_then(|r| r.deserialize::<T>(Some(&headers)))
+                        .map_err(|e| e.to_string());
+                    (line, record)
+                })
+                .collect()
+        }
+        BulkFormat::JsonLines => input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| (i + 1, serde_json::from_str(line).map_err(|e| e.to_string())))
+            .collect(),
+    }
+}
+
+pub fn write_records<T: Serialize>(format: BulkFormat, records: &[T]) -> Result<String> {
+    match format {
+        BulkFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for record in records {
+                writer.serialize(record)?;
+            }
+            Ok(String::from_utf8(writer.into_inner()?)?)
+        }
+        BulkFormat::JsonLines => records
+            .iter()
+            .map(|r| Ok(format!("{}\n", serde_json::to_string(r)?)))
+            .collect(),
+    }
+}
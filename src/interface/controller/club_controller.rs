@@ -1,65 +1,151 @@
-use std::{
-    fmt::Display,
-    sync::{Arc, Mutex},
-};
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::Result;
-use sqlx::postgres;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::Mutex;
 
 use crate::{
-    application::club::{
-        ClubCreateCommand, ClubCreateService, ClubJoinCommand, ClubJoinService,
-        ClubRecommendationService,
+    application::{
+        club::{
+            ClubCreateCommand, ClubCreateService, ClubExportService, ClubGetInfoService, ClubInfo,
+            ClubJoinCommand, ClubJoinService, ClubLeaveCommand, ClubLeaveService, ClubListCommand,
+            ClubListService, ClubRecommendationCommand, ClubRecommendationService,
+            ClubStatisticsData, ClubStatisticsListService, ClubStatisticsProjector,
+            ClubTransferCommand, ClubTransferService,
+        },
+        user::UserData,
+    },
+    domain::model::{
+        audit::entity::Actor,
+        club::{factory::ClubFactory, service::ClubService},
     },
-    domain::model::club::{factory::ClubFactory, service::ClubService},
-    infrastructure::database::{
-        club::PostgresClubDatabase, shared::DATABASE_CONFIG, user::PostgresUserDatabase,
+    infrastructure::database::shared::DatabaseConnection,
+    interface::{
+        controller::bulk_format::{write_records, BulkFormat},
+        repository::{
+            club::ClubRepository, club_statistics::ClubStatisticsRepository, user::UserRepository,
+        },
     },
-    interface::repository::{club::ClubRepository, user::UserRepository},
 };
 
 pub struct ClubController {
+    club_export_service: ClubExportService,
     club_create_service: ClubCreateService,
     club_join_service: ClubJoinService,
+    club_leave_service: ClubLeaveService,
+    club_get_info_service: ClubGetInfoService,
+    club_list_service: ClubListService,
     club_recommendation_service: ClubRecommendationService,
+    club_transfer_service: ClubTransferService,
+    club_statistics_list_service: ClubStatisticsListService,
+    club_statistics_projector: ClubStatisticsProjector,
 }
 
 pub struct PostClubArgs {
+    pub actor: Actor,
     pub user_id: String,
     pub name: String,
 }
 
 pub struct PostMemberArgs {
+    pub actor: Actor,
     pub user_id: String,
     pub club_id: String,
 }
 
-pub struct ClubRecommendationData {
-    club_id: String,
-    club_name: String,
-    owner: String,
+pub struct DeleteMemberArgs {
+    pub actor: Actor,
+    pub user_id: String,
+    pub club_id: String,
+}
+
+pub struct PutOwnerArgs {
+    pub actor: Actor,
+    pub club_id: String,
+    pub user_id: String,
+}
+
+pub struct GetClubArgs {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClubUserData {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetClubResult {
+    pub id: String,
+    pub name: String,
+    pub owner: Option<ClubUserData>,
+    pub members: Vec<ClubUserData>,
 }
 
-impl Display for ClubRecommendationData {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!(
-            "{{ \"club_id\": \"{}\", \"club_name\": \"{}\", \"owner\": \"{}\", }}",
-            self.club_id, self.club_name, self.owner
-        ))
+impl From<ClubInfo> for GetClubResult {
+    fn from(club: ClubInfo) -> Self {
+        let to_user = |u: &UserData| ClubUserData {
+            id: u.get_id(),
+            name: u.get_name(),
+        };
+        Self {
+            id: club.id,
+            name: club.name,
+            owner: club.owner.as_ref().map(to_user),
+            members: club.members.iter().map(to_user).collect(),
+        }
     }
 }
 
+pub struct ListClubsArgs {
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    pub name: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ClubSummaryData {
+    pub id: String,
+    pub name: String,
+    pub owner: String,
+    pub member_count: usize,
+}
+
+#[derive(Debug)]
+pub struct ListClubsResult {
+    pub clubs: Vec<ClubSummaryData>,
+    pub next_cursor: Option<String>,
+}
+
+pub struct GetRecommendationArgs {
+    pub limit: Option<usize>,
+    pub sort: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClubRecommendationData {
+    pub club_id: String,
+    pub club_name: String,
+    pub owner: String,
+    pub member_count: usize,
+}
+
+pub struct RebuildStatisticsArgs {
+    /// When a member last joined each club, by club id.
+    pub last_joined_at: HashMap<String, DateTime<Utc>>,
+}
+
+pub struct ExportClubsArgs {
+    pub actor: Actor,
+    pub format: BulkFormat,
+}
+
 impl ClubController {
-    pub async fn new() -> Result<Self> {
-        let pool = postgres::PgPoolOptions::new()
-            .max_connections(20)
-            .connect(&DATABASE_CONFIG.database_url())
-            .await?;
-        let pool = Arc::new(pool);
-
-        let pgpool = Arc::clone(&pool);
+    pub async fn new(connection: &DatabaseConnection) -> Result<Self> {
         // repository
-        let club_database = Box::new(PostgresClubDatabase::new(pgpool)?);
+        let club_database = connection.club_database()?;
         let club_repository = ClubRepository::new(club_database).await?;
         let club_repository = Arc::new(Mutex::new(club_repository));
 
@@ -70,12 +156,17 @@ impl ClubController {
         let club_repo = Arc::clone(&club_repository);
         let club_service = Arc::new(ClubService::new(club_repo));
 
-        let pgpool = Arc::clone(&pool);
         // user repository
-        let user_database = PostgresUserDatabase::new(pgpool)?;
-        let user_repository = UserRepository::new(Box::new(user_database)).await?;
+        let user_database = connection.user_database()?;
+        let user_repository = UserRepository::new(user_database).await?;
         let user_repository = Arc::new(user_repository);
 
+        // club statistics repository
+        let club_statistics_database = connection.club_statistics_database()?;
+        let club_statistics_repository =
+            ClubStatisticsRepository::new(club_statistics_database).await?;
+        let club_statistics_repository = Arc::new(club_statistics_repository);
+
         let club_repo = Arc::clone(&club_repository);
         let club_fac = Arc::clone(&club_factory);
         let club_ser = Arc::clone(&club_service);
@@ -86,38 +177,176 @@ impl ClubController {
         let club_fac = Arc::clone(&club_factory);
         let club_ser = Arc::clone(&club_service);
         let user_repo = Arc::clone(&user_repository);
-        let club_join_service = ClubJoinService::new(club_repo, club_fac, club_ser, user_repo);
+        let club_statistics_repo = Arc::clone(&club_statistics_repository);
+        let club_join_service = ClubJoinService::new(
+            club_repo,
+            club_fac,
+            club_ser,
+            user_repo,
+            club_statistics_repo,
+        );
+
+        let club_repo = Arc::clone(&club_repository);
+        let user_repo = Arc::clone(&user_repository);
+        let club_export_service = ClubExportService::new(club_repo, user_repo);
+
+        let club_repo = Arc::clone(&club_repository);
+        let club_leave_service = ClubLeaveService::new(club_repo);
+
+        let club_repo = Arc::clone(&club_repository);
+        let user_repo = Arc::clone(&user_repository);
+        let club_get_info_service = ClubGetInfoService::new(club_repo, user_repo);
 
         let club_repo = Arc::clone(&club_repository);
-        let club_recommendation_service = ClubRecommendationService::new(club_repo);
+        let user_repo = Arc::clone(&user_repository);
+        let club_list_service = ClubListService::new(club_repo, user_repo);
+
+        let club_statistics_repo = Arc::clone(&club_statistics_repository);
+        let club_recommendation_service = ClubRecommendationService::new(club_statistics_repo);
+
+        let club_repo = Arc::clone(&club_repository);
+        let club_transfer_service = ClubTransferService::new(club_repo);
+
+        let club_statistics_repo = Arc::clone(&club_statistics_repository);
+        let club_statistics_list_service = ClubStatisticsListService::new(club_statistics_repo);
+
+        let club_repo = Arc::clone(&club_repository);
+        let user_repo = Arc::clone(&user_repository);
+        let club_statistics_repo = Arc::clone(&club_statistics_repository);
+        let club_statistics_projector =
+            ClubStatisticsProjector::new(club_repo, user_repo, club_statistics_repo);
         Ok(Self {
             club_create_service,
+            club_export_service,
             club_join_service,
+            club_leave_service,
+            club_get_info_service,
+            club_list_service,
             club_recommendation_service,
+            club_transfer_service,
+            club_statistics_list_service,
+            club_statistics_projector,
         })
     }
 
-    pub async fn post_club(&self, args: PostClubArgs) -> Result<()> {
-        let command = ClubCreateCommand::new(&args.user_id, &args.name);
-        self.club_create_service.handle(command).await
+    pub async fn post_club(&self, args: PostClubArgs) -> Result<GetClubResult> {
+        let command = ClubCreateCommand::new(&args.actor, &args.user_id, &args.name);
+        self.club_create_service
+            .handle(command)
+            .await
+            .map(GetClubResult::from)
     }
 
     pub async fn post_member(&self, args: PostMemberArgs) -> Result<()> {
-        let command = ClubJoinCommand::new(&args.user_id, &args.club_id);
+        let command = ClubJoinCommand::new(&args.actor, &args.user_id, &args.club_id);
         self.club_join_service.handle(command).await
     }
 
-    pub async fn get_recommendation(&self) -> Result<Vec<ClubRecommendationData>> {
+    pub async fn delete_member(&self, args: DeleteMemberArgs) -> Result<()> {
+        let command = ClubLeaveCommand::new(&args.actor, &args.user_id, &args.club_id);
+        self.club_leave_service.handle(command).await
+    }
+
+    pub async fn put_owner(&self, args: PutOwnerArgs) -> Result<()> {
+        let command = ClubTransferCommand::new(&args.actor, &args.club_id, &args.user_id);
+        self.club_transfer_service.handle(command).await
+    }
+
+    /// Writes every club in `format`. In CSV the member ids are joined with
+    /// `;` because a cell cannot hold a list.
+    pub async fn export_clubs(&self, args: ExportClubsArgs) -> Result<String> {
+        #[derive(Serialize)]
+        struct ClubRecord<Members> {
+            id: String,
+            name: String,
+            owner: String,
+            members: Members,
+        }
+
+        let clubs = self.club_export_service.handle(&args.actor).await?;
+        match args.format {
+            BulkFormat::Csv => {
+                let records = clubs
+                    .into_iter()
+                    .map(|c| ClubRecord {
+                        id: c.id,
+                        name: c.name,
+                        owner: c.owner,
+                        members: c.members.join(";"),
+                    })
+                    .collect::<Vec<_>>();
+                write_records(args.format, &records)
+            }
+            BulkFormat::JsonLines => {
+                let records = clubs
+                    .into_iter()
+                    .map(|c| ClubRecord {
+                        id: c.id,
+                        name: c.name,
+                        owner: c.owner,
+                        members: c.members,
+                    })
+                    .collect::<Vec<_>>();
+                write_records(args.format, &records)
+            }
+        }
+    }
+
+    pub async fn get_club(&self, args: GetClubArgs) -> Result<Option<GetClubResult>> {
+        self.club_get_info_service
+            .handle(&args.id)
+            .await
+            .map(|maybe_club| maybe_club.map(GetClubResult::from))
+    }
+
+    pub async fn list_clubs(&self, args: ListClubsArgs) -> Result<ListClubsResult> {
+        let command =
+            ClubListCommand::new(args.cursor.as_deref(), args.limit, args.name.as_deref());
+        self.club_list_service
+            .handle(command)
+            .await
+            .map(|page| ListClubsResult {
+                clubs: page
+                    .clubs
+                    .into_iter()
+                    .map(|c| ClubSummaryData {
+                        id: c.id,
+                        name: c.name,
+                        owner: c.owner,
+                        member_count: c.member_count,
+                    })
+                    .collect(),
+                next_cursor: page.next_cursor,
+            })
+    }
+
+    pub async fn get_recommendation(
+        &self,
+        args: GetRecommendationArgs,
+    ) -> Result<Vec<ClubRecommendationData>> {
+        let command = ClubRecommendationCommand::new(args.limit, args.sort.as_deref());
         Ok(self
             .club_recommendation_service
-            .handle()
+            .handle(command)
             .await?
             .iter()
             .map(|x| ClubRecommendationData {
                 club_id: x.club_id.to_string(),
                 club_name: x.club_name.to_string(),
                 owner: x.owner.to_string(),
+                member_count: x.member_count,
             })
             .collect())
     }
+
+    pub async fn list_statistics(&self) -> Result<Vec<ClubStatisticsData>> {
+        self.club_statistics_list_service.handle().await
+    }
+
+    /// Recounts every club from scratch and returns how many were counted.
+    pub async fn rebuild_statistics(&self, args: RebuildStatisticsArgs) -> Result<usize> {
+        self.club_statistics_projector
+            .rebuild(&args.last_joined_at)
+            .await
+    }
 }
@@ -1,2 +1,6 @@
+pub mod api_key_controller;
+pub mod audit_controller;
+pub mod bulk_format;
 pub mod club_controller;
 pub mod user_controller;
+pub mod webhook_controller;
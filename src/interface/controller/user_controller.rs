@@ -1,33 +1,52 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 use anyhow::Result;
-use sqlx::postgres;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
 
+use crate::application::club::ClubSummary;
 use crate::application::user::{
-    UserDeleteCommand, UserDeleteService, UserDowngradeCommand, UserDowngradeService,
-    UserGetInfoService, UserRegisterService, UserUpdateCommand, UserUpdateInfoService,
-    UserUpgradeCommand, UserUpgradeService,
+    UserClubsService, UserDeleteCommand, UserDeleteService, UserDowngradeCommand,
+    UserDowngradeService, UserExportService, UserGetInfoService, UserImportCommand, UserImportRow,
+    UserImportService, UserListCommand, UserListService, UserProfile, UserPurgeService,
+    UserRegisterCommand, UserRegisterService, UserRestoreCommand, UserRestoreService,
+    UserUpdateCommand, UserUpdateInfoService, UserUpgradeCommand, UserUpgradeService,
 };
-use crate::domain::model::user::factory::UserFactory;
-use crate::infrastructure::database::shared::DATABASE_CONFIG;
-// use crate::infrastructure::database::user::InMemoryUserDatabase;
-use crate::infrastructure::database::user::PostgresUserDatabase;
-use crate::interface::repository::user::UserRepository;
+use crate::domain::model::{audit::entity::Actor, user::factory::UserFactory};
+use crate::infrastructure::database::shared::DatabaseConnection;
+use crate::interface::controller::{
+    bulk_format::{read_records, write_records, BulkFormat},
+    club_controller::ClubSummaryData,
+};
+use crate::interface::repository::{club::ClubRepository, user::UserRepository};
 
 pub struct UserController {
+    user_clubs_service: UserClubsService,
     user_delete_service: UserDeleteService,
+    user_export_service: UserExportService,
     user_get_info_service: UserGetInfoService,
+    user_import_service: UserImportService,
+    user_list_service: UserListService,
+    user_purge_service: UserPurgeService,
     user_register_service: UserRegisterService,
+    user_restore_service: UserRestoreService,
     user_update_info_service: UserUpdateInfoService,
     user_upgrade_service: UserUpgradeService,
     user_downgrade_service: UserDowngradeService,
 }
 
 pub struct PostArgs {
+    pub actor: Actor,
     pub name: String,
 }
 
 pub struct DeleteArgs {
+    pub actor: Actor,
+    pub id: String,
+}
+
+pub struct RestoreArgs {
+    pub actor: Actor,
     pub id: String,
 }
 
@@ -41,39 +60,141 @@ pub struct GetResult {
     pub name: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct GetProfileResult {
+    pub id: String,
+    pub name: String,
+    pub is_premium: bool,
+    pub premium_since: Option<String>,
+    pub premium_expires_at: Option<String>,
+    pub clubs_count: usize,
+    pub created_at: String,
+}
+
+impl From<UserProfile> for GetProfileResult {
+    fn from(profile: UserProfile) -> Self {
+        Self {
+            id: profile.id,
+            name: profile.name,
+            is_premium: profile.is_premium,
+            premium_since: profile.premium_since.map(|t| t.to_rfc3339()),
+            premium_expires_at: profile.premium_expires_at.map(|t| t.to_rfc3339()),
+            clubs_count: profile.clubs_count,
+            created_at: profile.created_at.to_rfc3339(),
+        }
+    }
+}
+
+pub struct ListArgs {
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    pub premium: Option<bool>,
+    pub name_prefix: Option<String>,
+    pub sort: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ListResult {
+    pub users: Vec<GetResult>,
+    pub next_cursor: Option<String>,
+}
+
+pub struct GetClubsArgs {
+    pub id: String,
+}
+
+#[derive(Debug)]
+pub struct GetClubsResult {
+    pub owned: Vec<ClubSummaryData>,
+    pub member_of: Vec<ClubSummaryData>,
+}
+
+pub struct ImportArgs {
+    pub actor: Actor,
+    pub format: BulkFormat,
+    pub data: String,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportRowResult {
+    pub line: usize,
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportResult {
+    pub dry_run: bool,
+    pub imported: usize,
+    pub failed: usize,
+    pub rows: Vec<ImportRowResult>,
+}
+
+pub struct ExportArgs {
+    pub actor: Actor,
+    pub format: BulkFormat,
+}
+
 pub struct PutArgs {
+    pub actor: Actor,
     pub id: String,
     pub name: String,
 }
 
 pub struct PostPremiumArgs {
+    pub actor: Actor,
     pub id: String,
 }
 
 pub struct DeletePremiumArgs {
+    pub actor: Actor,
     pub id: String,
 }
 
 impl UserController {
-    pub async fn new() -> Result<Self> {
-        let pool = postgres::PgPoolOptions::new()
-            .max_connections(20)
-            .connect(&DATABASE_CONFIG.database_url())
-            .await?;
-        let pool = Arc::new(pool);
-
-        // let user_database = InMemoryUserRepository::new();
-        let pgpool = Arc::clone(&pool);
-        let user_database = PostgresUserDatabase::new(pgpool)?;
-        let user_repository = UserRepository::new(Box::new(user_database)).await?;
+    pub async fn new(connection: &DatabaseConnection) -> Result<Self> {
+        let user_database = connection.user_database()?;
+        let user_repository = UserRepository::new(user_database).await?;
         let user_repository = Arc::new(Mutex::new(user_repository));
         let user_factory = Arc::new(Mutex::new(UserFactory::new()));
+        let user_retention = chrono::Duration::from_std(connection.user_retention())?;
+
+        let club_database = connection.club_database()?;
+        let club_repository = ClubRepository::new(club_database).await?;
+        let club_repository = Arc::new(Mutex::new(club_repository));
+
+        let clubs_repository = Arc::clone(&user_repository);
+        let clubs_club_repository = Arc::clone(&club_repository);
+        let user_clubs_service = UserClubsService::new(clubs_repository, clubs_club_repository);
 
         let deletion_repository = Arc::clone(&user_repository);
-        let user_delete_service = UserDeleteService::new(deletion_repository);
+        let deletion_club_repository = Arc::clone(&club_repository);
+        let user_delete_service =
+            UserDeleteService::new(deletion_repository, deletion_club_repository);
+
+        let export_repository = Arc::clone(&user_repository);
+        let user_export_service = UserExportService::new(export_repository);
+
+        let import_repository = Arc::clone(&user_repository);
+        let import_factory = Arc::clone(&user_factory);
+        let user_import_service = UserImportService::new(import_repository, import_factory);
 
         let read_repository = Arc::clone(&user_repository);
-        let user_get_info_service = UserGetInfoService::new(read_repository);
+        let read_club_repository = Arc::clone(&club_repository);
+        let user_get_info_service = UserGetInfoService::new(read_repository, read_club_repository);
+
+        let list_repository = Arc::clone(&user_repository);
+        let user_list_service = UserListService::new(list_repository);
+
+        let purge_repository = Arc::clone(&user_repository);
+        let purge_club_repository = Arc::clone(&club_repository);
+        let user_purge_service =
+            UserPurgeService::new(purge_repository, purge_club_repository, user_retention);
+
+        let restore_repository = Arc::clone(&user_repository);
+        let user_restore_service = UserRestoreService::new(restore_repository, user_retention);
 
         let registry_repository = Arc::clone(&user_repository);
         let user_register_service = UserRegisterService::new(registry_repository, user_factory);
@@ -88,48 +209,186 @@ impl UserController {
         let user_downgrade_service = UserDowngradeService::new(downgrade_repository);
 
         Ok(Self {
+            user_clubs_service,
             user_delete_service,
+            user_export_service,
             user_get_info_service,
+            user_import_service,
+            user_list_service,
+            user_purge_service,
             user_register_service,
+            user_restore_service,
             user_update_info_service,
             user_upgrade_service,
             user_downgrade_service,
         })
     }
 
-    pub async fn post(&self, args: PostArgs) -> Result<()> {
-        self.user_register_service.handle(&args.name).await
+    pub async fn post(&self, args: PostArgs) -> Result<GetProfileResult> {
+        let command = UserRegisterCommand::new(&args.actor, &args.name);
+        self.user_register_service
+            .handle(command)
+            .await
+            .map(GetProfileResult::from)
     }
 
     pub async fn delete(&self, args: DeleteArgs) -> Result<()> {
-        let command = UserDeleteCommand::new(&args.id);
+        let command = UserDeleteCommand::new(&args.actor, &args.id);
         self.user_delete_service.handle(command).await
     }
 
-    pub async fn get(&self, args: GetArgs) -> Result<Option<GetResult>> {
+    pub async fn restore(&self, args: RestoreArgs) -> Result<()> {
+        let command = UserRestoreCommand::new(&args.actor, &args.id);
+        self.user_restore_service.handle(command).await
+    }
+
+    /// Purges the users deleted longer than the retention window ago and
+    /// returns their ids.
+    pub async fn purge(&self) -> Result<Vec<String>> {
+        self.user_purge_service.handle().await
+    }
+
+    /// Registers the users in `args.data`. Records that cannot be read are
+    /// reported next to the ones rejected by the domain.
+    pub async fn import(&self, args: ImportArgs) -> Result<ImportResult> {
+        #[derive(Deserialize)]
+        struct ImportRecord {
+            name: String,
+            #[serde(default)]
+            is_premium: Option<bool>,
+        }
+
+        let mut unreadable = Vec::new();
+        let mut rows = Vec::new();
+        for (line, record) in read_records::<ImportRecord>(args.format, &args.data) {
+            match record {
+                Ok(record) => rows.push(UserImportRow {
+                    line,
+                    name: record.name,
+                    is_premium: record.is_premium.unwrap_or(false),
+                }),
+                Err(error) => unreadable.push(ImportRowResult {
+                    line,
+                    name: None,
+                    id: None,
+                    error: Some(error),
+                }),
+            }
+        }
+
+        let command = UserImportCommand::new(&args.actor, rows, args.dry_run);
+        let report = self.user_import_service.handle(command).await?;
+
+        let mut rows = report
+            .rows
+            .into_iter()
+            .map(|r| ImportRowResult {
+                line: r.line,
+                name: Some(r.name),
+                id: r.id,
+                error: r.error,
+            })
+            .chain(unreadable)
+            .collect::<Vec<ImportRowResult>>();
+        rows.sort_by_key(|r| r.line);
+
+        Ok(ImportResult {
+            dry_run: report.dry_run,
+            imported: report.imported,
+            failed: rows.len() - report.imported,
+            rows,
+        })
+    }
+
+    pub async fn export(&self, args: ExportArgs) -> Result<String> {
+        #[derive(Serialize)]
+        struct UserRecord {
+            id: String,
+            name: String,
+            is_premium: bool,
+            premium_since: Option<String>,
+            premium_expires_at: Option<String>,
+            created_at: String,
+        }
+
+        let records = self
+            .user_export_service
+            .handle(&args.actor)
+            .await?
+            .into_iter()
+            .map(|u| UserRecord {
+                id: u.id,
+                name: u.name,
+                is_premium: u.is_premium,
+                premium_since: u.premium_since.map(|t| t.to_rfc3339()),
+                premium_expires_at: u.premium_expires_at.map(|t| t.to_rfc3339()),
+                created_at: u.created_at.to_rfc3339(),
+            })
+            .collect::<Vec<UserRecord>>();
+        write_records(args.format, &records)
+    }
+
+    pub async fn get(&self, args: GetArgs) -> Result<Option<GetProfileResult>> {
         self.user_get_info_service
             .handle(&args.id)
             .await
-            .map(|maybe_user| {
-                maybe_user.map(|u| GetResult {
-                    id: u.get_id(),
-                    name: u.get_name(),
+            .map(|maybe_user| maybe_user.map(GetProfileResult::from))
+    }
+
+    pub async fn list(&self, args: ListArgs) -> Result<ListResult> {
+        let command = UserListCommand::new(
+            args.cursor.as_deref(),
+            args.limit,
+            args.premium,
+            args.name_prefix.as_deref(),
+            args.sort.as_deref(),
+        );
+        self.user_list_service
+            .handle(command)
+            .await
+            .map(|page| ListResult {
+                users: page
+                    .users
+                    .iter()
+                    .map(|u| GetResult {
+                        id: u.get_id(),
+                        name: u.get_name(),
+                    })
+                    .collect(),
+                next_cursor: page.next_cursor,
+            })
+    }
+
+    pub async fn get_clubs(&self, args: GetClubsArgs) -> Result<Option<GetClubsResult>> {
+        let to_data = |c: &ClubSummary| ClubSummaryData {
+            id: c.id.to_string(),
+            name: c.name.to_string(),
+            owner: c.owner.to_string(),
+            member_count: c.member_count,
+        };
+        self.user_clubs_service
+            .handle(&args.id)
+            .await
+            .map(|maybe_clubs| {
+                maybe_clubs.map(|c| GetClubsResult {
+                    owned: c.owned.iter().map(to_data).collect(),
+                    member_of: c.member_of.iter().map(to_data).collect(),
                 })
             })
     }
 
     pub async fn put(&self, args: PutArgs) -> Result<()> {
-        let command = UserUpdateCommand::new(&args.id, Some(&args.name));
+        let command = UserUpdateCommand::new(&args.actor, &args.id, Some(&args.name));
         self.user_update_info_service.handle(command).await
     }
 
     pub async fn post_premium(&self, args: PostPremiumArgs) -> Result<()> {
-        let command = UserUpgradeCommand::new(&args.id);
+        let command = UserUpgradeCommand::new(&args.actor, &args.id);
         self.user_upgrade_service.handle(command).await
     }
 
     pub async fn delete_premium(&self, args: DeletePremiumArgs) -> Result<()> {
-        let command = UserDowngradeCommand::new(&args.id);
+        let command = UserDowngradeCommand::new(&args.actor, &args.id);
         self.user_downgrade_service.handle(command).await
     }
 }
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tokio::sync::Mutex;
+
+use crate::{
+    application::webhook::{
+        WebhookCreateCommand, WebhookCreateService, WebhookData, WebhookDeleteService,
+        WebhookDeliveriesService, WebhookDeliveryData, WebhookGetService, WebhookListService,
+    },
+    domain::model::{audit::entity::Actor, webhook::factory::WebhookFactory},
+    infrastructure::{config::WebhookConfig, database::shared::DatabaseConnection},
+    interface::repository::webhook::WebhookRepository,
+};
+
+pub struct WebhookController {
+    webhook_create_service: WebhookCreateService,
+    webhook_get_service: WebhookGetService,
+    webhook_list_service: WebhookListService,
+    webhook_delete_service: WebhookDeleteService,
+    webhook_deliveries_service: WebhookDeliveriesService,
+}
+
+pub struct PostWebhookArgs {
+    pub actor: Actor,
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: Option<String>,
+}
+
+pub struct ListWebhooksArgs {
+    pub actor: Actor,
+}
+
+pub struct GetWebhookArgs {
+    pub actor: Actor,
+    pub id: String,
+}
+
+pub struct DeleteWebhookArgs {
+    pub actor: Actor,
+    pub id: String,
+}
+
+pub struct GetDeliveriesArgs {
+    pub actor: Actor,
+    pub id: String,
+    pub limit: Option<usize>,
+}
+
+impl WebhookController {
+    pub async fn new(connection: &DatabaseConnection, config: &WebhookConfig) -> Result<Self> {
+        // repository
+        let webhook_database = connection.webhook_database()?;
+        let webhook_repository = WebhookRepository::new(webhook_database).await?;
+        let webhook_repository = Arc::new(Mutex::new(webhook_repository));
+
+        // factory
+        let webhook_factory = Arc::new(WebhookFactory::new());
+
+        let webhook_repo = Arc::clone(&webhook_repository);
+        let webhook_create_service =
+            WebhookCreateService::new(webhook_repo, webhook_factory, config.allow_private_hosts);
+
+        let webhook_repo = Arc::clone(&webhook_repository);
+        let webhook_get_service = WebhookGetService::new(webhook_repo);
+
+        let webhook_repo = Arc::clone(&webhook_repository);
+        let webhook_list_service = WebhookListService::new(webhook_repo);
+
+        let webhook_repo = Arc::clone(&webhook_repository);
+        let webhook_delete_service = WebhookDeleteService::new(webhook_repo);
+
+        let webhook_repo = Arc::clone(&webhook_repository);
+        let webhook_deliveries_service = WebhookDeliveriesService::new(webhook_repo);
+
+        Ok(Self {
+            webhook_create_service,
+            webhook_get_service,
+            webhook_list_service,
+            webhook_delete_service,
+            webhook_deliveries_service,
+        })
+    }
+
+    pub async fn post_webhook(&self, args: PostWebhookArgs) -> Result<WebhookData> {
+        let command =
+            WebhookCreateCommand::new(&args.actor, &args.url, &args.events, args.secret.as_deref());
+        self.webhook_create_service.handle(command).await
+    }
+
+    pub async fn get_webhook(&self, args: GetWebhookArgs) -> Result<Option<WebhookData>> {
+        self.webhook_get_service.handle(&args.actor, &args.id).await
+    }
+
+    pub async fn list_webhooks(&self, args: ListWebhooksArgs) -> Result<Vec<WebhookData>> {
+        self.webhook_list_service.handle(&args.actor).await
+    }
+
+    pub async fn delete_webhook(&self, args: DeleteWebhookArgs) -> Result<()> {
+        self.webhook_delete_service
+            .handle(&args.actor, &args.id)
+            .await
+    }
+
+    pub async fn get_deliveries(
+        &self,
+        args: GetDeliveriesArgs,
+    ) -> Result<Vec<WebhookDeliveryData>> {
+        self.webhook_deliveries_service
+            .handle(&args.actor, &args.id, args.limit)
+            .await
+    }
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// An API key in the primitive types every database can convert from and to.
+#[derive(Debug, Clone)]
+pub struct PrimitiveApiKey {
+    pub id: String,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+pub trait ApiKeyDatabaseTrait {
+    async fn save(&self, api_key: &PrimitiveApiKey) -> Result<()>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<PrimitiveApiKey>>;
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<PrimitiveApiKey>>;
+    async fn find_all(&self) -> Result<Vec<PrimitiveApiKey>>;
+    async fn touch(&self, id: &str, used_at: DateTime<Utc>) -> Result<()>;
+}
@@ -0,0 +1,87 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::model::api_key::{
+    entity::{ApiKey, ApiKeyId, ApiKeyName, ApiKeyScope},
+    repository::ApiKeyRepositoryTrait,
+};
+
+use super::{ApiKeyDatabaseTrait, PrimitiveApiKey};
+
+fn to_api_key(api_key: &PrimitiveApiKey) -> Result<ApiKey> {
+    let scopes = api_key
+        .scopes
+        .iter()
+        .map(|scope| ApiKeyScope::parse(scope))
+        .collect::<Result<Vec<ApiKeyScope>>>()?;
+    ApiKey::restore(
+        ApiKeyId::new(&api_key.id)?,
+        ApiKeyName::new(&api_key.name)?,
+        api_key.prefix.to_string(),
+        api_key.key_hash.to_string(),
+        &scopes,
+        api_key.created_at,
+        api_key.last_used_at,
+        api_key.revoked_at,
+    )
+}
+
+fn to_primitive(api_key: &ApiKey) -> PrimitiveApiKey {
+    PrimitiveApiKey {
+        id: api_key.get_id().to_string(),
+        name: api_key.get_name().to_string(),
+        prefix: api_key.get_prefix().to_string(),
+        key_hash: api_key.get_key_hash().to_string(),
+        scopes: api_key
+            .get_scopes()
+            .iter()
+            .map(|scope| scope.to_string())
+            .collect(),
+        created_at: *api_key.get_created_at(),
+        last_used_at: api_key.get_last_used_at().copied(),
+        revoked_at: api_key.get_revoked_at().copied(),
+    }
+}
+
+pub struct ApiKeyRepository {
+    database: Box<dyn ApiKeyDatabaseTrait + Send + Sync>,
+}
+
+#[async_trait]
+impl ApiKeyRepositoryTrait for ApiKeyRepository {
+    async fn save(&self, api_key: &ApiKey) -> Result<()> {
+        self.database.save(&to_primitive(api_key)).await
+    }
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>> {
+        self.database
+            .find_by_id(&id.to_string())
+            .await?
+            .map(|api_key| to_api_key(&api_key))
+            .transpose()
+    }
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>> {
+        self.database
+            .find_by_hash(key_hash)
+            .await?
+            .map(|api_key| to_api_key(&api_key))
+            .transpose()
+    }
+    async fn find_all(&self) -> Result<Vec<ApiKey>> {
+        self.database
+            .find_all()
+            .await?
+            .iter()
+            .map(to_api_key)
+            .collect()
+    }
+    async fn touch(&self, id: &ApiKeyId, used_at: DateTime<Utc>) -> Result<()> {
+        self.database.touch(&id.to_string(), used_at).await
+    }
+}
+
+impl ApiKeyRepository {
+    pub async fn new(database: Box<dyn ApiKeyDatabaseTrait + Send + Sync>) -> Result<Self> {
+        Ok(Self { database })
+    }
+}
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::model::audit::entity::AuditEntry;
+
+/// An audit entry in the primitive types every database can convert from and
+/// to. The snapshots are JSON objects.
+#[derive(Debug, Clone)]
+pub struct PrimitiveAuditEntry {
+    pub id: String,
+    pub actor: String,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl PrimitiveAuditEntry {
+    pub fn from_entry(entry: &AuditEntry) -> Self {
+        Self {
+            id: entry.id.to_string(),
+            actor: entry.actor.to_string(),
+            action: entry.action.to_string(),
+            target_type: entry.target_type.to_string(),
+            target_id: entry.target_id.to_string(),
+            before: entry.before.as_ref().map(|v| v.to_string()),
+            after: entry.after.as_ref().map(|v| v.to_string()),
+            occurred_at: entry.occurred_at,
+        }
+    }
+}
+
+/// Reads the audit log. Entries are written by the user and club databases,
+/// in the transaction that saves the change they record.
+#[async_trait]
+pub trait AuditDatabaseTrait {
+    async fn find(&self, target_id: Option<&str>, limit: usize)
+        -> Result<Vec<PrimitiveAuditEntry>>;
+}
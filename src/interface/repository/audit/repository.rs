@@ -0,0 +1,45 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::model::audit::{
+    entity::{Actor, AuditEntry},
+    repository::AuditRepositoryTrait,
+};
+
+use super::{AuditDatabaseTrait, PrimitiveAuditEntry};
+
+fn to_entry(entry: &PrimitiveAuditEntry) -> Result<AuditEntry> {
+    let snapshot = |value: &Option<String>| value.as_deref().map(serde_json::from_str).transpose();
+    Ok(AuditEntry {
+        id: entry.id.to_string(),
+        actor: Actor::parse(&entry.actor)?,
+        action: entry.action.to_string(),
+        target_type: entry.target_type.to_string(),
+        target_id: entry.target_id.to_string(),
+        before: snapshot(&entry.before)?,
+        after: snapshot(&entry.after)?,
+        occurred_at: entry.occurred_at,
+    })
+}
+
+pub struct AuditRepository {
+    database: Box<dyn AuditDatabaseTrait + Send + Sync>,
+}
+
+#[async_trait]
+impl AuditRepositoryTrait for AuditRepository {
+    async fn find(&self, target_id: Option<&str>, limit: usize) -> Result<Vec<AuditEntry>> {
+        self.database
+            .find(target_id, limit)
+            .await?
+            .iter()
+            .map(to_entry)
+            .collect()
+    }
+}
+
+impl AuditRepository {
+    pub async fn new(database: Box<dyn AuditDatabaseTrait + Send + Sync>) -> Result<Self> {
+        Ok(Self { database })
+    }
+}
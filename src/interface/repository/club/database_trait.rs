@@ -1,11 +1,30 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
+use crate::interface::repository::{audit::PrimitiveAuditEntry, outbox::PrimitiveEvent};
+
 pub type PrimitiveId = String;
 pub type PrimitiveName = String;
 pub type PrimitiveMembers = Vec<String>;
 pub type PrimitiveOwner = String;
 
+/// The members a save adds to and removes from a club. They are read from the
+/// club's events rather than from its member list, so that two requests
+/// changing the members of the same club keep each other's changes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrimitiveMemberChanges {
+    pub joined: PrimitiveMembers,
+    pub left: PrimitiveMembers,
+}
+
+/// A page request expressed in the database's own id type. `after` holds the
+/// id and name of the last club of the previous page.
+pub struct ClubPageQuery<ClubId> {
+    pub name_contains: Option<String>,
+    pub after: Option<(ClubId, PrimitiveName)>,
+    pub limit: usize,
+}
+
 #[async_trait]
 pub trait ClubDatabaseTrait {
     type ClubId: Send + Sync;
@@ -33,8 +52,20 @@ pub trait ClubDatabaseTrait {
         members: &PrimitiveMembers,
     ) -> Result<Self::ClubData>;
 
-    async fn save(&self, club: &Self::ClubData) -> Result<()>;
-    async fn find_by_name(&self, club_name: &Self::ClubName) -> Result<Self::ClubData>;
-    async fn find_by_id(&self, id: &Self::ClubId) -> Result<Self::ClubData>;
+    /// Saves the name and owner of the club and applies `changes` to its
+    /// members, appends `events` to the outbox and writes `audit` to the audit
+    /// log in one transaction.
+    async fn save(
+        &self,
+        club: &Self::ClubData,
+        changes: &PrimitiveMemberChanges,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()>;
+    async fn find_by_name(&self, club_name: &Self::ClubName) -> Result<Option<Self::ClubData>>;
+    async fn find_by_id(&self, id: &Self::ClubId) -> Result<Option<Self::ClubData>>;
     async fn find_all(&self) -> Result<Vec<Self::ClubData>>;
+    async fn find_page(&self, query: &ClubPageQuery<Self::ClubId>) -> Result<Vec<Self::ClubData>>;
+    async fn find_by_owner(&self, owner: &Self::ClubOwner) -> Result<Vec<Self::ClubData>>;
+    async fn find_by_member(&self, user_id: &Self::ClubOwner) -> Result<Vec<Self::ClubData>>;
 }
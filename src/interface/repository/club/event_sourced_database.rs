@@ -0,0 +1,273 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::model::{
+    audit::entity::AuditEntry,
+    club::{
+        entity::{Club, ClubId, ClubName},
+        repository::ClubListQuery,
+    },
+    user::entity::UserId,
+};
+use crate::interface::repository::{audit::PrimitiveAuditEntry, outbox::PrimitiveEvent};
+
+use super::{
+    ClubDatabaseTraitWrapper, ClubEventStoreTrait, ClubViewQuery, PrimitiveClubSnapshot,
+    PrimitiveClubView,
+};
+
+/// Keeps clubs as streams of events instead of rows of state. A club is
+/// rebuilt by replaying its stream from the latest snapshot, and a snapshot
+/// is taken every `snapshot_interval` events.
+///
+/// The other queries read the clubs' views, which the store writes in the
+/// same transaction as their events.
+pub struct EventSourcedClubDatabase {
+    store: Box<dyn ClubEventStoreTrait + Send + Sync>,
+    snapshot_interval: u64,
+}
+
+impl EventSourcedClubDatabase {
+    pub fn new(store: Box<dyn ClubEventStoreTrait + Send + Sync>, snapshot_interval: u64) -> Self {
+        Self {
+            store,
+            snapshot_interval: snapshot_interval.max(1),
+        }
+    }
+
+    async fn load(&self, id: &str) -> Result<Option<Club>> {
+        let stream = self.store.load(id).await?;
+        let events = stream
+            .events
+            .iter()
+            .map(|e| e.event.to_event())
+            .collect::<Result<Vec<_>>>()?;
+
+        let club = match (stream.snapshot, events.is_empty()) {
+            (Some(snapshot), _) => {
+                let mut club = Club::from_snapshot(
+                    ClubId::new(&snapshot.club_id)?,
+                    ClubName::new(&snapshot.name)?,
+                    snapshot
+                        .members
+                        .iter()
+                        .map(|m| UserId::new(m))
+                        .collect::<Result<Vec<_>>>()?,
+                    UserId::new(&snapshot.owner)?,
+                    snapshot.version,
+                )?;
+                for event in &events {
+                    club.apply(event)?;
+                }
+                club
+            }
+            (None, false) => Club::from_events(&events)?,
+            (None, true) => return Ok(None),
+        };
+
+        Ok(Some(club))
+    }
+
+    async fn find_views(&self, query: &ClubViewQuery) -> Result<Vec<Club>> {
+        self.store
+            .find_views(query)
+            .await?
+            .iter()
+            .map(to_club)
+            .collect()
+    }
+
+    /// Writes the view of every club whose stream has none and returns how
+    /// many were written.
+    pub async fn fill_missing_views(&self) -> Result<usize> {
+        let ids = self.store.club_ids_without_view().await?;
+        for id in &ids {
+            if let Some(club) = self.load(id).await? {
+                self.store
+                    .save_view(&to_view(&club, club.get_version()))
+                    .await?;
+            }
+        }
+        Ok(ids.len())
+    }
+}
+
+fn to_view(club: &Club, version: u64) -> PrimitiveClubView {
+    PrimitiveClubView {
+        club_id: club.get_id().to_string(),
+        version,
+        name: club.get_name().to_string(),
+        owner: club.get_owner_id().to_string(),
+        members: club.get_members().iter().map(|m| m.to_string()).collect(),
+    }
+}
+
+fn to_club(view: &PrimitiveClubView) -> Result<Club> {
+    Club::from_snapshot(
+        ClubId::new(&view.club_id)?,
+        ClubName::new(&view.name)?,
+        view.members
+            .iter()
+            .map(|m| UserId::new(m))
+            .collect::<Result<Vec<_>>>()?,
+        UserId::new(&view.owner)?,
+        view.version,
+    )
+}
+
+#[async_trait]
+impl ClubDatabaseTraitWrapper for EventSourcedClubDatabase {
+    async fn save(&self, club: &Club, audit: Option<&AuditEntry>) -> Result<()> {
+        let events = PrimitiveEvent::from_events(club.get_events());
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let version = club.get_version();
+        let new_version = version + events.len() as u64;
+        let view = to_view(club, new_version);
+        let audit = audit.map(PrimitiveAuditEntry::from_entry);
+        self.store
+            .append(&view, version, &events, audit.as_ref())
+            .await?;
+
+        if new_version / self.snapshot_interval > version / self.snapshot_interval {
+            let snapshot = PrimitiveClubSnapshot {
+                club_id: view.club_id,
+                version: new_version,
+                name: view.name,
+                owner: view.owner,
+                members: view.members,
+                taken_at: Utc::now(),
+            };
+            self.store.save_snapshot(&snapshot).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_name(&self, club_name: &ClubName) -> Result<Option<Club>> {
+        Ok(self
+            .find_views(&ClubViewQuery {
+                name: Some(club_name.to_string()),
+                ..Default::default()
+            })
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    async fn find_by_id(&self, id: &ClubId) -> Result<Option<Club>> {
+        self.load(&id.to_string()).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<Club>> {
+        self.find_views(&ClubViewQuery::default()).await
+    }
+
+    async fn find_page(&self, query: &ClubListQuery) -> Result<Vec<Club>> {
+        self.find_views(&ClubViewQuery {
+            name_contains: query.name_contains.clone(),
+            after: query
+                .after
+                .as_ref()
+                .map(|cursor| (cursor.name.to_string(), cursor.id.to_string())),
+            limit: Some(query.limit),
+            ..Default::default()
+        })
+        .await
+    }
+
+    async fn find_by_owner(&self, owner: &UserId) -> Result<Vec<Club>> {
+        self.find_views(&ClubViewQuery {
+            owner: Some(owner.to_string()),
+            ..Default::default()
+        })
+        .await
+    }
+
+    async fn find_by_member(&self, member: &UserId) -> Result<Vec<Club>> {
+        self.find_views(&ClubViewQuery {
+            member: Some(member.to_string()),
+            ..Default::default()
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::model::{
+        club::entity::{Club, ClubId, ClubName},
+        error::DomainError,
+        user::entity::{User, UserId, UserIsPremium, UserName},
+    };
+    use crate::infrastructure::database::club_event::InMemoryClubEventStore;
+    use crate::interface::repository::club::{ClubDatabaseTraitWrapper, ClubEventStoreTrait};
+
+    use super::EventSourcedClubDatabase;
+
+    fn user(id: &str) -> User {
+        User::new(
+            UserId::new(id).unwrap(),
+            UserName::new(id).unwrap(),
+            UserIsPremium::new(false),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn rebuilds_clubs_from_snapshots_and_rejects_stale_saves() {
+        let database = EventSourcedClubDatabase::new(Box::new(InMemoryClubEventStore::new()), 3);
+        let id = ClubId::new("es-club").unwrap();
+        let club = Club::new(
+            id.clone(),
+            ClubName::new("es-club").unwrap(),
+            Vec::new(),
+            UserId::new("es-owner").unwrap(),
+        )
+        .unwrap();
+        database.save(&club, None).await.unwrap();
+
+        let mut club = database.find_by_id(&id).await.unwrap().unwrap();
+        club.join(user("es-first")).unwrap();
+        club.join(user("es-second")).unwrap();
+        database.save(&club, None).await.unwrap();
+
+        let stale = database.find_by_id(&id).await.unwrap().unwrap();
+        let mut club = stale.clone();
+        club.change_name(ClubName::new("es-renamed").unwrap())
+            .unwrap();
+        club.transfer_ownership(&UserId::new("es-first").unwrap())
+            .unwrap();
+        database.save(&club, None).await.unwrap();
+
+        let stream = InMemoryClubEventStore::new()
+            .load(&id.to_string())
+            .await
+            .unwrap();
+        assert_eq!(stream.snapshot.map(|s| s.version), Some(3));
+        assert_eq!(stream.events.len(), 2);
+
+        let club = database.find_by_id(&id).await.unwrap().unwrap();
+        assert_eq!(club.get_version(), 5);
+        assert_eq!(club.get_name().to_string(), "es-renamed");
+        assert_eq!(club.get_owner_id().to_string(), "es-first");
+        assert_eq!(
+            club.get_members()
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<_>>(),
+            vec!["es-second", "es-owner"]
+        );
+
+        let mut stale = stale;
+        stale.leave(&UserId::new("es-second").unwrap()).unwrap();
+        let error = database.save(&stale, None).await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<DomainError>(),
+            Some(DomainError::Conflict(_))
+        ));
+    }
+}
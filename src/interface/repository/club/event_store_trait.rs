@@ -0,0 +1,79 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::interface::repository::{audit::PrimitiveAuditEntry, outbox::PrimitiveEvent};
+
+/// The state of a club after the first `version` events of its stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimitiveClubSnapshot {
+    pub club_id: String,
+    pub version: u64,
+    pub name: String,
+    pub owner: String,
+    pub members: Vec<String>,
+    pub taken_at: DateTime<Utc>,
+}
+
+/// The current state of a club, written with its events so that clubs can be
+/// found and listed without replaying their streams.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimitiveClubView {
+    pub club_id: String,
+    pub version: u64,
+    pub name: String,
+    pub owner: String,
+    pub members: Vec<String>,
+}
+
+/// Filters of `ClubEventStoreTrait::find_views`. Views come ordered by name
+/// and then id.
+#[derive(Debug, Clone, Default)]
+pub struct ClubViewQuery {
+    pub name: Option<String>,
+    pub name_contains: Option<String>,
+    /// The name and id of the last club of the previous page.
+    pub after: Option<(String, String)>,
+    pub owner: Option<String>,
+    pub member: Option<String>,
+    pub limit: Option<usize>,
+}
+
+/// An event of a club stream with its position in the stream.
+#[derive(Debug, Clone)]
+pub struct StoredClubEvent {
+    pub version: u64,
+    pub event: PrimitiveEvent,
+}
+
+/// The latest snapshot of a club and the events recorded after it.
+#[derive(Debug, Clone, Default)]
+pub struct ClubStream {
+    pub snapshot: Option<PrimitiveClubSnapshot>,
+    pub events: Vec<StoredClubEvent>,
+}
+
+/// Stores clubs as append-only streams of events.
+#[async_trait]
+pub trait ClubEventStoreTrait {
+    /// Appends `events` to the club's stream and to the outbox, writes `audit`
+    /// to the audit log and replaces the club's view in one transaction. Fails with a conflict unless the
+    /// stream holds exactly `expected_version` events, so that a club changed
+    /// by someone else since it was loaded is not overwritten, or when
+    /// another club has the name of the view.
+    async fn append(
+        &self,
+        view: &PrimitiveClubView,
+        expected_version: u64,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()>;
+    async fn load(&self, club_id: &str) -> Result<ClubStream>;
+    /// Replaces the club's snapshot unless a later one exists.
+    async fn save_snapshot(&self, snapshot: &PrimitiveClubSnapshot) -> Result<()>;
+    async fn find_views(&self, query: &ClubViewQuery) -> Result<Vec<PrimitiveClubView>>;
+    async fn save_view(&self, view: &PrimitiveClubView) -> Result<()>;
+    /// The clubs whose stream has no view yet, e.g. clubs saved before views
+    /// were kept.
+    async fn club_ids_without_view(&self) -> Result<Vec<String>>;
+}
@@ -1,4 +1,6 @@
 mod database_trait;
+mod event_sourced_database;
+mod event_store_trait;
 mod repository;
 
-pub use self::{database_trait::*, repository::*};
+pub use self::{database_trait::*, event_sourced_database::*, event_store_trait::*, repository::*};
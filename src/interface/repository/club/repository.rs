@@ -2,26 +2,35 @@ use anyhow::Result;
 use async_trait::async_trait;
 
 use crate::domain::model::{
+    audit::entity::AuditEntry,
     club::{
         entity::{Club, ClubId, ClubName},
-        repository::ClubRepositoryTrait,
+        repository::{ClubListQuery, ClubRepositoryTrait},
     },
+    event::DomainEvent,
     user::entity::UserId,
 };
+use crate::interface::repository::{audit::PrimitiveAuditEntry, outbox::PrimitiveEvent};
 
-use super::database_trait::ClubDatabaseTrait;
+use super::database_trait::{ClubDatabaseTrait, ClubPageQuery, PrimitiveMemberChanges};
 
 #[async_trait]
 pub trait ClubDatabaseTraitWrapper {
-    async fn save(&self, club: &Club) -> Result<()>;
+    async fn save(&self, club: &Club, audit: Option<&AuditEntry>) -> Result<()>;
     async fn find_by_name(&self, club_name: &ClubName) -> Result<Option<Club>>;
     async fn find_by_id(&self, id: &ClubId) -> Result<Option<Club>>;
     async fn find_all(&self) -> Result<Vec<Club>>;
+    async fn find_page(&self, query: &ClubListQuery) -> Result<Vec<Club>>;
+    async fn find_by_owner(&self, owner: &UserId) -> Result<Vec<Club>>;
+    async fn find_by_member(&self, member: &UserId) -> Result<Vec<Club>>;
 }
 
 #[async_trait]
 impl<D: ClubDatabaseTrait + Send + Sync> ClubDatabaseTraitWrapper for D {
-    async fn save(&self, club: &Club) -> Result<()> {
+    async fn save(&self, club: &Club, audit: Option<&AuditEntry>) -> Result<()> {
+        let events = PrimitiveEvent::from_events(club.get_events());
+        let audit = audit.map(PrimitiveAuditEntry::from_entry);
+        let changes = member_changes(club);
         let club = D::to_club_data(
             &club.get_id().to_string(),
             &club.get_name().to_string(),
@@ -32,38 +41,23 @@ impl<D: ClubDatabaseTrait + Send + Sync> ClubDatabaseTraitWrapper for D {
                 .map(|m| m.to_string())
                 .collect::<Vec<String>>(),
         )?;
-        self.save(&club).await
+        self.save(&club, &changes, &events, audit.as_ref()).await
     }
 
     async fn find_by_name(&self, club_name: &ClubName) -> Result<Option<Club>> {
         let club_name = D::to_club_name(&club_name.to_string())?;
-        let club = self.find_by_name(&club_name).await?;
-        let club = D::from_club_data(&club)?;
-
-        let club_id = ClubId::new(&club.0)?;
-        let club_name = ClubName::new(&club.1)?;
-        let user_id = UserId::new(&club.2)?;
-        let club = Club::new(club_id, club_name, Vec::new(), user_id)?;
-
-        Ok(Some(club))
+        self.find_by_name(&club_name)
+            .await?
+            .map(|club| to_club::<D>(&club))
+            .transpose()
     }
 
     async fn find_by_id(&self, club_id: &ClubId) -> Result<Option<Club>> {
         let club_id = D::to_club_id(&club_id.to_string())?;
-        let club = self.find_by_id(&club_id).await?;
-        let club = D::from_club_data(&club)?;
-
-        let club_id = ClubId::new(&club.0)?;
-        let club_name = ClubName::new(&club.1)?;
-        let user_id = UserId::new(&club.2)?;
-        let member = club
-            .3
-            .iter()
-            .map(|u| UserId::new(u).unwrap())
-            .collect::<Vec<UserId>>();
-        let club = Club::new(club_id, club_name, member, user_id)?;
-
-        Ok(Some(club))
+        self.find_by_id(&club_id)
+            .await?
+            .map(|club| to_club::<D>(&club))
+            .transpose()
     }
 
     async fn find_all(&self) -> Result<Vec<Club>> {
@@ -77,10 +71,105 @@ impl<D: ClubDatabaseTrait + Send + Sync> ClubDatabaseTraitWrapper for D {
                 let name = ClubName::new(&c.1).unwrap();
                 let owner = UserId::new(&c.2).unwrap();
                 let members = c.3.iter().map(|u| UserId::new(u).unwrap()).collect();
-                Club::new(id, name, members, owner).unwrap()
+                Club::restore(id, name, members, owner).unwrap()
             })
             .collect())
     }
+
+    async fn find_page(&self, query: &ClubListQuery) -> Result<Vec<Club>> {
+        let after = match &query.after {
+            Some(cursor) => Some((
+                D::to_club_id(&cursor.id.to_string())?,
+                cursor.name.to_string(),
+            )),
+            None => None,
+        };
+        let query = ClubPageQuery {
+            name_contains: query.name_contains.clone(),
+            after,
+            limit: query.limit,
+        };
+
+        self.find_page(&query)
+            .await?
+            .iter()
+            .map(to_club::<D>)
+            .collect()
+    }
+
+    async fn find_by_owner(&self, owner: &UserId) -> Result<Vec<Club>> {
+        let owner = D::to_club_owner(&owner.to_string())?;
+        self.find_by_owner(&owner)
+            .await?
+            .iter()
+            .map(to_club::<D>)
+            .collect()
+    }
+
+    async fn find_by_member(&self, member: &UserId) -> Result<Vec<Club>> {
+        let member = D::to_club_owner(&member.to_string())?;
+        self.find_by_member(&member)
+            .await?
+            .iter()
+            .map(to_club::<D>)
+            .collect()
+    }
+}
+
+/// The members added and removed by the events recorded since the club was
+/// loaded. A new club adds all of its members.
+fn member_changes(club: &Club) -> PrimitiveMemberChanges {
+    fn join(changes: &mut PrimitiveMemberChanges, member: &UserId) {
+        let member = member.to_string();
+        changes.left.retain(|m| *m != member);
+        if !changes.joined.contains(&member) {
+            changes.joined.push(member);
+        }
+    }
+    fn leave(changes: &mut PrimitiveMemberChanges, member: &UserId) {
+        let member = member.to_string();
+        changes.joined.retain(|m| *m != member);
+        if !changes.left.contains(&member) {
+            changes.left.push(member);
+        }
+    }
+
+    let mut changes = PrimitiveMemberChanges::default();
+    for event in club.get_events() {
+        match event {
+            DomainEvent::ClubCreated { .. } => {
+                return PrimitiveMemberChanges {
+                    joined: club.get_members().iter().map(|m| m.to_string()).collect(),
+                    left: Vec::new(),
+                };
+            }
+            DomainEvent::MemberJoined { user_id, .. } => join(&mut changes, user_id),
+            DomainEvent::MemberLeft { user_id, .. } => leave(&mut changes, user_id),
+            DomainEvent::OwnershipTransferred {
+                previous_owner,
+                new_owner,
+                ..
+            } => {
+                leave(&mut changes, new_owner);
+                join(&mut changes, previous_owner);
+            }
+            _ => {}
+        }
+    }
+    changes
+}
+
+fn to_club<D: ClubDatabaseTrait>(club: &D::ClubData) -> Result<Club> {
+    let club = D::from_club_data(club)?;
+    let id = ClubId::new(&club.0)?;
+    let name = ClubName::new(&club.1)?;
+    let owner = UserId::new(&club.2)?;
+    let members = club
+        .3
+        .iter()
+        .map(|u| UserId::new(u))
+        .collect::<Result<Vec<UserId>>>()?;
+    Club::restore(id, name, members, owner)
 }
 
 pub struct ClubRepository {
@@ -89,8 +178,15 @@ pub struct ClubRepository {
 
 #[async_trait]
 impl ClubRepositoryTrait for ClubRepository {
-    async fn save(&self, club: &Club) -> Result<()> {
-        self.database.save(club).await
+    async fn save(&self, club: &mut Club) -> Result<()> {
+        self.database.save(club, None).await?;
+        club.clear_events();
+        Ok(())
+    }
+    async fn save_audited(&self, club: &mut Club, entry: &AuditEntry) -> Result<()> {
+        self.database.save(club, Some(entry)).await?;
+        club.clear_events();
+        Ok(())
     }
     async fn find_by_name(&self, club_name: &ClubName) -> Result<Option<Club>> {
         self.database.find_by_name(club_name).await
@@ -101,6 +197,15 @@ impl ClubRepositoryTrait for ClubRepository {
     async fn find_all(&self) -> Result<Vec<Club>> {
         self.database.find_all().await
     }
+    async fn find_page(&self, query: &ClubListQuery) -> Result<Vec<Club>> {
+        self.database.find_page(query).await
+    }
+    async fn find_by_owner(&self, owner: &UserId) -> Result<Vec<Club>> {
+        self.database.find_by_owner(owner).await
+    }
+    async fn find_by_member(&self, member: &UserId) -> Result<Vec<Club>> {
+        self.database.find_by_member(member).await
+    }
 }
 
 impl ClubRepository {
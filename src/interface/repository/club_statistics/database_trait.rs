@@ -0,0 +1,25 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// A row of the club statistics projection in the primitive types every
+/// database can convert from and to.
+#[derive(Debug, Clone)]
+pub struct PrimitiveClubStatistics {
+    pub club_id: String,
+    pub name: String,
+    pub owner_id: String,
+    pub member_count: u32,
+    pub premium_member_count: u32,
+    pub last_joined_at: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+pub trait ClubStatisticsDatabaseTrait {
+    /// Inserts the row or replaces the one of the same club.
+    async fn save(&self, statistics: &PrimitiveClubStatistics) -> Result<()>;
+    async fn find_by_id(&self, club_id: &str) -> Result<Option<PrimitiveClubStatistics>>;
+    async fn find_all(&self) -> Result<Vec<PrimitiveClubStatistics>>;
+    async fn delete(&self, club_id: &str) -> Result<()>;
+    async fn delete_all(&self) -> Result<()>;
+}
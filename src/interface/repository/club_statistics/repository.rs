@@ -0,0 +1,72 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::model::{
+    club::{
+        entity::{ClubId, ClubName, ClubStatistics},
+        repository::ClubStatisticsRepositoryTrait,
+    },
+    user::entity::UserId,
+};
+
+use super::{ClubStatisticsDatabaseTrait, PrimitiveClubStatistics};
+
+fn to_statistics(statistics: &PrimitiveClubStatistics) -> Result<ClubStatistics> {
+    Ok(ClubStatistics {
+        club_id: ClubId::new(&statistics.club_id)?,
+        name: ClubName::new(&statistics.name)?,
+        owner_id: UserId::new(&statistics.owner_id)?,
+        member_count: statistics.member_count as usize,
+        premium_member_count: statistics.premium_member_count as usize,
+        last_joined_at: statistics.last_joined_at,
+    })
+}
+
+fn to_primitive(statistics: &ClubStatistics) -> PrimitiveClubStatistics {
+    PrimitiveClubStatistics {
+        club_id: statistics.club_id.to_string(),
+        name: statistics.name.to_string(),
+        owner_id: statistics.owner_id.to_string(),
+        member_count: statistics.member_count as u32,
+        premium_member_count: statistics.premium_member_count as u32,
+        last_joined_at: statistics.last_joined_at,
+    }
+}
+
+pub struct ClubStatisticsRepository {
+    database: Box<dyn ClubStatisticsDatabaseTrait + Send + Sync>,
+}
+
+#[async_trait]
+impl ClubStatisticsRepositoryTrait for ClubStatisticsRepository {
+    async fn save(&self, statistics: &ClubStatistics) -> Result<()> {
+        self.database.save(&to_primitive(statistics)).await
+    }
+    async fn find_by_id(&self, id: &ClubId) -> Result<Option<ClubStatistics>> {
+        self.database
+            .find_by_id(&id.to_string())
+            .await?
+            .map(|statistics| to_statistics(&statistics))
+            .transpose()
+    }
+    async fn find_all(&self) -> Result<Vec<ClubStatistics>> {
+        self.database
+            .find_all()
+            .await?
+            .iter()
+            .map(to_statistics)
+            .collect()
+    }
+    async fn delete(&self, id: &ClubId) -> Result<()> {
+        self.database.delete(&id.to_string()).await
+    }
+    async fn delete_all(&self) -> Result<()> {
+        self.database.delete_all().await
+    }
+}
+
+impl ClubStatisticsRepository {
+    pub async fn new(database: Box<dyn ClubStatisticsDatabaseTrait + Send + Sync>) -> Result<Self> {
+        Ok(Self { database })
+    }
+}
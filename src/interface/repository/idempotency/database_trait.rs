@@ -0,0 +1,45 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// The response sent for a request, kept to be sent again when the request
+/// is retried with the same idempotency key.
+#[derive(Debug, Clone)]
+pub struct PrimitiveIdempotentResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// A request made with an idempotency key. `scope` is the caller the key
+/// belongs to, so that callers cannot see each other's responses. Until the
+/// response is known the key is locked until `locked_until`.
+#[derive(Debug, Clone)]
+pub struct PrimitiveIdempotencyRecord {
+    pub scope: String,
+    pub key: String,
+    pub fingerprint: String,
+    pub response: Option<PrimitiveIdempotentResponse>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub locked_until: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait IdempotencyDatabaseTrait {
+    /// Stores `record` unless its key is held by a record that has not
+    /// expired and is either answered or still locked at `record.created_at`.
+    /// Returns whether the record was stored.
+    async fn claim(&self, record: &PrimitiveIdempotencyRecord) -> Result<bool>;
+    async fn find(&self, scope: &str, key: &str) -> Result<Option<PrimitiveIdempotencyRecord>>;
+    /// Keeps the response of a claimed key.
+    async fn complete(
+        &self,
+        scope: &str,
+        key: &str,
+        response: &PrimitiveIdempotentResponse,
+    ) -> Result<()>;
+    /// Frees a claimed key without a response, so that the request can be
+    /// retried.
+    async fn release(&self, scope: &str, key: &str) -> Result<()>;
+}
@@ -1,2 +1,8 @@
+pub mod api_key;
+pub mod audit;
 pub mod club;
+pub mod club_statistics;
+pub mod idempotency;
+pub mod outbox;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::{json, Map, Value};
+use uuid::Uuid;
+
+use crate::domain::model::{
+    club::entity::{ClubId, ClubName},
+    event::DomainEvent,
+    user::entity::{UserId, UserName},
+};
+
+/// A domain event as it is stored in the outbox. `payload` holds the fields of
+/// the event as a JSON object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimitiveEvent {
+    pub id: String,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub payload: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl PrimitiveEvent {
+    pub fn from_event(event: &DomainEvent) -> Self {
+        let payload = match event {
+            DomainEvent::UserRegistered { user_id, name }
+            | DomainEvent::UserRenamed { user_id, name } => {
+                json!({ "user_id": user_id.to_string(), "name": name.to_string() })
+            }
+            DomainEvent::UserUpgraded { user_id }
+            | DomainEvent::UserDowngraded { user_id }
+            | DomainEvent::UserDeleted { user_id }
+            | DomainEvent::UserRestored { user_id } => {
+                json!({ "user_id": user_id.to_string() })
+            }
+            DomainEvent::ClubCreated {
+                club_id,
+                name,
+                owner,
+            } => json!({
+                "club_id": club_id.to_string(),
+                "name": name.to_string(),
+                "owner": owner.to_string(),
+            }),
+            DomainEvent::ClubRenamed { club_id, name } => {
+                json!({ "club_id": club_id.to_string(), "name": name.to_string() })
+            }
+            DomainEvent::MemberJoined { club_id, user_id }
+            | DomainEvent::MemberLeft { club_id, user_id } => {
+                json!({ "club_id": club_id.to_string(), "user_id": user_id.to_string() })
+            }
+            DomainEvent::OwnershipTransferred {
+                club_id,
+                previous_owner,
+                new_owner,
+            } => json!({
+                "club_id": club_id.to_string(),
+                "previous_owner": previous_owner.to_string(),
+                "new_owner": new_owner.to_string(),
+            }),
+        };
+
+        Self {
+            id: Uuid::new_v4().to_string(),
+            event_type: event.name().to_string(),
+            aggregate_id: event.aggregate_id(),
+            payload: payload.to_string(),
+            occurred_at: Utc::now(),
+        }
+    }
+
+    pub fn from_events(events: &[DomainEvent]) -> Vec<Self> {
+        events.iter().map(Self::from_event).collect()
+    }
+
+    pub fn to_event(&self) -> Result<DomainEvent> {
+        let payload = serde_json::from_str::<Map<String, Value>>(&self.payload)?;
+        let field = |key: &str| {
+            payload.get(key).and_then(Value::as_str).ok_or_else(|| {
+                anyhow!(
+                    "Event {} ({}) has no `{}` field",
+                    self.id,
+                    self.event_type,
+                    key
+                )
+            })
+        };
+
+        let event = match self.event_type.as_str() {
+            "UserRegistered" => DomainEvent::UserRegistered {
+                user_id: UserId::new(field("user_id")?)?,
+                name: UserName::new(field("name")?)?,
+            },
+            "UserRenamed" => DomainEvent::UserRenamed {
+                user_id: UserId::new(field("user_id")?)?,
+                name: UserName::new(field("name")?)?,
+            },
+            "UserUpgraded" => DomainEvent::UserUpgraded {
+                user_id: UserId::new(field("user_id")?)?,
+            },
+            "UserDowngraded" => DomainEvent::UserDowngraded {
+                user_id: UserId::new(field("user_id")?)?,
+            },
+            "UserDeleted" => DomainEvent::UserDeleted {
+                user_id: UserId::new(field("user_id")?)?,
+            },
+            "UserRestored" => DomainEvent::UserRestored {
+                user_id: UserId::new(field("user_id")?)?,
+            },
+            "ClubCreated" => DomainEvent::ClubCreated {
+                club_id: ClubId::new(field("club_id")?)?,
+                name: ClubName::new(field("name")?)?,
+                owner: UserId::new(field("owner")?)?,
+            },
+            "ClubRenamed" => DomainEvent::ClubRenamed {
+                club_id: ClubId::new(field("club_id")?)?,
+                name: ClubName::new(field("name")?)?,
+            },
+            "MemberJoined" => DomainEvent::MemberJoined {
+                club_id: ClubId::new(field("club_id")?)?,
+                user_id: UserId::new(field("user_id")?)?,
+            },
+            "MemberLeft" => DomainEvent::MemberLeft {
+                club_id: ClubId::new(field("club_id")?)?,
+                user_id: UserId::new(field("user_id")?)?,
+            },
+            "OwnershipTransferred" => DomainEvent::OwnershipTransferred {
+                club_id: ClubId::new(field("club_id")?)?,
+                previous_owner: UserId::new(field("previous_owner")?)?,
+                new_owner: UserId::new(field("new_owner")?)?,
+            },
+            other => bail!("Unknown event type `{}`", other),
+        };
+
+        Ok(event)
+    }
+}
+
+/// An unpublished event handed to the relay, with the number of attempts
+/// that already failed.
+#[derive(Debug, Clone)]
+pub struct OutboxRecord {
+    pub event: PrimitiveEvent,
+    pub attempts: u32,
+}
+
+/// Counts of outbox entries. `dead` events failed `max_attempts` times and
+/// are no longer retried.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutboxStats {
+    pub pending: u64,
+    pub retrying: u64,
+    pub dead: u64,
+    pub published: u64,
+}
+
+/// Reads and updates the outbox. Events are written to it by the user and
+/// club databases, in the same transaction as the change they describe.
+#[async_trait]
+pub trait OutboxDatabaseTrait {
+    /// Returns up to `limit` unpublished events that are due at `now` and have
+    /// failed fewer than `max_attempts` times, oldest first. They are not
+    /// returned again before `lease_until`, so that relays running side by
+    /// side do not publish the same event at the same time.
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+        max_attempts: u32,
+    ) -> Result<Vec<OutboxRecord>>;
+    async fn mark_published(&self, id: &str, published_at: DateTime<Utc>) -> Result<()>;
+    /// Records a failed attempt and when to try again.
+    async fn mark_failed(&self, id: &str, error: &str, retry_at: DateTime<Utc>) -> Result<()>;
+    async fn stats(&self, max_attempts: u32) -> Result<OutboxStats>;
+    /// When each aggregate last recorded an event of `event_type`, by
+    /// aggregate id.
+    async fn last_occurred_at(&self, event_type: &str) -> Result<HashMap<String, DateTime<Utc>>>;
+}
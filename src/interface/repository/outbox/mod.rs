@@ -0,0 +1,3 @@
+mod database_trait;
+
+pub use self::database_trait::*;
@@ -1,5 +1,32 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::model::user::repository::{SortOrder, UserSortKey};
+use crate::interface::repository::{audit::PrimitiveAuditEntry, outbox::PrimitiveEvent};
+
+/// A user in the primitive types every database can convert from and to.
+#[derive(Debug, Clone)]
+pub struct PrimitiveUser {
+    pub id: String,
+    pub name: String,
+    pub is_premium: bool,
+    pub premium_since: Option<DateTime<Utc>>,
+    pub premium_expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// A page request expressed in the database's own id type. `after` holds the
+/// id and name of the last row of the previous page.
+pub struct UserPageQuery<UserId> {
+    pub is_premium: Option<bool>,
+    pub name_prefix: Option<String>,
+    pub sort_key: UserSortKey,
+    pub sort_order: SortOrder,
+    pub after: Option<(UserId, String)>,
+    pub limit: usize,
+}
 
 #[async_trait]
 pub trait UserDatabaseTrait {
@@ -11,16 +38,29 @@ pub trait UserDatabaseTrait {
     fn from_user_id(id: &Self::UserId) -> Result<String>;
     fn from_user_name(name: &Self::UserName) -> Result<String>;
     fn from_user_is_premium(is_premium: Self::UserIsPremium) -> Result<bool>;
-    fn from_user_data(user: &Self::UserData) -> Result<(String, String, bool)>;
+    fn from_user_data(user: &Self::UserData) -> Result<PrimitiveUser>;
 
     fn to_user_id(value: &str) -> Result<Self::UserId>;
     fn to_user_name(value: &str) -> Result<Self::UserName>;
     fn to_user_is_premium(value: bool) -> Result<Self::UserIsPremium>;
-    fn to_user_data(id: &str, name: &str, is_premium: bool) -> Result<Self::UserData>;
+    fn to_user_data(user: &PrimitiveUser) -> Result<Self::UserData>;
 
-    async fn save(&self, user: &Self::UserData) -> Result<()>;
-    async fn find(&self, user_name: &Self::UserName) -> Result<Self::UserData>;
-    async fn find_by_id(&self, id: &Self::UserId) -> Result<Self::UserData>;
-    async fn delete(&self, id: &Self::UserId) -> Result<()>;
+    /// Saves the user, appends `events` to the outbox and writes `audit` to
+    /// the audit log in one transaction.
+    async fn save(
+        &self,
+        user: &Self::UserData,
+        events: &[PrimitiveEvent],
+        audit: Option<&PrimitiveAuditEntry>,
+    ) -> Result<()>;
+    async fn find(&self, user_name: &Self::UserName) -> Result<Option<Self::UserData>>;
+    async fn find_by_id(&self, id: &Self::UserId) -> Result<Option<Self::UserData>>;
+    async fn find_deleted_by_id(&self, id: &Self::UserId) -> Result<Option<Self::UserData>>;
+    async fn find_deleted_before(&self, deleted_before: DateTime<Utc>)
+        -> Result<Vec<Self::UserId>>;
+    /// Deletes the user if they are soft-deleted, along with their rows in
+    /// other tables.
+    async fn purge(&self, id: &Self::UserId) -> Result<()>;
     async fn batch_find(&self, users: Vec<Self::UserId>) -> Result<Vec<Self::UserData>>;
+    async fn find_page(&self, query: &UserPageQuery<Self::UserId>) -> Result<Vec<Self::UserData>>;
 }
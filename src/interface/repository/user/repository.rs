@@ -1,79 +1,137 @@
-use crate::domain::model::user::{
-    entity::{User, UserId, UserIsPremium, UserName},
-    repository::UserRepositoryTrait,
+use crate::domain::model::{
+    audit::entity::AuditEntry,
+    user::{
+        entity::{User, UserId, UserIsPremium, UserName},
+        repository::{UserListQuery, UserRepositoryTrait},
+    },
+};
+use crate::interface::repository::{
+    audit::PrimitiveAuditEntry,
+    outbox::PrimitiveEvent,
+    user::{PrimitiveUser, UserDatabaseTrait, UserPageQuery},
 };
-use crate::interface::repository::user::UserDatabaseTrait;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 #[async_trait]
 pub trait UserDatabaseTraitWrapper {
-    async fn save(&self, user: &User) -> Result<()>;
+    async fn save(&self, user: &User, audit: Option<&AuditEntry>) -> Result<()>;
     async fn find_by_name(&self, user_name: &UserName) -> Result<Option<User>>;
     async fn find_by_id(&self, id: &UserId) -> Result<Option<User>>;
-    async fn delete(&self, id: &UserId) -> Result<()>;
+    async fn find_deleted_by_id(&self, id: &UserId) -> Result<Option<User>>;
+    async fn find_deleted_before(&self, deleted_before: DateTime<Utc>) -> Result<Vec<UserId>>;
+    async fn purge(&self, id: &UserId) -> Result<()>;
     async fn batch_find(&self, users: Vec<UserId>) -> Result<Vec<User>>;
+    async fn find_page(&self, query: &UserListQuery) -> Result<Vec<User>>;
+}
+
+fn to_primitive(user: &User) -> PrimitiveUser {
+    PrimitiveUser {
+        id: user.get_id().to_string(),
+        name: user.get_name().to_string(),
+        is_premium: user.get_is_premium().to_inner(),
+        premium_since: user.get_premium_since().cloned(),
+        premium_expires_at: user.get_premium_expires_at().cloned(),
+        created_at: *user.get_created_at(),
+        deleted_at: user.get_deleted_at().cloned(),
+    }
+}
+
+fn to_user<D: UserDatabaseTrait>(user: &D::UserData) -> Result<User> {
+    let user = D::from_user_data(user)?;
+    User::restore(
+        UserId::new(&user.id)?,
+        UserName::new(&user.name)?,
+        UserIsPremium::new(user.is_premium),
+        user.premium_since,
+        user.premium_expires_at,
+        user.created_at,
+        user.deleted_at,
+    )
 }
 
 #[async_trait]
 impl<D: UserDatabaseTrait + Send + Sync> UserDatabaseTraitWrapper for D {
-    async fn save(&self, user: &User) -> Result<()> {
-        let user = D::to_user_data(
-            &user.get_id().to_string(),
-            &user.get_name().to_string(),
-            user.get_is_premium().to_inner(),
-        )?;
-        self.save(&user).await
+    async fn save(&self, user: &User, audit: Option<&AuditEntry>) -> Result<()> {
+        let events = PrimitiveEvent::from_events(user.get_events());
+        let audit = audit.map(PrimitiveAuditEntry::from_entry);
+        let user = D::to_user_data(&to_primitive(user))?;
+        self.save(&user, &events, audit.as_ref()).await
     }
 
     async fn find_by_name(&self, user_name: &UserName) -> Result<Option<User>> {
         let user_name = D::to_user_name(&user_name.to_string())?;
-        let user = self.find(&user_name).await?;
-        let user = D::from_user_data(&user)?;
-
-        let user_id = UserId::new(&user.0)?;
-        let user_name = UserName::new(&user.1)?;
-        let is_premium = UserIsPremium::new(user.2);
-        let user = User::new(user_id, user_name, is_premium)?;
-
-        Ok(Some(user))
+        self.find(&user_name)
+            .await?
+            .map(|user| to_user::<D>(&user))
+            .transpose()
     }
 
     async fn find_by_id(&self, user_id: &UserId) -> Result<Option<User>> {
         let user_id = D::to_user_id(&user_id.to_string())?;
-        let user = self.find_by_id(&user_id).await?;
-        let user = D::from_user_data(&user)?;
+        self.find_by_id(&user_id)
+            .await?
+            .map(|user| to_user::<D>(&user))
+            .transpose()
+    }
 
-        let user_id = UserId::new(&user.0)?;
-        let user_name = UserName::new(&user.1)?;
-        let is_premium = UserIsPremium::new(user.2);
-        let user = User::new(user_id, user_name, is_premium)?;
+    async fn find_deleted_by_id(&self, user_id: &UserId) -> Result<Option<User>> {
+        let user_id = D::to_user_id(&user_id.to_string())?;
+        self.find_deleted_by_id(&user_id)
+            .await?
+            .map(|user| to_user::<D>(&user))
+            .transpose()
+    }
 
-        Ok(Some(user))
+    async fn find_deleted_before(&self, deleted_before: DateTime<Utc>) -> Result<Vec<UserId>> {
+        self.find_deleted_before(deleted_before)
+            .await?
+            .iter()
+            .map(|id| UserId::new(&D::from_user_id(id)?))
+            .collect()
     }
 
-    async fn delete(&self, user_id: &UserId) -> Result<()> {
+    async fn purge(&self, user_id: &UserId) -> Result<()> {
         let user_id = D::to_user_id(&user_id.to_string())?;
-        self.delete(&user_id).await
+        self.purge(&user_id).await
     }
 
     async fn batch_find(&self, users: Vec<UserId>) -> Result<Vec<User>> {
         let users = users
             .iter()
-            .map(|u| D::to_user_id(&u.to_string()).unwrap()) // TODO: Rewrite without unwrap
-            .collect::<Vec<D::UserId>>();
+            .map(|u| D::to_user_id(&u.to_string()))
+            .collect::<Result<Vec<D::UserId>>>()?;
 
-        let users = self.batch_find(users).await?;
-        users
+        self.batch_find(users)
+            .await?
             .iter()
-            .map(|u| D::from_user_data(u).unwrap())
-            .map(|u| {
-                let user_id = UserId::new(&u.0).unwrap();
-                let user_name = UserName::new(&u.1).unwrap();
-                let is_premium = UserIsPremium::new(u.2);
-                User::new(user_id, user_name, is_premium)
-            })
+            .map(to_user::<D>)
+            .collect()
+    }
+
+    async fn find_page(&self, query: &UserListQuery) -> Result<Vec<User>> {
+        let after = match &query.after {
+            Some(cursor) => Some((
+                D::to_user_id(&cursor.id.to_string())?,
+                cursor.name.to_string(),
+            )),
+            None => None,
+        };
+        let query = UserPageQuery {
+            is_premium: query.is_premium,
+            name_prefix: query.name_prefix.clone(),
+            sort_key: query.sort_key,
+            sort_order: query.sort_order,
+            after,
+            limit: query.limit,
+        };
+
+        self.find_page(&query)
+            .await?
+            .iter()
+            .map(to_user::<D>)
             .collect()
     }
 }
@@ -84,16 +142,32 @@ pub struct UserRepository {
 
 #[async_trait]
 impl UserRepositoryTrait for UserRepository {
-    async fn save(&self, user: &User) -> Result<()> {
-        self.database.save(user).await
+    async fn save(&self, user: &mut User) -> Result<()> {
+        self.database.save(user, None).await?;
+        user.clear_events();
+        Ok(())
+    }
+
+    async fn save_audited(&self, user: &mut User, entry: &AuditEntry) -> Result<()> {
+        self.database.save(user, Some(entry)).await?;
+        user.clear_events();
+        Ok(())
     }
 
     async fn find_by_name(&self, user_name: &UserName) -> Result<Option<User>> {
         self.database.find_by_name(user_name).await
     }
 
-    async fn delete(&self, id: &UserId) -> Result<()> {
-        self.database.delete(id).await
+    async fn find_deleted_by_id(&self, id: &UserId) -> Result<Option<User>> {
+        self.database.find_deleted_by_id(id).await
+    }
+
+    async fn find_deleted_before(&self, deleted_before: DateTime<Utc>) -> Result<Vec<UserId>> {
+        self.database.find_deleted_before(deleted_before).await
+    }
+
+    async fn purge(&self, id: &UserId) -> Result<()> {
+        self.database.purge(id).await
     }
 
     async fn find_by_id(&self, id: &UserId) -> Result<Option<User>> {
@@ -103,6 +177,10 @@ impl UserRepositoryTrait for UserRepository {
     async fn batch_find(&self, users: Vec<UserId>) -> Result<Vec<User>> {
         self.database.batch_find(users).await
     }
+
+    async fn find_page(&self, query: &UserListQuery) -> Result<Vec<User>> {
+        self.database.find_page(query).await
+    }
 }
 
 impl UserRepository {
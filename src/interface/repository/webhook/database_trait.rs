@@ -0,0 +1,81 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::interface::repository::outbox::PrimitiveEvent;
+
+/// A webhook in the primitive types every database can convert from and to.
+#[derive(Debug, Clone)]
+pub struct PrimitiveWebhook {
+    pub id: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrimitiveDelivery {
+    pub id: String,
+    pub webhook_id: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub status: String,
+    pub attempts: u32,
+    pub response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// A delivery that is due, with what is needed to send it.
+#[derive(Debug, Clone)]
+pub struct PendingDelivery {
+    pub id: String,
+    pub webhook_id: String,
+    pub url: String,
+    pub secret: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub body: String,
+    pub attempts: u32,
+}
+
+#[async_trait]
+pub trait WebhookDatabaseTrait {
+    async fn save(&self, webhook: &PrimitiveWebhook) -> Result<()>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<PrimitiveWebhook>>;
+    async fn find_all(&self) -> Result<Vec<PrimitiveWebhook>>;
+    async fn delete(&self, id: &str) -> Result<()>;
+    async fn find_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: usize,
+    ) -> Result<Vec<PrimitiveDelivery>>;
+
+    /// Queues `event` for the webhook. Queuing the same event twice has no
+    /// effect, so the outbox may hand it over more than once.
+    async fn enqueue(&self, webhook_id: &str, event: &PrimitiveEvent, body: &str) -> Result<()>;
+    /// Returns up to `limit` pending deliveries due at `now`, oldest first,
+    /// and holds them back until `lease_until`.
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<PendingDelivery>>;
+    async fn mark_delivered(
+        &self,
+        id: &str,
+        response_status: u16,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<()>;
+    /// Records a failed attempt. Without `retry_at` the delivery is given up.
+    async fn mark_failed(
+        &self,
+        id: &str,
+        response_status: Option<u16>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<()>;
+}
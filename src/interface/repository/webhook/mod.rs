@@ -0,0 +1,4 @@
+mod database_trait;
+mod repository;
+
+pub use self::{database_trait::*, repository::*};
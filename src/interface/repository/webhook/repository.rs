@@ -0,0 +1,90 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::model::webhook::{
+    entity::{
+        DeliveryStatus, Webhook, WebhookDelivery, WebhookEvents, WebhookId, WebhookSecret,
+        WebhookUrl,
+    },
+    repository::WebhookRepositoryTrait,
+};
+
+use super::{PrimitiveDelivery, PrimitiveWebhook, WebhookDatabaseTrait};
+
+pub fn to_webhook(webhook: &PrimitiveWebhook) -> Result<Webhook> {
+    Webhook::restore(
+        WebhookId::new(&webhook.id)?,
+        WebhookUrl::new(&webhook.url)?,
+        WebhookEvents::new(&webhook.events)?,
+        WebhookSecret::new(&webhook.secret)?,
+        webhook.created_at,
+    )
+}
+
+fn to_primitive(webhook: &Webhook) -> PrimitiveWebhook {
+    PrimitiveWebhook {
+        id: webhook.get_id().to_string(),
+        url: webhook.get_url().to_string(),
+        events: webhook.get_events().to_vec(),
+        secret: webhook.get_secret().to_string(),
+        created_at: *webhook.get_created_at(),
+    }
+}
+
+fn to_delivery(delivery: &PrimitiveDelivery) -> Result<WebhookDelivery> {
+    Ok(WebhookDelivery {
+        id: delivery.id.to_string(),
+        webhook_id: WebhookId::new(&delivery.webhook_id)?,
+        event_id: delivery.event_id.to_string(),
+        event_type: delivery.event_type.to_string(),
+        status: DeliveryStatus::parse(&delivery.status)?,
+        attempts: delivery.attempts,
+        response_status: delivery.response_status,
+        last_error: delivery.last_error.clone(),
+        created_at: delivery.created_at,
+        delivered_at: delivery.delivered_at,
+    })
+}
+
+pub struct WebhookRepository {
+    database: Box<dyn WebhookDatabaseTrait + Send + Sync>,
+}
+
+#[async_trait]
+impl WebhookRepositoryTrait for WebhookRepository {
+    async fn save(&self, webhook: &Webhook) -> Result<()> {
+        self.database.save(&to_primitive(webhook)).await
+    }
+    async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>> {
+        self.database
+            .find_by_id(&id.to_string())
+            .await?
+            .map(|webhook| to_webhook(&webhook))
+            .transpose()
+    }
+    async fn find_all(&self) -> Result<Vec<Webhook>> {
+        self.database
+            .find_all()
+            .await?
+            .iter()
+            .map(to_webhook)
+            .collect()
+    }
+    async fn delete(&self, id: &WebhookId) -> Result<()> {
+        self.database.delete(&id.to_string()).await
+    }
+    async fn find_deliveries(&self, id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>> {
+        self.database
+            .find_deliveries(&id.to_string(), limit)
+            .await?
+            .iter()
+            .map(to_delivery)
+            .collect()
+    }
+}
+
+impl WebhookRepository {
+    pub async fn new(database: Box<dyn WebhookDatabaseTrait + Send + Sync>) -> Result<Self> {
+        Ok(Self { database })
+    }
+}
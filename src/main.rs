@@ -1,7 +1,6 @@
 #![allow(dead_code)]
 
-use infrastructure::web_server::WebServer;
-// use infrastructure::command_line::CommandLine;
+use infrastructure::command_line::CommandLine;
 
 mod application;
 mod domain;
@@ -9,18 +8,10 @@ mod infrastructure;
 mod interface;
 
 #[actix_web::main]
-async fn main() -> std::io::Result<()> {
-    let server = WebServer::new();
-    server.run().await
-}
-
+async fn main() {
+    let command_line = CommandLine::new();
 
-// #[tokio::main]
-// async fn main() -> Result<()> {
-//     let command_line = CommandLine::new().await?;
-//     
-//     if let Err(e) = command_line.start().await {
-//         eprintln!("Error: {}", e);
-//     }
-//     Ok(())
-// }
\ No newline at end of file
+    if let Err(e) = command_line.start().await {
+        std::process::exit(command_line.report_error(&e));
+    }
+}